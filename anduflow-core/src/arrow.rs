@@ -0,0 +1,843 @@
+//! Helpers for working with Arrow [`RecordBatch`]es produced by extractors.
+//!
+//! Arrow types are used through the `datafusion::arrow` re-export so that the
+//! crate stays on the same Arrow version as DataFusion.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, Write};
+use std::sync::Arc;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use chrono::DateTime;
+use datafusion::arrow::array::{
+    new_null_array, ArrayRef, BooleanArray, Float64Array, Int64Array, LargeStringArray,
+    StringArray, TimestampMicrosecondArray, TimestampMillisecondArray,
+    TimestampNanosecondArray, TimestampSecondArray, UInt64Array,
+};
+use datafusion::arrow::compute::{self, cast_with_options, CastOptions};
+use datafusion::arrow::datatypes::{DataType, Field, Fields, Schema, SchemaRef, TimeUnit};
+use datafusion::arrow::ipc::reader::FileReader;
+use datafusion::arrow::ipc::writer::FileWriter;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::arrow::util::display::{ArrayFormatter, FormatOptions};
+use serde_json::Value;
+
+/// Concatenate record batches whose schemas differ in compatible ways.
+///
+/// Unlike Arrow's strict `concat_batches`, the schemas do not have to match.
+/// The output schema is the union of all columns in first-seen order; columns
+/// missing from a batch are filled with nulls, and columns that appear with
+/// different types are widened to a type that holds every value of both (e.g.
+/// `Int32` and `Int64` to `Int64`, `UInt64` and signed integers to
+/// `Decimal128(20, 0)`, and floats and integers of up to 32 bits to `Float64`).
+/// Floats and 64-bit integers are incompatible, since `Float64` cannot hold
+/// integers beyond 2^53 exactly.
+///
+/// # Parameters
+///
+/// - `batches`: The batches to concatenate
+///
+/// # Returns
+///
+/// - `Ok(RecordBatch)` containing every row of every batch
+/// - `Err(ExtractorError)` if no batches were given or a column has incompatible types
+pub fn concat_batches(batches: &[RecordBatch]) -> ExtractorResult<RecordBatch> {
+    if batches.is_empty() {
+        return Err(ExtractorError::ExtractOpsError(
+            "No record batches to concatenate".into(),
+        ));
+    }
+
+    let schema = unified_schema(batches)?;
+    let aligned = batches
+        .iter()
+        .map(|batch| align_batch(batch, &schema))
+        .collect::<ExtractorResult<Vec<_>>>()?;
+
+    Ok(compute::concat_batches(&schema, &aligned)?)
+}
+
+/// Build the union of the batches' schemas, widening types where needed.
+fn unified_schema(batches: &[RecordBatch]) -> ExtractorResult<SchemaRef> {
+    let mut fields: Vec<Field> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+
+    for batch in batches {
+        for field in batch.schema().fields() {
+            match positions.get(field.name()) {
+                Some(&index) => {
+                    let existing = &fields[index];
+                    let data_type = widen_type(existing.data_type(), field.data_type())
+                        .ok_or_else(|| {
+                            ExtractorError::ExtractOpsError(format!(
+                                "Column '{}' has incompatible types {} and {}",
+                                field.name(),
+                                existing.data_type(),
+                                field.data_type()
+                            ))
+                        })?;
+                    let nullable = existing.is_nullable() || field.is_nullable();
+                    fields[index] = Field::new(field.name(), data_type, nullable);
+                }
+                None => {
+                    positions.insert(field.name().clone(), fields.len());
+                    fields.push(field.as_ref().clone());
+                }
+            }
+        }
+    }
+
+    // Columns that are absent from at least one batch will be null-filled.
+    let fields = fields
+        .into_iter()
+        .map(|field| {
+            let everywhere = batches
+                .iter()
+                .all(|batch| batch.schema().column_with_name(field.name()).is_some());
+            if everywhere {
+                field
+            } else {
+                field.with_nullable(true)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Arc::new(Schema::new(fields)))
+}
+
+/// Find a type both `left` and `right` can be losslessly cast to, if any.
+///
+/// Casts to the result should use [`LOSSLESS_CAST`], so a value that does not
+/// fit fails the cast instead of silently becoming null.
+pub(crate) fn widen_type(left: &DataType, right: &DataType) -> Option<DataType> {
+    if left == right {
+        return Some(left.clone());
+    }
+    match (left, right) {
+        (DataType::Null, other) | (other, DataType::Null) => Some(other.clone()),
+        (l, r) if l.is_unsigned_integer() && r.is_unsigned_integer() => Some(DataType::UInt64),
+        (DataType::UInt64, other) | (other, DataType::UInt64) if other.is_integer() => {
+            Some(DataType::Decimal128(20, 0))
+        }
+        (l, r) if l.is_integer() && r.is_integer() => Some(DataType::Int64),
+        (&DataType::Decimal128(precision, 0), other) | (other, &DataType::Decimal128(precision, 0))
+            if precision >= 20 && other.is_integer() =>
+        {
+            Some(DataType::Decimal128(precision, 0))
+        }
+        (l, r) if l.is_floating() && r.is_floating() => Some(DataType::Float64),
+        (l, r) if (l.is_floating() && fits_float64(r)) || (r.is_floating() && fits_float64(l)) => {
+            Some(DataType::Float64)
+        }
+        (l, r) if l.is_string() && r.is_string() => Some(DataType::LargeUtf8),
+        _ => None,
+    }
+}
+
+/// Whether every value of the integer type `data_type` is exactly representable
+/// as an `f64`.
+fn fits_float64(data_type: &DataType) -> bool {
+    data_type.is_integer() && data_type.primitive_width().is_some_and(|width| width <= 4)
+}
+
+/// Cast options that fail on values the target type cannot hold.
+pub(crate) const LOSSLESS_CAST: CastOptions<'static> = CastOptions {
+    safe: false,
+    format_options: FormatOptions::new(),
+};
+
+/// Project `batch` onto `schema`, casting existing columns and null-filling missing ones.
+fn align_batch(batch: &RecordBatch, schema: &SchemaRef) -> ExtractorResult<RecordBatch> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| match batch.column_by_name(field.name()) {
+            Some(column) if column.data_type() == field.data_type() => Ok(column.clone()),
+            Some(column) => Ok(cast_with_options(column, field.data_type(), &LOSSLESS_CAST)?),
+            None => Ok(new_null_array(field.data_type(), batch.num_rows())),
+        })
+        .collect::<ExtractorResult<Vec<ArrayRef>>>()?;
+
+    Ok(RecordBatch::try_new(schema.clone(), columns)?)
+}
+
+/// How values that cannot be coerced to their column's type are handled when
+/// building a batch against an explicit schema.
+///
+/// Missing and `null` values are always accepted in nullable columns and
+/// always rejected in non-nullable ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullPolicy {
+    /// Fail on any value that cannot be coerced.
+    #[default]
+    Strict,
+    /// Replace values that cannot be coerced with null, if the column is nullable.
+    NullOnError,
+}
+
+/// Build a [`RecordBatch`] from JSON records using an explicit schema.
+///
+/// Each field of `schema` is read from the record key of the same name. Values
+/// are coerced where it is safe to do so (e.g. the string `"42"` into an
+/// `Int64` column, or an RFC-3339 string into a `Timestamp` column); anything
+/// else is an error. This is [`json_to_record_batch_with_policy`] with
+/// [`NullPolicy::Strict`].
+///
+/// # Parameters
+///
+/// - `values`: The JSON records, normally objects
+/// - `schema`: The schema the output must have
+///
+/// # Returns
+///
+/// - `Ok(RecordBatch)` with exactly the given schema
+/// - `Err(ExtractorError)` if a value cannot be coerced or a non-nullable column has nulls
+///
+/// # Examples
+///
+/// ```
+/// use datafusion::arrow::datatypes::{DataType, Field, Schema};
+/// use anduflow_core::arrow::json_to_record_batch_with_schema;
+/// use serde_json::json;
+///
+/// let schema = Schema::new(vec![Field::new("id", DataType::Int64, false)]);
+/// let batch = json_to_record_batch_with_schema(&[json!({"id": "7"}), json!({"id": 8})], &schema).unwrap();
+/// assert_eq!(batch.num_rows(), 2);
+/// ```
+pub fn json_to_record_batch_with_schema(
+    values: &[Value],
+    schema: &Schema,
+) -> ExtractorResult<RecordBatch> {
+    json_to_record_batch_with_policy(values, schema, NullPolicy::Strict)
+}
+
+/// Build a [`RecordBatch`] from JSON records using an explicit schema and [`NullPolicy`].
+///
+/// See [`json_to_record_batch_with_schema`] for the coercion rules.
+pub fn json_to_record_batch_with_policy(
+    values: &[Value],
+    schema: &Schema,
+    policy: NullPolicy,
+) -> ExtractorResult<RecordBatch> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| build_column(values, field, policy).map(|(array, _)| array))
+        .collect::<ExtractorResult<Vec<_>>>()?;
+
+    Ok(RecordBatch::try_new(Arc::new(schema.clone()), columns)?)
+}
+
+/// Coerce a possibly mixed-type JSON column to a single Arrow type.
+///
+/// Real-world JSON often mixes representations within a column, such as `5`
+/// and `"5"`. This reads `field` from every record and converts each value to
+/// `target` using the same rules as [`json_to_record_batch_with_schema`];
+/// values that cannot be converted become null.
+///
+/// # Parameters
+///
+/// - `values`: The JSON records, normally objects
+/// - `field`: The record key to read
+/// - `target`: The Arrow type to produce
+///
+/// # Returns
+///
+/// - `Ok((ArrayRef, usize))` with the array and the number of values that had
+///   to be coerced from a different JSON type (e.g. parsed from a string)
+/// - `Err(ExtractorError)` if `target` is not a supported type
+///
+/// # Examples
+///
+/// ```
+/// use datafusion::arrow::datatypes::DataType;
+/// use anduflow_core::arrow::coerce_column;
+/// use serde_json::json;
+///
+/// let records = [json!({"n": 1}), json!({"n": "2"}), json!({"n": "three"})];
+/// let (array, coerced) = coerce_column(&records, "n", DataType::Int64).unwrap();
+/// assert_eq!(array.null_count(), 1);
+/// assert_eq!(coerced, 1);
+/// ```
+pub fn coerce_column(
+    values: &[Value],
+    field: &str,
+    target: DataType,
+) -> ExtractorResult<(ArrayRef, usize)> {
+    build_column(
+        values,
+        &Field::new(field, target, true),
+        NullPolicy::NullOnError,
+    )
+}
+
+/// Build the array for `field` from `records`, returning it with the number of
+/// values that had to be coerced from another JSON type.
+fn build_column(
+    records: &[Value],
+    field: &Field,
+    policy: NullPolicy,
+) -> ExtractorResult<(ArrayRef, usize)> {
+    let mut coerced = 0;
+    let array: ArrayRef = match field.data_type() {
+        DataType::Boolean => Arc::new(BooleanArray::from(collect_values(
+            records, field, policy, &mut coerced, coerce_bool,
+        )?)),
+        DataType::Int64 => Arc::new(Int64Array::from(collect_values(
+            records, field, policy, &mut coerced, coerce_i64,
+        )?)),
+        DataType::UInt64 => Arc::new(UInt64Array::from(collect_values(
+            records, field, policy, &mut coerced, coerce_u64,
+        )?)),
+        DataType::Float64 => Arc::new(Float64Array::from(collect_values(
+            records, field, policy, &mut coerced, coerce_f64,
+        )?)),
+        DataType::Utf8 => Arc::new(StringArray::from(collect_values(
+            records, field, policy, &mut coerced, coerce_string,
+        )?)),
+        DataType::LargeUtf8 => Arc::new(LargeStringArray::from(collect_values(
+            records, field, policy, &mut coerced, coerce_string,
+        )?)),
+        DataType::Timestamp(unit, tz) => {
+            let unit = *unit;
+            let values = collect_values(records, field, policy, &mut coerced, |value| {
+                coerce_timestamp(value, unit)
+            })?;
+            match unit {
+                TimeUnit::Second => {
+                    Arc::new(TimestampSecondArray::from(values).with_timezone_opt(tz.clone()))
+                }
+                TimeUnit::Millisecond => {
+                    Arc::new(TimestampMillisecondArray::from(values).with_timezone_opt(tz.clone()))
+                }
+                TimeUnit::Microsecond => {
+                    Arc::new(TimestampMicrosecondArray::from(values).with_timezone_opt(tz.clone()))
+                }
+                TimeUnit::Nanosecond => {
+                    Arc::new(TimestampNanosecondArray::from(values).with_timezone_opt(tz.clone()))
+                }
+            }
+        }
+        // Narrower numeric types are built wide and cast down, failing on overflow.
+        data_type if data_type.is_integer() || data_type.is_floating() => {
+            let wide = Field::new(
+                field.name(),
+                if data_type.is_floating() {
+                    DataType::Float64
+                } else if data_type.is_unsigned_integer() {
+                    DataType::UInt64
+                } else {
+                    DataType::Int64
+                },
+                field.is_nullable(),
+            );
+            let (array, count) = build_column(records, &wide, policy)?;
+            coerced = count;
+            // Overflow is an error in strict mode and null otherwise.
+            let options = CastOptions {
+                safe: policy == NullPolicy::NullOnError,
+                ..Default::default()
+            };
+            cast_with_options(&array, data_type, &options)?
+        }
+        data_type => {
+            return Err(ExtractorError::ExtractOpsError(format!(
+                "Column '{}': unsupported data type {data_type}",
+                field.name()
+            )))
+        }
+    };
+    Ok((array, coerced))
+}
+
+/// Read `field` from every record, converting each value with `convert`.
+///
+/// `convert` returns the converted value and whether it was coerced from a
+/// different JSON type, or `None` if the value cannot be converted.
+fn collect_values<T>(
+    records: &[Value],
+    field: &Field,
+    policy: NullPolicy,
+    coerced: &mut usize,
+    convert: impl Fn(&Value) -> Option<(T, bool)>,
+) -> ExtractorResult<Vec<Option<T>>> {
+    records
+        .iter()
+        .map(|record| match record.get(field.name()) {
+            None | Some(Value::Null) if field.is_nullable() => Ok(None),
+            None | Some(Value::Null) => Err(ExtractorError::ExtractOpsError(format!(
+                "Column '{}' is not nullable but a record has no value",
+                field.name()
+            ))),
+            Some(value) => match convert(value) {
+                Some((converted, was_coerced)) => {
+                    if was_coerced {
+                        *coerced += 1;
+                    }
+                    Ok(Some(converted))
+                }
+                None if policy == NullPolicy::NullOnError && field.is_nullable() => Ok(None),
+                None => Err(ExtractorError::ExtractOpsError(format!(
+                    "Column '{}': cannot coerce {value} to {}",
+                    field.name(),
+                    field.data_type()
+                ))),
+            },
+        })
+        .collect()
+}
+
+fn coerce_bool(value: &Value) -> Option<(bool, bool)> {
+    match value {
+        Value::Bool(b) => Some((*b, false)),
+        Value::String(s) if s.eq_ignore_ascii_case("true") => Some((true, true)),
+        Value::String(s) if s.eq_ignore_ascii_case("false") => Some((false, true)),
+        _ => None,
+    }
+}
+
+fn coerce_i64(value: &Value) -> Option<(i64, bool)> {
+    match value {
+        Value::Number(n) => n.as_i64().map(|i| (i, false)).or_else(|| {
+            n.as_f64()
+                .filter(|f| f.fract() == 0.0 && f.abs() < i64::MAX as f64)
+                .map(|f| (f as i64, true))
+        }),
+        Value::String(s) => s.trim().parse().ok().map(|i| (i, true)),
+        _ => None,
+    }
+}
+
+fn coerce_u64(value: &Value) -> Option<(u64, bool)> {
+    match value {
+        Value::Number(n) => n.as_u64().map(|u| (u, false)).or_else(|| {
+            n.as_f64()
+                .filter(|f| f.fract() == 0.0 && *f >= 0.0 && *f < u64::MAX as f64)
+                .map(|f| (f as u64, true))
+        }),
+        Value::String(s) => s.trim().parse().ok().map(|u| (u, true)),
+        _ => None,
+    }
+}
+
+fn coerce_f64(value: &Value) -> Option<(f64, bool)> {
+    match value {
+        Value::Number(n) => n.as_f64().map(|f| (f, false)),
+        Value::String(s) => s.trim().parse().ok().map(|f| (f, true)),
+        _ => None,
+    }
+}
+
+fn coerce_string(value: &Value) -> Option<(String, bool)> {
+    match value {
+        Value::String(s) => Some((s.clone(), false)),
+        other => Some((other.to_string(), true)),
+    }
+}
+
+fn coerce_timestamp(value: &Value, unit: TimeUnit) -> Option<(i64, bool)> {
+    match value {
+        Value::Number(n) => n.as_i64().map(|i| (i, false)),
+        Value::String(s) => {
+            let ts = DateTime::parse_from_rfc3339(s.trim()).ok()?;
+            let converted = match unit {
+                TimeUnit::Second => ts.timestamp(),
+                TimeUnit::Millisecond => ts.timestamp_millis(),
+                TimeUnit::Microsecond => ts.timestamp_micros(),
+                TimeUnit::Nanosecond => ts.timestamp_nanos_opt()?,
+            };
+            Some((converted, false))
+        }
+        _ => None,
+    }
+}
+
+/// Cells longer than this many characters are truncated by [`print_table`].
+const MAX_CELL_WIDTH: usize = 40;
+
+/// Render a [`RecordBatch`] as an aligned ASCII table.
+///
+/// Intended for eyeballing extracted data from the command line. Nulls are
+/// shown as `null`, line breaks inside cells are flattened, and cells longer
+/// than 40 characters are cut short with `...`. When the batch has more than
+/// `max_rows` rows, only the first `max_rows` are shown, followed by a line
+/// with the number of rows omitted.
+///
+/// # Parameters
+///
+/// - `batch`: The batch to render
+/// - `max_rows`: The maximum number of rows to show
+/// - `writer`: The destination, e.g. `std::io::stdout()` or a `Vec<u8>`
+///
+/// # Returns
+///
+/// - `Ok(())` if the table was written
+/// - `Err(ExtractorError)` if a column cannot be formatted or writing failed
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use datafusion::arrow::array::{Int64Array, StringArray};
+/// use datafusion::arrow::record_batch::RecordBatch;
+/// use anduflow_core::arrow::print_table;
+///
+/// let batch = RecordBatch::try_from_iter([
+///     ("id", Arc::new(Int64Array::from(vec![1, 2])) as _),
+///     ("name", Arc::new(StringArray::from(vec![Some("ada"), None])) as _),
+/// ]).unwrap();
+///
+/// let mut out = Vec::new();
+/// print_table(&batch, 10, &mut out).unwrap();
+/// let table = String::from_utf8(out).unwrap();
+/// assert!(table.contains("| id | name |"));
+/// assert!(table.contains("| 1  | ada  |"));
+/// ```
+pub fn print_table(
+    batch: &RecordBatch,
+    max_rows: usize,
+    mut writer: impl Write,
+) -> ExtractorResult<()> {
+    let options = FormatOptions::new().with_null("null");
+    let formatters = batch
+        .columns()
+        .iter()
+        .map(|column| ArrayFormatter::try_new(column.as_ref(), &options))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let shown = batch.num_rows().min(max_rows);
+    let header: Vec<String> = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| table_cell(field.name()))
+        .collect();
+    let rows: Vec<Vec<String>> = (0..shown)
+        .map(|row| {
+            formatters
+                .iter()
+                .map(|formatter| table_cell(&formatter.value(row).to_string()))
+                .collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([name.chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let separator: String = widths
+        .iter()
+        .map(|width| format!("+{}", "-".repeat(width + 2)))
+        .chain(["+".to_string()])
+        .collect();
+    let format_row = |cells: &[String]| -> String {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("| {cell}{} ", " ".repeat(width - cell.chars().count())))
+            .chain(["|".to_string()])
+            .collect()
+    };
+
+    writeln!(writer, "{separator}")?;
+    writeln!(writer, "{}", format_row(&header))?;
+    writeln!(writer, "{separator}")?;
+    for row in &rows {
+        writeln!(writer, "{}", format_row(row))?;
+    }
+    writeln!(writer, "{separator}")?;
+    if batch.num_rows() > shown {
+        writeln!(writer, "... {} more rows", batch.num_rows() - shown)?;
+    }
+    Ok(())
+}
+
+/// Flatten line breaks and truncate a value to [`MAX_CELL_WIDTH`] characters.
+fn table_cell(value: &str) -> String {
+    let flat = value.replace(['\n', '\r'], " ");
+    if flat.chars().count() <= MAX_CELL_WIDTH {
+        flat
+    } else {
+        let kept: String = flat.chars().take(MAX_CELL_WIDTH - 3).collect();
+        format!("{kept}...")
+    }
+}
+
+/// Write a [`RecordBatch`] in the Arrow IPC file (Feather v2) format.
+///
+/// The output can be read directly by pandas (`pd.read_feather`) or pyarrow.
+///
+/// # Parameters
+///
+/// - `batch`: The batch to write
+/// - `writer`: The destination, e.g. a `File` or `Vec<u8>`
+///
+/// # Returns
+///
+/// - `Ok(())` if the batch was written and the file footer finalized
+/// - `Err(ExtractorError)` if writing failed
+pub fn write_ipc(batch: &RecordBatch, writer: impl Write) -> ExtractorResult<()> {
+    let mut writer = FileWriter::try_new(writer, &batch.schema())?;
+    writer.write(batch)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Read every [`RecordBatch`] from an Arrow IPC file (Feather v2).
+///
+/// # Parameters
+///
+/// - `reader`: The source, e.g. a `File` or `Cursor<Vec<u8>>`
+///
+/// # Returns
+///
+/// - `Ok(Vec<RecordBatch>)` with the batches in file order
+/// - `Err(ExtractorError)` if the input is not a valid IPC file
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use std::sync::Arc;
+/// use datafusion::arrow::array::Int64Array;
+/// use datafusion::arrow::record_batch::RecordBatch;
+/// use anduflow_core::arrow::{read_ipc, write_ipc};
+///
+/// let batch = RecordBatch::try_from_iter([("id", Arc::new(Int64Array::from(vec![1, 2])) as _)]).unwrap();
+/// let mut buffer = Vec::new();
+/// write_ipc(&batch, &mut buffer).unwrap();
+/// let batches = read_ipc(Cursor::new(buffer)).unwrap();
+/// assert_eq!(batches[0], batch);
+/// ```
+pub fn read_ipc(reader: impl Read + Seek) -> ExtractorResult<Vec<RecordBatch>> {
+    let reader = FileReader::try_new(reader, None)?;
+    Ok(reader.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Derive an Arrow [`Schema`] from a JSON Schema document.
+///
+/// The document must describe an object; each entry of its `properties`
+/// becomes a field. Types map as follows:
+///
+/// | JSON Schema                       | Arrow                         |
+/// |-----------------------------------|-------------------------------|
+/// | `string`                          | `Utf8`                        |
+/// | `string` with `format: date-time` | `Timestamp(Microsecond, UTC)` |
+/// | `integer`                         | `Int64`                       |
+/// | `number`                          | `Float64`                     |
+/// | `boolean`                         | `Boolean`                     |
+/// | `array`                           | `List` of the `items` type    |
+/// | `object`                          | `Struct` of its `properties`  |
+///
+/// A field is nullable unless it is listed in `required` and its `type` does
+/// not include `"null"` (e.g. `["string", "null"]`). Fields appear in the
+/// order `serde_json` iterates the `properties` object.
+///
+/// # Parameters
+///
+/// - `schema`: The JSON Schema document
+///
+/// # Returns
+///
+/// - `Ok(Schema)` with one field per property
+/// - `Err(ExtractorError)` if the document is not an object schema or uses an unsupported type
+///
+/// # Examples
+///
+/// ```
+/// use datafusion::arrow::datatypes::{DataType, TimeUnit};
+/// use anduflow_core::arrow::schema_from_json_schema;
+/// use serde_json::json;
+///
+/// let schema = schema_from_json_schema(&json!({
+///     "type": "object",
+///     "properties": {
+///         "id": {"type": "integer"},
+///         "created_at": {"type": "string", "format": "date-time"}
+///     },
+///     "required": ["id"]
+/// })).unwrap();
+///
+/// assert!(!schema.field_with_name("id").unwrap().is_nullable());
+/// assert_eq!(
+///     schema.field_with_name("created_at").unwrap().data_type(),
+///     &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+/// );
+/// ```
+pub fn schema_from_json_schema(schema: &Value) -> ExtractorResult<Schema> {
+    match json_schema_type(schema, "<root>")? {
+        (DataType::Struct(fields), _) => Ok(Schema::new(fields)),
+        (other, _) => Err(ExtractorError::ExtractOpsError(format!(
+            "JSON Schema root must be an object, found {other}"
+        ))),
+    }
+}
+
+/// Describe an Arrow schema as a JSON Schema document.
+///
+/// This is the inverse of [`schema_from_json_schema`]: the result is an
+/// `object` schema with one property per field. Types map as follows, and
+/// anything else (e.g. binary or map columns) becomes an unconstrained `{}`:
+///
+/// | Arrow type                      | JSON Schema                       |
+/// |---------------------------------|-----------------------------------|
+/// | `Utf8`, `LargeUtf8`             | `string`                          |
+/// | signed and unsigned integers    | `integer`                         |
+/// | floats and decimals             | `number`                          |
+/// | `Boolean`                       | `boolean`                         |
+/// | `Timestamp`                     | `string` with `format: date-time` |
+/// | `Date32`, `Date64`              | `string` with `format: date`      |
+/// | `List`, `LargeList`             | `array` of the item type          |
+/// | `Struct`                        | nested `object`                   |
+///
+/// Nullable fields also allow `"null"`; non-nullable fields are `required`.
+///
+/// # Examples
+///
+/// ```
+/// use datafusion::arrow::datatypes::{DataType, Field, Schema};
+/// use anduflow_core::arrow::{json_schema_from_schema, schema_from_json_schema};
+/// use serde_json::json;
+///
+/// let schema = Schema::new(vec![
+///     Field::new("id", DataType::Int64, false),
+///     Field::new("name", DataType::Utf8, true),
+/// ]);
+/// let document = json_schema_from_schema(&schema);
+/// assert_eq!(document["properties"]["id"], json!({"type": "integer"}));
+/// assert_eq!(document["properties"]["name"], json!({"type": ["string", "null"]}));
+/// assert_eq!(document["required"], json!(["id"]));
+///
+/// let round_trip = schema_from_json_schema(&document).unwrap();
+/// assert_eq!(round_trip.field_with_name("name").unwrap().data_type(), &DataType::Utf8);
+/// ```
+pub fn json_schema_from_schema(schema: &Schema) -> Value {
+    let mut document = json_schema_object(schema.fields());
+    if let Value::Object(map) = &mut document {
+        map.insert(
+            "$schema".to_string(),
+            Value::from("https://json-schema.org/draft/2020-12/schema"),
+        );
+    }
+    document
+}
+
+/// The JSON Schema `object` node for a list of fields.
+fn json_schema_object(fields: &Fields) -> Value {
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    for field in fields {
+        let mut node = json_schema_node(field.data_type());
+        if field.is_nullable() {
+            if let Some(Value::String(ty)) = node.get("type").cloned()
+                && ty != "null"
+            {
+                node["type"] = serde_json::json!([ty, "null"]);
+            }
+        } else {
+            required.push(Value::from(field.name().as_str()));
+        }
+        properties.insert(field.name().clone(), node);
+    }
+    let mut node = serde_json::json!({"type": "object", "properties": properties});
+    if !required.is_empty() {
+        node["required"] = Value::Array(required);
+    }
+    node
+}
+
+/// The JSON Schema node for one Arrow type, ignoring nullability.
+fn json_schema_node(data_type: &DataType) -> Value {
+    use serde_json::json;
+    match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => json!({"type": "string"}),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => json!({"type": "integer"}),
+        DataType::Float16
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Decimal128(..)
+        | DataType::Decimal256(..) => json!({"type": "number"}),
+        DataType::Boolean => json!({"type": "boolean"}),
+        DataType::Timestamp(..) => json!({"type": "string", "format": "date-time"}),
+        DataType::Date32 | DataType::Date64 => json!({"type": "string", "format": "date"}),
+        DataType::List(item) | DataType::LargeList(item) => {
+            json!({"type": "array", "items": json_schema_node(item.data_type())})
+        }
+        DataType::Struct(fields) => json_schema_object(fields),
+        DataType::Null => json!({"type": "null"}),
+        _ => json!({}),
+    }
+}
+
+/// Map one JSON Schema node to an Arrow type, returning whether `"null"` was
+/// among its declared types.
+fn json_schema_type(node: &Value, name: &str) -> ExtractorResult<(DataType, bool)> {
+    let unsupported = |detail: String| {
+        ExtractorError::ExtractOpsError(format!("Unsupported JSON Schema for '{name}': {detail}"))
+    };
+
+    let types: Vec<&str> = match node.get("type") {
+        Some(Value::String(ty)) => vec![ty.as_str()],
+        Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).collect(),
+        _ => return Err(unsupported("missing 'type'".into())),
+    };
+    let allows_null = types.contains(&"null");
+    let ty = match types.iter().filter(|ty| **ty != "null").collect::<Vec<_>>()[..] {
+        [ty] => *ty,
+        _ => return Err(unsupported(format!("expected a single non-null type, found {types:?}"))),
+    };
+
+    let data_type = match ty {
+        "string" if node.get("format").and_then(Value::as_str) == Some("date-time") => {
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        }
+        "string" => DataType::Utf8,
+        "integer" => DataType::Int64,
+        "number" => DataType::Float64,
+        "boolean" => DataType::Boolean,
+        "array" => {
+            let items = node
+                .get("items")
+                .ok_or_else(|| unsupported("array without 'items'".into()))?;
+            let (item_type, _) = json_schema_type(items, name)?;
+            DataType::List(Arc::new(Field::new("item", item_type, true)))
+        }
+        "object" => {
+            let properties = node
+                .get("properties")
+                .and_then(Value::as_object)
+                .ok_or_else(|| unsupported("object without 'properties'".into()))?;
+            let required: Vec<&str> = node
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|required| required.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+            let fields = properties
+                .iter()
+                .map(|(key, property)| {
+                    let (data_type, allows_null) = json_schema_type(property, key)?;
+                    let nullable = allows_null || !required.contains(&key.as_str());
+                    Ok(Field::new(key, data_type, nullable))
+                })
+                .collect::<ExtractorResult<Vec<_>>>()?;
+            DataType::Struct(fields.into())
+        }
+        other => return Err(unsupported(format!("type '{other}'"))),
+    };
+    Ok((data_type, allows_null))
+}
@@ -0,0 +1,91 @@
+//! Time sources for time-based extraction features.
+//!
+//! Retry backoff, rate limiting and cache expiry all need to know the current
+//! time and to wait. Routing those through the [`Clock`] trait lets tests swap
+//! in a [`TestClock`] that is advanced by hand instead of sleeping for real.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use anduflow_core::clock::{Clock, TestClock};
+//!
+//! let clock = TestClock::new();
+//! let start = clock.now();
+//! clock.advance(Duration::from_secs(30));
+//! assert_eq!(clock.now() - start, Duration::from_secs(30));
+//! ```
+
+use std::fmt::Debug;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+/// A source of time.
+#[async_trait]
+pub trait Clock: Debug + Send + Sync {
+    /// Get the current instant.
+    fn now(&self) -> Instant;
+
+    /// Wait for the given duration.
+    async fn sleep(&self, duration: Duration);
+}
+
+/// A [`Clock`] backed by the system monotonic clock and the Tokio timer.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// A manually-advanced [`Clock`] for deterministic tests.
+///
+/// Time only moves when [`advance`](TestClock::advance) or
+/// [`sleep`](Clock::sleep) is called; sleeping returns immediately after
+/// moving the clock forward.
+#[derive(Debug)]
+pub struct TestClock {
+    start: Instant,
+    elapsed: Mutex<Duration>,
+}
+
+impl TestClock {
+    /// Create a new test clock frozen at the current instant.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap() += duration;
+    }
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Clock for TestClock {
+    fn now(&self) -> Instant {
+        self.start + *self.elapsed.lock().unwrap()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
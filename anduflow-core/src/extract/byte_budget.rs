@@ -0,0 +1,118 @@
+//! Run-level limits on the number of bytes extracted.
+//!
+//! A [`ByteBudget`] is shared (behind an `Arc`) by every extractor taking part
+//! in a run. Each response is charged against it by its body size, and once it
+//! is exhausted further extractions fail with
+//! [`ExtractorError::BudgetExceeded`] instead of contacting the source. This
+//! bounds the cost of a run against metered or quota-limited APIs.
+//!
+//! # Examples
+//!
+//! ```
+//! use anduflow_core::extract::byte_budget::ByteBudget;
+//! use anduflow_utils::error::ExtractorError;
+//!
+//! let budget = ByteBudget::new(1_000);
+//!
+//! // The first extraction consumes most of the budget...
+//! budget.consume(900).unwrap();
+//! assert_eq!(budget.remaining(), 100);
+//!
+//! // ...so the second one, which is larger than what is left, is refused.
+//! let err = budget.consume(500).unwrap_err();
+//! assert!(matches!(err, ExtractorError::BudgetExceeded { limit: 1_000, .. }));
+//! assert!(budget.is_exhausted());
+//! assert!(budget.check().is_err());
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+
+/// A shared, thread-safe allowance of bytes for one run.
+#[derive(Debug)]
+pub struct ByteBudget {
+    limit: u64,
+    remaining: AtomicU64,
+}
+
+impl ByteBudget {
+    /// Create a budget allowing `limit` bytes in total.
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            remaining: AtomicU64::new(limit),
+        }
+    }
+
+    /// The total number of bytes the budget allows.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// The number of bytes still available.
+    pub fn remaining(&self) -> u64 {
+        self.remaining.load(Ordering::Acquire)
+    }
+
+    /// The number of bytes charged so far.
+    ///
+    /// After a refused charge this equals the limit, since the budget is then
+    /// exhausted.
+    pub fn consumed(&self) -> u64 {
+        self.limit - self.remaining()
+    }
+
+    /// Whether no bytes are left.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Fail if the budget is exhausted.
+    ///
+    /// Extractors call this before sending a request, so that no further
+    /// requests are made once the budget has run out.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if bytes remain
+    /// - `Err(ExtractorError::BudgetExceeded)` if the budget is exhausted
+    pub fn check(&self) -> ExtractorResult<()> {
+        if self.is_exhausted() {
+            return Err(ExtractorError::BudgetExceeded {
+                limit: self.limit,
+                requested: 0,
+            });
+        }
+        Ok(())
+    }
+
+    /// Charge `bytes` against the budget.
+    ///
+    /// If `bytes` is more than what remains, the charge is refused and the
+    /// budget is marked exhausted, so every later extraction is refused too.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The size of the response being charged
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the bytes fit in the remaining budget
+    /// - `Err(ExtractorError::BudgetExceeded)` otherwise
+    pub fn consume(&self, bytes: u64) -> ExtractorResult<()> {
+        let charged = self
+            .remaining
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |remaining| {
+                remaining.checked_sub(bytes)
+            });
+        if charged.is_err() {
+            self.remaining.store(0, Ordering::Release);
+            return Err(ExtractorError::BudgetExceeded {
+                limit: self.limit,
+                requested: bytes,
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+//! Fault injection for resilience testing.
+//!
+//! This module provides [`ChaosExtractor`], a decorator that adds latency and
+//! random failures to any [`Extractor`]. With a fixed seed the sequence of
+//! injected failures is reproducible, so retry, fallback and circuit-breaker
+//! logic can be tested deterministically.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use anduflow_core::extract::chaos_extractor::{ChaosConfig, ChaosExtractor};
+//! use anduflow_core::extract::rest_extractor::RestExtractor;
+//!
+//! let extractor = ChaosExtractor::new(
+//!     RestExtractor::new("https://api.example.com", "data"),
+//!     ChaosConfig {
+//!         failure_probability: 0.2,
+//!         latency: Duration::from_millis(250),
+//!         seed: 42,
+//!     },
+//! );
+//! ```
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::LogStore;
+
+use super::{Checkpoint, Extractor};
+use crate::clock::{Clock, SystemClock};
+
+/// Faults injected by a [`ChaosExtractor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChaosConfig {
+    /// Probability (`0.0..=1.0`) that a call fails instead of reaching the inner extractor.
+    pub failure_probability: f64,
+    /// Delay added before every call.
+    pub latency: Duration,
+    /// Seed for the failure sequence; the same seed gives the same failures.
+    pub seed: u64,
+}
+
+impl Default for ChaosConfig {
+    /// No failures and no latency.
+    fn default() -> Self {
+        Self {
+            failure_probability: 0.0,
+            latency: Duration::ZERO,
+            seed: 0,
+        }
+    }
+}
+
+/// Wraps an [`Extractor`] and injects latency and failures into its calls.
+///
+/// Every call to [`ping`](Extractor::ping), [`metadata`](Extractor::metadata)
+/// and the `extract_*` methods first waits for the configured latency, then
+/// fails with [`ExtractorError::ExtractOpsError`] with the configured
+/// probability. Otherwise the call is forwarded to the inner extractor.
+#[derive(Debug)]
+pub struct ChaosExtractor<E> {
+    inner: E,
+    config: ChaosConfig,
+    rng: Mutex<u64>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<E: Extractor + Send + Sync> ChaosExtractor<E> {
+    /// Wrap `inner` with the faults described by `config`.
+    pub fn new(inner: E, config: ChaosConfig) -> Self {
+        // xorshift state must be non-zero
+        let rng = Mutex::new(config.seed.max(1));
+        Self {
+            inner,
+            config,
+            rng,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Set the clock used to wait for the injected latency.
+    ///
+    /// Defaults to [`SystemClock`]. A [`TestClock`](crate::clock::TestClock)
+    /// lets tests inject latency without actually sleeping.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// The wrapped extractor.
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    /// Apply the configured latency, then fail with the configured probability.
+    async fn inject(&self, operation: &str) -> ExtractorResult<()> {
+        if !self.config.latency.is_zero() {
+            self.clock.sleep(self.config.latency).await;
+        }
+        if self.next_f64() < self.config.failure_probability {
+            return Err(ExtractorError::ExtractOpsError(format!(
+                "Chaos: injected failure in {operation}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// The next value in `[0, 1)` from a xorshift64* generator.
+    fn next_f64(&self) -> f64 {
+        let mut state = self.rng.lock().unwrap();
+        *state ^= *state >> 12;
+        *state ^= *state << 25;
+        *state ^= *state >> 27;
+        let value = state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        (value >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[async_trait]
+impl<E: Extractor + Send + Sync> Extractor for ChaosExtractor<E> {
+    async fn ping(&self) -> ExtractorResult<()> {
+        self.inject("ping").await?;
+        self.inner.ping().await
+    }
+
+    async fn close(&mut self) -> ExtractorResult<()> {
+        self.inner.close().await
+    }
+
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+        self.inject("extract_json").await?;
+        self.inner.extract_json(logger).await
+    }
+
+    async fn extract_value(&self) -> ExtractorResult<serde_json::Value> {
+        self.inject("extract_value").await?;
+        self.inner.extract_value().await
+    }
+
+    async fn extract_text(&self) -> ExtractorResult<String> {
+        self.inject("extract_text").await?;
+        self.inner.extract_text().await
+    }
+
+    async fn extract_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        self.inject("extract_bytes").await?;
+        self.inner.extract_bytes().await
+    }
+
+    async fn extract_raw(&self) -> ExtractorResult<Bytes> {
+        self.inject("extract_raw").await?;
+        self.inner.extract_raw().await
+    }
+
+    fn schema(&self) -> Option<datafusion::arrow::datatypes::SchemaRef> {
+        self.inner.schema()
+    }
+
+    fn source_name(&self) -> ExtractorResult<&str> {
+        self.inner.source_name()
+    }
+
+    async fn metadata(&self) -> ExtractorResult<String> {
+        self.inject("metadata").await?;
+        self.inner.metadata().await
+    }
+
+    fn supports_incremental(&self) -> bool {
+        self.inner.supports_incremental()
+    }
+
+    fn checkpoint(&self) -> Option<Checkpoint> {
+        self.inner.checkpoint()
+    }
+
+    fn set_checkpoint(&mut self, chk: Checkpoint) -> ExtractorResult<()> {
+        self.inner.set_checkpoint(chk)
+    }
+}
@@ -0,0 +1,154 @@
+//! Extraction of CSV files into Arrow record batches.
+//!
+//! This module provides [`CsvExtractor`], which parses a CSV file with Arrow's
+//! CSV reader, either against an explicit schema or one inferred from a
+//! sample of the file.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use anduflow_core::extract::csv_extractor::CsvExtractor;
+//!
+//! let batches = CsvExtractor::new("data/users.csv")
+//!     .with_delimiter(b';')
+//!     .with_batch_size(10_000)
+//!     .extract_batches()
+//!     .unwrap();
+//! ```
+
+use std::fs::File;
+use std::io::{Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anduflow_utils::error::ExtractorResult;
+use datafusion::arrow::csv::ReaderBuilder;
+use datafusion::arrow::csv::reader::Format;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::record_batch::RecordBatch;
+
+/// Default number of rows per output batch.
+const DEFAULT_BATCH_SIZE: usize = 8192;
+
+/// Default number of rows sampled when inferring the schema.
+const DEFAULT_INFER_ROWS: usize = 1000;
+
+/// A CSV file extractor producing Arrow [`RecordBatch`]es.
+///
+/// Fields are comma-separated with a header row by default, and may be quoted
+/// with `"` to contain delimiters or line breaks. Without an explicit schema,
+/// column types are inferred from the first rows of the file; headerless files
+/// get columns named `column_1`, `column_2`, and so on.
+#[derive(Debug, Clone)]
+pub struct CsvExtractor {
+    path: PathBuf,
+    delimiter: u8,
+    has_header: bool,
+    schema: Option<SchemaRef>,
+    batch_size: usize,
+    infer_rows: usize,
+}
+
+impl CsvExtractor {
+    /// Create an extractor for the CSV file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            delimiter: b',',
+            has_header: true,
+            schema: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+            infer_rows: DEFAULT_INFER_ROWS,
+        }
+    }
+
+    /// Set the field delimiter, e.g. `b'\t'` for TSV. Defaults to `,`.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set whether the first row is a header. Defaults to `true`.
+    ///
+    /// With an explicit schema, the header row is skipped rather than used for names.
+    pub fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Parse against `schema` instead of inferring one.
+    ///
+    /// Columns are matched by position, not by header name.
+    pub fn with_schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Set the maximum number of rows per output batch. Defaults to 8192.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set how many rows are sampled to infer the schema. Defaults to 1000.
+    pub fn with_infer_rows(mut self, rows: usize) -> Self {
+        self.infer_rows = rows;
+        self
+    }
+
+    /// The path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The schema the file will be parsed with: the explicit schema, or one
+    /// inferred from the sampled rows.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(SchemaRef)` with the schema
+    /// - `Err(ExtractorError)` if the file cannot be read or is not valid CSV
+    pub fn schema(&self) -> ExtractorResult<SchemaRef> {
+        match &self.schema {
+            Some(schema) => Ok(schema.clone()),
+            None => self.infer_schema(&mut File::open(&self.path)?),
+        }
+    }
+
+    /// Parse the whole file.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<RecordBatch>)` with the rows in file order, at most
+    ///   `batch_size` rows per batch
+    /// - `Err(ExtractorError)` if the file cannot be read or a row does not
+    ///   match the schema
+    pub fn extract_batches(&self) -> ExtractorResult<Vec<RecordBatch>> {
+        let mut file = File::open(&self.path)?;
+        let schema = match &self.schema {
+            Some(schema) => schema.clone(),
+            None => {
+                let schema = self.infer_schema(&mut file)?;
+                file.seek(SeekFrom::Start(0))?;
+                schema
+            }
+        };
+
+        let reader = ReaderBuilder::new(schema)
+            .with_format(self.format())
+            .with_batch_size(self.batch_size)
+            .build(file)?;
+        Ok(reader.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn format(&self) -> Format {
+        Format::default()
+            .with_header(self.has_header)
+            .with_delimiter(self.delimiter)
+    }
+
+    fn infer_schema(&self, file: &mut File) -> ExtractorResult<SchemaRef> {
+        let (schema, _) = self.format().infer_schema(file, Some(self.infer_rows))?;
+        Ok(Arc::new(schema))
+    }
+}
@@ -0,0 +1,331 @@
+//! Extraction from SQLite databases.
+//!
+//! This module provides [`DbExtractor`], which runs a SQL query against a
+//! `rusqlite` connection and returns the rows as JSON objects (column name to
+//! value) or as an Arrow record batch.
+//!
+//! # Examples
+//!
+//! ```
+//! use datafusion::arrow::array::{AsArray, Int64Array};
+//! use datafusion::arrow::datatypes::{DataType, Int64Type};
+//! use rusqlite::Connection;
+//! use serde_json::{json, Value};
+//! use anduflow_core::extract::Extractor;
+//! use anduflow_core::extract::db_extractor::DbExtractor;
+//! use anduflow_utils::logger::store::LogStore;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let conn = Connection::open_in_memory().unwrap();
+//! conn.execute_batch(
+//!     "CREATE TABLE users (id INTEGER NOT NULL, name TEXT, score REAL);
+//!      INSERT INTO users VALUES (1, 'Ada', 9.5), (2, 'Grace', NULL);",
+//! )
+//! .unwrap();
+//!
+//! let extractor = DbExtractor::new(conn, "SELECT id, name, score FROM users ORDER BY id");
+//! let mut logger = LogStore::new("users".into(), "extract".into());
+//! let rows: Value = extractor.extract_json(&mut logger).await.unwrap();
+//! assert_eq!(
+//!     rows,
+//!     json!([
+//!         {"id": 1, "name": "Ada", "score": 9.5},
+//!         {"id": 2, "name": "Grace", "score": null},
+//!     ])
+//! );
+//!
+//! let batch = extractor.extract_arrow().await.unwrap();
+//! assert_eq!(batch.num_rows(), 2);
+//! assert_eq!(batch.schema().field(0).data_type(), &DataType::Int64);
+//! assert_eq!(batch.column(0).as_primitive::<Int64Type>(), &Int64Array::from(vec![1, 2]));
+//! # }
+//! ```
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bytes::Bytes;
+use datafusion::arrow::array::{ArrayRef, BinaryArray, Float64Array, Int64Array, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use rusqlite::Connection;
+use rusqlite::types::Value as SqlValue;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::LogStore;
+
+use super::Extractor;
+
+/// A column of a query result.
+struct ColumnInfo {
+    name: String,
+    /// The declared type of the column, or `None` for an expression.
+    decl_type: Option<String>,
+}
+
+/// The columns and rows of a query result.
+struct QueryResult {
+    columns: Vec<ColumnInfo>,
+    rows: Vec<Vec<SqlValue>>,
+}
+
+/// A SQL query against a SQLite database.
+///
+/// Every extraction runs the query again on the same connection.
+/// [`extract_json`](Extractor::extract_json) returns the rows as an array of
+/// JSON objects keyed by column name; blobs are encoded as base64 strings.
+/// [`extract_arrow`](Self::extract_arrow) returns them as a record batch.
+/// SQLite failures are reported as [`ExtractorError::SqliteError`].
+#[derive(Debug)]
+pub struct DbExtractor {
+    conn: Mutex<Connection>,
+    sql: String,
+}
+
+impl DbExtractor {
+    /// Create an extractor running `sql` on `conn`.
+    pub fn new(conn: Connection, sql: &str) -> Self {
+        Self {
+            conn: Mutex::new(conn),
+            sql: sql.to_string(),
+        }
+    }
+
+    /// Open the SQLite database at `path` and create an extractor running `sql` on it.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DbExtractor)` if the database could be opened
+    /// - `Err(ExtractorError::SqliteError)` otherwise
+    pub fn open(path: impl AsRef<Path>, sql: &str) -> ExtractorResult<Self> {
+        Ok(Self::new(Connection::open(path)?, sql))
+    }
+
+    /// The query.
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Run the query and return its rows as a record batch.
+    ///
+    /// Each column's Arrow type follows its declared SQLite type: `INTEGER`
+    /// columns become `Int64`, `REAL` columns `Float64`, `TEXT` columns `Utf8`
+    /// and `BLOB` columns `Binary`. Columns without a declared type, such as
+    /// expressions, take the type of their first non-null value. A column
+    /// holding values that do not fit its type is read as `Utf8`. Every column
+    /// is nullable; an empty result gives an empty batch.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(RecordBatch)` with the result rows
+    /// - `Err(ExtractorError)` if the query fails
+    pub async fn extract_arrow(&self) -> ExtractorResult<RecordBatch> {
+        let result = self.run_query()?;
+        let mut fields = Vec::with_capacity(result.columns.len());
+        let mut arrays = Vec::with_capacity(result.columns.len());
+        for (index, column) in result.columns.iter().enumerate() {
+            let values: Vec<&SqlValue> = result.rows.iter().map(|row| &row[index]).collect();
+            let data_type = column_type(column.decl_type.as_deref(), &values);
+            arrays.push(build_array(&data_type, &values));
+            fields.push(Field::new(column.name.as_str(), data_type, true));
+        }
+        Ok(RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)?)
+    }
+
+    /// Run the query and collect its columns and rows.
+    fn run_query(&self) -> ExtractorResult<QueryResult> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&self.sql)?;
+        let columns: Vec<ColumnInfo> = stmt
+            .columns()
+            .iter()
+            .map(|column| ColumnInfo {
+                name: column.name().to_string(),
+                decl_type: column.decl_type().map(str::to_string),
+            })
+            .collect();
+        let mut rows = Vec::new();
+        let mut cursor = stmt.query([])?;
+        while let Some(row) = cursor.next()? {
+            let values = (0..columns.len())
+                .map(|index| row.get::<_, SqlValue>(index))
+                .collect::<Result<Vec<_>, _>>()?;
+            rows.push(values);
+        }
+        Ok(QueryResult { columns, rows })
+    }
+
+    /// Run the query and return its rows as an array of JSON objects.
+    fn extract_rows(&self) -> ExtractorResult<Value> {
+        let result = self.run_query()?;
+        let rows = result
+            .rows
+            .into_iter()
+            .map(|row| {
+                let object: Map<String, Value> = result
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(column, value)| (column.name.clone(), json_value(value)))
+                    .collect();
+                Value::Object(object)
+            })
+            .collect();
+        Ok(Value::Array(rows))
+    }
+}
+
+/// Convert a SQLite value to JSON, encoding blobs as base64.
+fn json_value(value: SqlValue) -> Value {
+    match value {
+        SqlValue::Null => Value::Null,
+        SqlValue::Integer(n) => Value::from(n),
+        SqlValue::Real(x) => serde_json::Number::from_f64(x).map_or(Value::Null, Value::Number),
+        SqlValue::Text(s) => Value::String(s),
+        SqlValue::Blob(b) => Value::String(BASE64.encode(b)),
+    }
+}
+
+/// The Arrow type of a column, from its declared type using SQLite's type
+/// affinity rules, or from its values when it has none.
+fn column_type(decl_type: Option<&str>, values: &[&SqlValue]) -> DataType {
+    let declared = decl_type.map(str::to_ascii_uppercase).and_then(|decl| {
+        if decl.contains("INT") {
+            Some(DataType::Int64)
+        } else if ["CHAR", "CLOB", "TEXT"].iter().any(|t| decl.contains(t)) {
+            Some(DataType::Utf8)
+        } else if decl.contains("BLOB") {
+            Some(DataType::Binary)
+        } else if ["REAL", "FLOA", "DOUB"].iter().any(|t| decl.contains(t)) {
+            Some(DataType::Float64)
+        } else {
+            None
+        }
+    });
+    let data_type = declared.unwrap_or_else(|| {
+        match values.iter().find(|value| !matches!(value, SqlValue::Null)) {
+            Some(SqlValue::Integer(_)) => DataType::Int64,
+            Some(SqlValue::Real(_)) => DataType::Float64,
+            Some(SqlValue::Blob(_)) => DataType::Binary,
+            _ => DataType::Utf8,
+        }
+    });
+    let fits = values.iter().all(|value| match (&data_type, value) {
+        (_, SqlValue::Null) => true,
+        (DataType::Int64, SqlValue::Integer(_)) => true,
+        (DataType::Float64, SqlValue::Integer(_) | SqlValue::Real(_)) => true,
+        (DataType::Binary, SqlValue::Blob(_)) => true,
+        (DataType::Utf8, _) => true,
+        _ => false,
+    });
+    if fits { data_type } else { DataType::Utf8 }
+}
+
+/// Build an array of `data_type` from values that fit it.
+fn build_array(data_type: &DataType, values: &[&SqlValue]) -> ArrayRef {
+    match data_type {
+        DataType::Int64 => Arc::new(Int64Array::from_iter(values.iter().map(|value| match value {
+            SqlValue::Integer(n) => Some(*n),
+            _ => None,
+        }))),
+        DataType::Float64 => Arc::new(Float64Array::from_iter(values.iter().map(|value| {
+            match value {
+                SqlValue::Integer(n) => Some(*n as f64),
+                SqlValue::Real(x) => Some(*x),
+                _ => None,
+            }
+        }))),
+        DataType::Binary => Arc::new(BinaryArray::from_iter(values.iter().map(|value| {
+            match value {
+                SqlValue::Blob(b) => Some(b.as_slice()),
+                _ => None,
+            }
+        }))),
+        _ => Arc::new(StringArray::from_iter(values.iter().map(|value| match value {
+            SqlValue::Null => None,
+            SqlValue::Integer(n) => Some(n.to_string()),
+            SqlValue::Real(x) => Some(x.to_string()),
+            SqlValue::Text(s) => Some(s.clone()),
+            SqlValue::Blob(b) => Some(BASE64.encode(b)),
+        }))),
+    }
+}
+
+#[async_trait]
+impl Extractor for DbExtractor {
+    /// Check that the query compiles against the database, without running it.
+    async fn ping(&self) -> ExtractorResult<()> {
+        self.conn.lock().unwrap().prepare(&self.sql)?;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> ExtractorResult<()> {
+        Ok(())
+    }
+
+    /// Run the query and deserialize its rows, as an array of JSON objects.
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+        logger.mark_in_progress();
+        let result = async {
+            let rows = self.extract_rows()?;
+            let count = rows.as_array().map_or(0, Vec::len);
+            Ok::<(T, usize), ExtractorError>((serde_json::from_value(rows)?, count))
+        }
+        .await;
+        match result {
+            Ok((data, count)) => {
+                logger.update_progress(count, count);
+                logger.mark_completed();
+                Ok(data)
+            }
+            Err(err) => {
+                logger.mark_failed(err.to_string());
+                Err(err)
+            }
+        }
+    }
+
+    /// The result rows as an array of JSON objects.
+    async fn extract_value(&self) -> ExtractorResult<Value> {
+        self.extract_rows()
+    }
+
+    /// The result rows as a JSON array of objects.
+    async fn extract_text(&self) -> ExtractorResult<String> {
+        Ok(self.extract_rows()?.to_string())
+    }
+
+    /// The result rows as a JSON array of objects.
+    async fn extract_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        Ok(serde_json::to_vec(&self.extract_rows()?)?)
+    }
+
+    /// The result rows as a JSON array of objects.
+    async fn extract_raw(&self) -> ExtractorResult<Bytes> {
+        Ok(Bytes::from(serde_json::to_vec(&self.extract_rows()?)?))
+    }
+
+    fn source_name(&self) -> ExtractorResult<&str> {
+        Ok("DbExtractor")
+    }
+
+    /// The query and the names and declared types of its columns as a JSON object.
+    ///
+    /// The type of a column computed by an expression is `null`.
+    async fn metadata(&self) -> ExtractorResult<String> {
+        let conn = self.conn.lock().unwrap();
+        let stmt = conn.prepare(&self.sql)?;
+        let columns: Vec<Value> = stmt
+            .columns()
+            .iter()
+            .map(|column| json!({ "name": column.name(), "type": column.decl_type() }))
+            .collect();
+        Ok(json!({ "sql": self.sql, "columns": columns }).to_string())
+    }
+}
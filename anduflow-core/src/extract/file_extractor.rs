@@ -0,0 +1,123 @@
+//! Extraction from local files.
+//!
+//! This module provides [`FileExtractor`], an [`Extractor`] that reads a file
+//! from the local filesystem.
+//!
+//! # Examples
+//!
+//! ```
+//! use anduflow_core::extract::file_extractor::FileExtractor;
+//!
+//! let extractor = FileExtractor::new("data/users.json");
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::LogStore;
+
+use super::Extractor;
+
+/// A local file extractor.
+///
+/// Every extraction reads the whole file. I/O failures are reported as
+/// [`ExtractorError::StandardError`].
+#[derive(Debug, Clone)]
+pub struct FileExtractor {
+    path: PathBuf,
+}
+
+impl FileExtractor {
+    /// Create an extractor for the file at `path`.
+    ///
+    /// The file is not opened until it is pinged or extracted.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl Extractor for FileExtractor {
+    /// Check that the path exists, is a regular file and can be opened for reading.
+    async fn ping(&self) -> ExtractorResult<()> {
+        let metadata = tokio::fs::metadata(&self.path).await?;
+        if !metadata.is_file() {
+            return Err(ExtractorError::ExtractOpsError(format!(
+                "'{}' is not a regular file",
+                self.path.display()
+            )));
+        }
+        tokio::fs::File::open(&self.path).await?;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> ExtractorResult<()> {
+        Ok(())
+    }
+
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+        logger.mark_in_progress();
+        logger.set_source_destination(Some(self.path.display().to_string()), None);
+        let result = async {
+            let contents = tokio::fs::read(&self.path).await?;
+            Ok::<T, ExtractorError>(serde_json::from_slice(&contents)?)
+        }
+        .await;
+        match &result {
+            Ok(_) => {
+                logger.update_progress(1, 1);
+                logger.mark_completed();
+            }
+            Err(err) => logger.mark_failed(err.to_string()),
+        }
+        result
+    }
+
+    async fn extract_text(&self) -> ExtractorResult<String> {
+        Ok(tokio::fs::read_to_string(&self.path).await?)
+    }
+
+    async fn extract_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        Ok(tokio::fs::read(&self.path).await?)
+    }
+
+    async fn extract_raw(&self) -> ExtractorResult<Bytes> {
+        Ok(Bytes::from(tokio::fs::read(&self.path).await?))
+    }
+
+    /// The file path; fails if it is not valid UTF-8.
+    fn source_name(&self) -> ExtractorResult<&str> {
+        self.path.to_str().ok_or_else(|| {
+            ExtractorError::ExtractOpsError(format!(
+                "Path '{}' is not valid UTF-8",
+                self.path.display()
+            ))
+        })
+    }
+
+    /// The file's path, size in bytes and last modification time as a JSON object.
+    async fn metadata(&self) -> ExtractorResult<String> {
+        let metadata = tokio::fs::metadata(&self.path).await?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .map(|modified| DateTime::<Utc>::from(modified).to_rfc3339());
+        Ok(json!({
+            "path": self.path.display().to_string(),
+            "size": metadata.len(),
+            "modified": modified,
+        })
+        .to_string())
+    }
+}
@@ -0,0 +1,170 @@
+//! Extraction from GraphQL APIs.
+//!
+//! This module provides [`GraphQLExtractor`], which sends a query and its
+//! variables to a GraphQL endpoint through a [`RestExtractor`] and unwraps the
+//! `data` field of the response.
+//!
+//! # Examples
+//!
+//! ```
+//! use anduflow_core::extract::graphql_extractor::GraphQLExtractor;
+//! use serde_json::json;
+//!
+//! let extractor = GraphQLExtractor::new(
+//!     "https://api.example.com/graphql",
+//!     "query Launches($limit: Int!) { launches(limit: $limit) { id name } }",
+//!     json!({"limit": 10}),
+//! )
+//! .with_auth_token("your-api-token");
+//! ```
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::LogStore;
+
+use super::Extractor;
+use super::rest_extractor::RestExtractor;
+
+/// A GraphQL query extractor.
+///
+/// Every extraction POSTs `{"query": ..., "variables": ...}` to the endpoint.
+/// GraphQL servers report query errors in an `errors` array, often with a
+/// `200 OK` status; [`extract_json`](Extractor::extract_json) turns a non-empty
+/// `errors` array into an [`ExtractorError::ExtractOpsError`] and otherwise
+/// deserializes the response's `data` field. The text and byte methods return
+/// the whole response body unchanged.
+#[derive(Debug)]
+pub struct GraphQLExtractor {
+    rest: RestExtractor,
+    query: String,
+    variables: Value,
+}
+
+impl GraphQLExtractor {
+    /// Create an extractor sending `query` with `variables` to `endpoint`.
+    ///
+    /// # Parameters
+    ///
+    /// - `endpoint`: The full URL of the GraphQL endpoint
+    /// - `query`: The GraphQL query document
+    /// - `variables`: The query's variables, or `Value::Null` for none
+    pub fn new(endpoint: &str, query: &str, variables: Value) -> Self {
+        Self::from_rest(RestExtractor::new(endpoint, ""), query, variables)
+    }
+
+    /// Create an extractor from a preconfigured [`RestExtractor`], e.g. one with
+    /// timeouts, retries or OAuth2 set up.
+    ///
+    /// The REST extractor's method is replaced with `POST` and its body with the
+    /// GraphQL request. As with [`RestExtractor::with_method`], headers and
+    /// query parameters are kept.
+    pub fn from_rest(rest: RestExtractor, query: &str, variables: Value) -> Self {
+        let body = json!({
+            "query": query,
+            "variables": variables,
+        });
+        Self {
+            rest: rest.with_method("POST").with_json_body(&body),
+            query: query.to_string(),
+            variables,
+        }
+    }
+
+    /// Add a header to every request.
+    pub fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.rest = self.rest.with_header(key, value);
+        self
+    }
+
+    /// Add bearer token authentication to every request.
+    pub fn with_auth_token(mut self, token: &str) -> Self {
+        self.rest = self.rest.with_auth_token(token);
+        self
+    }
+
+    /// The query document.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// The query variables.
+    pub fn variables(&self) -> &Value {
+        &self.variables
+    }
+}
+
+/// Fail if a GraphQL response has errors, otherwise return its `data` field.
+fn graphql_data(mut response: Value) -> ExtractorResult<Value> {
+    if let Some(errors) = response.get("errors").and_then(Value::as_array)
+        && !errors.is_empty()
+    {
+        let messages: Vec<String> = errors
+            .iter()
+            .map(|error| match error.get("message").and_then(Value::as_str) {
+                Some(message) => message.to_string(),
+                None => error.to_string(),
+            })
+            .collect();
+        return Err(ExtractorError::ExtractOpsError(format!(
+            "GraphQL errors: {}",
+            messages.join("; ")
+        )));
+    }
+    match response.get_mut("data").map(Value::take) {
+        Some(data) if !data.is_null() => Ok(data),
+        _ => Err(ExtractorError::ExtractOpsError(
+            "GraphQL response has no data".into(),
+        )),
+    }
+}
+
+#[async_trait]
+impl Extractor for GraphQLExtractor {
+    async fn ping(&self) -> ExtractorResult<()> {
+        self.rest.ping().await
+    }
+
+    async fn close(&mut self) -> ExtractorResult<()> {
+        self.rest.close().await
+    }
+
+    /// Run the query and deserialize the response's `data` field.
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+        let response: Value = self.rest.extract_json(logger).await?;
+        Ok(serde_json::from_value(graphql_data(response)?)?)
+    }
+
+    /// Run the query and return the response's `data` field.
+    async fn extract_value(&self) -> ExtractorResult<Value> {
+        graphql_data(self.rest.extract_value().await?)
+    }
+
+    async fn extract_text(&self) -> ExtractorResult<String> {
+        self.rest.extract_text().await
+    }
+
+    async fn extract_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        self.rest.extract_bytes().await
+    }
+
+    async fn extract_raw(&self) -> ExtractorResult<Bytes> {
+        self.rest.extract_raw().await
+    }
+
+    fn source_name(&self) -> ExtractorResult<&str> {
+        Ok("GraphQLExtractor")
+    }
+
+    /// The endpoint and query as a JSON object.
+    async fn metadata(&self) -> ExtractorResult<String> {
+        Ok(json!({
+            "endpoint": self.rest.url(),
+            "query": self.query,
+        })
+        .to_string())
+    }
+}
@@ -0,0 +1,98 @@
+//! Incremental splitting of a top-level JSON array into its elements.
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum Position {
+    #[default]
+    BeforeArray,
+    InArray,
+    AfterArray,
+}
+
+/// Splits a top-level JSON array into the raw bytes of each element as the
+/// body arrives, without buffering more than one element at a time.
+///
+/// The parser only tracks nesting and string boundaries; each element is
+/// validated when it is deserialized.
+#[derive(Debug, Default)]
+pub(crate) struct JsonArrayParser {
+    position: Position,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    element: Vec<u8>,
+}
+
+impl JsonArrayParser {
+    /// Feed the next chunk of the body, returning every element it completes.
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> ExtractorResult<Vec<Vec<u8>>> {
+        let mut elements = Vec::new();
+        for &byte in chunk {
+            match self.position {
+                Position::BeforeArray if byte.is_ascii_whitespace() => {}
+                Position::BeforeArray if byte == b'[' => self.position = Position::InArray,
+                Position::BeforeArray => {
+                    return Err(ExtractorError::ExtractOpsError(
+                        "Response body is not a JSON array".into(),
+                    ));
+                }
+                Position::AfterArray if byte.is_ascii_whitespace() => {}
+                Position::AfterArray => {
+                    return Err(ExtractorError::ExtractOpsError(
+                        "Unexpected data after the JSON array".into(),
+                    ));
+                }
+                Position::InArray if self.in_string => {
+                    self.element.push(byte);
+                    if self.escaped {
+                        self.escaped = false;
+                    } else if byte == b'\\' {
+                        self.escaped = true;
+                    } else if byte == b'"' {
+                        self.in_string = false;
+                    }
+                }
+                Position::InArray => match byte {
+                    b'"' => {
+                        self.in_string = true;
+                        self.element.push(byte);
+                    }
+                    b'[' | b'{' => {
+                        self.depth += 1;
+                        self.element.push(byte);
+                    }
+                    b']' | b'}' if self.depth > 0 => {
+                        self.depth -= 1;
+                        self.element.push(byte);
+                    }
+                    b']' => {
+                        self.flush(&mut elements);
+                        self.position = Position::AfterArray;
+                    }
+                    b',' if self.depth == 0 => self.flush(&mut elements),
+                    _ => self.element.push(byte),
+                },
+            }
+        }
+        Ok(elements)
+    }
+
+    /// Check that the body ended after a complete array.
+    pub(crate) fn finish(&self) -> ExtractorResult<()> {
+        match self.position {
+            Position::AfterArray => Ok(()),
+            _ => Err(ExtractorError::ExtractOpsError(
+                "Response body ended before the JSON array was closed".into(),
+            )),
+        }
+    }
+
+    fn flush(&mut self, elements: &mut Vec<Vec<u8>>) {
+        if self.element.iter().any(|b| !b.is_ascii_whitespace()) {
+            elements.push(std::mem::take(&mut self.element));
+        } else {
+            self.element.clear();
+        }
+    }
+}
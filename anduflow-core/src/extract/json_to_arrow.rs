@@ -0,0 +1,808 @@
+//! Conversion of JSON API responses into Arrow record batches with an
+//! inferred schema.
+//!
+//! [`ApiToArrowConverter`] infers a column per field by counting the JSON
+//! types seen for it across all records ([`FieldStats`]) and letting the most
+//! common type win. Nested objects are flattened into dotted column names
+//! (`{"author": {"name": "x"}}` becomes an `author.name` column), arrays of
+//! scalars become `List` columns, and string fields holding ISO-8601
+//! timestamps become `Timestamp` columns. Optionally, fields holding decimal
+//! strings or numbers such as prices become `Decimal128` columns. Integers
+//! too large for `f64` to hold exactly, such as 64-bit IDs, keep an integer
+//! column rather than being rounded into a `Float64` one.
+//!
+//! # Examples
+//!
+//! ```
+//! use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+//! use serde_json::json;
+//!
+//! let records = vec![
+//!     json!({"id": 1, "author": {"name": "Ada"}, "tags": ["rust", "etl"]}),
+//!     json!({"id": 2, "author": {"name": "Grace"}, "tags": []}),
+//! ];
+//! let batch = ApiToArrowConverter::new().convert(&records).unwrap();
+//! assert!(batch.schema().field_with_name("author.name").is_ok());
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use datafusion::arrow::array::{
+    ArrayRef, BooleanArray, Decimal128Array, Float64Array, Int64Array, ListArray, StringArray,
+    TimestampMillisecondArray, UInt64Array,
+};
+use datafusion::arrow::buffer::{NullBuffer, OffsetBuffer};
+use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use datafusion::arrow::record_batch::RecordBatch;
+use serde_json::{Map, Value};
+
+/// The default share of a field's strings that must be timestamps for it to
+/// be inferred as a `Timestamp` column; see
+/// [`ApiToArrowConverter::with_timestamp_threshold`].
+pub const DEFAULT_TIMESTAMP_THRESHOLD: f64 = 0.9;
+
+/// The largest precision a `Decimal128` column can have.
+const MAX_DECIMAL_PRECISION: usize = 38;
+
+/// The largest magnitude up to which every integer is exact as an `f64`.
+const MAX_EXACT_F64_INTEGER: u64 = 1 << 53;
+
+/// Enough digits for any `i64` or `u64`; see [`FieldStats::integer_type`].
+const INTEGER_DECIMAL_PRECISION: u8 = 20;
+
+/// Counts of the JSON types seen for one field, used to vote on its Arrow type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldStats {
+    /// Values that were `null`.
+    pub null_count: usize,
+    /// Values that were `true` or `false`.
+    pub bool_count: usize,
+    /// Numbers representable as `i64`.
+    pub int_count: usize,
+    /// Integers above `i64::MAX` that are representable as `u64`.
+    pub uint_count: usize,
+    /// Negative integers; a subset of [`int_count`](Self::int_count).
+    pub negative_int_count: usize,
+    /// Integers beyond ±2^53, which an `f64` cannot hold exactly; a subset of
+    /// [`int_count`](Self::int_count) and [`uint_count`](Self::uint_count).
+    pub wide_int_count: usize,
+    /// Other numbers.
+    pub float_count: usize,
+    /// Strings, and objects inside arrays (which are kept as JSON text).
+    pub string_count: usize,
+    /// Strings that parse as ISO-8601 timestamps or dates; a subset of
+    /// [`string_count`](Self::string_count).
+    pub timestamp_count: usize,
+    /// Arrays.
+    pub list_count: usize,
+    /// Stats over the elements of every array seen, if any.
+    pub item_stats: Option<Box<FieldStats>>,
+    /// Numbers and strings written as plain decimals, like `19.99` or
+    /// `"-0.5"`, without an exponent.
+    pub decimal_count: usize,
+    /// The most digits seen before the decimal point of a decimal, ignoring
+    /// leading zeros.
+    pub decimal_integer_digits: usize,
+    /// The most digits seen after the decimal point of a decimal.
+    pub decimal_scale: usize,
+}
+
+impl FieldStats {
+    /// Count `value`. Array elements are counted into [`item_stats`](Self::item_stats).
+    pub fn update(&mut self, value: &Value) {
+        match value {
+            Value::Null => self.null_count += 1,
+            Value::Bool(_) => self.bool_count += 1,
+            Value::Number(n) if n.is_i64() || n.is_u64() => {
+                match n.as_i64() {
+                    Some(int) => {
+                        self.int_count += 1;
+                        if int < 0 {
+                            self.negative_int_count += 1;
+                        }
+                        if int.unsigned_abs() > MAX_EXACT_F64_INTEGER {
+                            self.wide_int_count += 1;
+                        }
+                    }
+                    None => {
+                        self.uint_count += 1;
+                        self.wide_int_count += 1;
+                    }
+                }
+                self.update_decimal(&n.to_string());
+            }
+            Value::Number(n) => {
+                self.float_count += 1;
+                self.update_decimal(&n.to_string());
+            }
+            Value::String(s) => {
+                self.string_count += 1;
+                if parse_timestamp_millis(s).is_some() {
+                    self.timestamp_count += 1;
+                }
+                self.update_decimal(s);
+            }
+            Value::Object(_) => self.string_count += 1,
+            Value::Array(items) => {
+                self.list_count += 1;
+                let item_stats = self.item_stats.get_or_insert_with(Default::default);
+                for item in items {
+                    item_stats.update(item);
+                }
+            }
+        }
+    }
+
+    /// Count `text` as a decimal if it is written as one.
+    fn update_decimal(&mut self, text: &str) {
+        if let Some((integer_digits, scale)) = decimal_shape(text) {
+            self.decimal_count += 1;
+            self.decimal_integer_digits = self.decimal_integer_digits.max(integer_digits);
+            self.decimal_scale = self.decimal_scale.max(scale);
+        }
+    }
+
+    /// The `Decimal128` type for the field, if every value seen is a decimal
+    /// and at least one has a fractional part.
+    ///
+    /// The precision and scale are the widest seen, so every value fits.
+    /// Returns `None` for fields of only integers, fields with any other kind
+    /// of value, and decimals wider than 38 digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_core::extract::json_to_arrow::FieldStats;
+    /// use datafusion::arrow::datatypes::DataType;
+    /// use serde_json::json;
+    ///
+    /// let mut stats = FieldStats::default();
+    /// for value in [json!("19.99"), json!("1234.5"), json!(null)] {
+    ///     stats.update(&value);
+    /// }
+    /// assert_eq!(stats.decimal_type(), Some(DataType::Decimal128(6, 2)));
+    ///
+    /// stats.update(&json!("n/a"));
+    /// assert_eq!(stats.decimal_type(), None);
+    /// ```
+    pub fn decimal_type(&self) -> Option<DataType> {
+        let values = self.int_count + self.uint_count + self.float_count + self.string_count;
+        let precision = (self.decimal_integer_digits + self.decimal_scale).max(1);
+        if self.decimal_count == 0
+            || self.decimal_count != values
+            || self.bool_count + self.list_count > 0
+            || self.decimal_scale == 0
+            || precision > MAX_DECIMAL_PRECISION
+        {
+            return None;
+        }
+        Some(DataType::Decimal128(precision as u8, self.decimal_scale as i8))
+    }
+
+    /// The integer type that holds every integer seen without loss.
+    ///
+    /// `Int64`, or `UInt64` when some integers exceed `i64::MAX` and none is
+    /// negative. A field with both needs `Decimal128(20, 0)`.
+    pub fn integer_type(&self) -> DataType {
+        match (self.uint_count, self.negative_int_count) {
+            (0, _) => DataType::Int64,
+            (_, 0) => DataType::UInt64,
+            _ => DataType::Decimal128(INTEGER_DECIMAL_PRECISION, 0),
+        }
+    }
+
+    /// Whether the field mixes integers beyond ±2^53 with floats, so that no
+    /// single numeric column holds every value exactly.
+    pub fn has_precision_conflict(&self) -> bool {
+        self.wide_int_count > 0 && self.float_count > 0
+    }
+
+    /// The Arrow type for the field: the most frequently seen JSON type.
+    ///
+    /// Ties go to the wider type, from narrowest to widest `Boolean`, integer,
+    /// `Float64`, `List`, `Utf8`, so that a field split evenly between integers
+    /// and floats becomes `Float64` and one split between numbers and strings
+    /// becomes `Utf8`. Fields that were only ever `null` become `Utf8`. The
+    /// integer type is given by [`integer_type`](Self::integer_type).
+    ///
+    /// A field that would become `Float64` but holds an integer beyond ±2^53
+    /// takes the integer type instead, however many floats it has, since
+    /// `f64` would silently change such IDs. Its floats are then null.
+    ///
+    /// A `Utf8` field becomes `Timestamp(Millisecond, None)` when at least
+    /// [`DEFAULT_TIMESTAMP_THRESHOLD`] of its strings are timestamps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_core::extract::json_to_arrow::FieldStats;
+    /// use datafusion::arrow::datatypes::DataType;
+    /// use serde_json::json;
+    ///
+    /// let mut stats = FieldStats::default();
+    /// for value in [json!(9_007_199_254_740_993_i64), json!(1.5), json!(2.5)] {
+    ///     stats.update(&value);
+    /// }
+    /// assert_eq!(stats.determine_type(), DataType::Int64);
+    /// assert!(stats.has_precision_conflict());
+    ///
+    /// stats.update(&json!(u64::MAX));
+    /// assert_eq!(stats.determine_type(), DataType::UInt64);
+    /// ```
+    pub fn determine_type(&self) -> DataType {
+        self.determine_type_with_threshold(DEFAULT_TIMESTAMP_THRESHOLD)
+    }
+
+    /// Like [`determine_type`](Self::determine_type), with the share of
+    /// strings (`0.0..=1.0`) that must be timestamps given explicitly.
+    pub fn determine_type_with_threshold(&self, timestamp_threshold: f64) -> DataType {
+        // Candidates are listed narrowest first; `max_by_key` returns the last
+        // of several equal maxima, so ties resolve to the wider type.
+        let candidates = [
+            (DataType::Boolean, self.bool_count),
+            (self.integer_type(), self.int_count + self.uint_count),
+            (DataType::Float64, self.float_count),
+            (DataType::Null, self.list_count),
+            (DataType::Utf8, self.string_count),
+        ];
+        match candidates.into_iter().max_by_key(|(_, count)| *count) {
+            Some((_, 0)) | None => DataType::Utf8,
+            Some((DataType::Float64, _)) if self.wide_int_count > 0 => self.integer_type(),
+            Some((DataType::Null, _)) => {
+                let item_type = self
+                    .item_stats
+                    .as_ref()
+                    .map(|stats| stats.determine_type_with_threshold(timestamp_threshold))
+                    .unwrap_or(DataType::Utf8);
+                DataType::List(Arc::new(Field::new("item", item_type, true)))
+            }
+            Some((DataType::Utf8, strings))
+                if self.timestamp_count as f64 >= timestamp_threshold * strings as f64 =>
+            {
+                DataType::Timestamp(TimeUnit::Millisecond, None)
+            }
+            Some((data_type, _)) => data_type,
+        }
+    }
+}
+
+/// Converts JSON records into a [`RecordBatch`] with an inferred schema.
+///
+/// Every column is nullable. Values that don't match their column's type
+/// become null, except that integers are accepted in `Float64` columns and
+/// any value is accepted in a `Utf8` column as its JSON text. Strings in a
+/// `Timestamp` column that fail to parse become null.
+#[derive(Debug, Clone)]
+pub struct ApiToArrowConverter {
+    timestamp_threshold: f64,
+    infer_decimals: bool,
+    strict_precision: bool,
+}
+
+impl Default for ApiToArrowConverter {
+    fn default() -> Self {
+        Self {
+            timestamp_threshold: DEFAULT_TIMESTAMP_THRESHOLD,
+            infer_decimals: false,
+            strict_precision: false,
+        }
+    }
+}
+
+impl ApiToArrowConverter {
+    /// Create a converter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the share of a field's strings (`0.0..=1.0`) that must parse as
+    /// timestamps for it to become a `Timestamp` column.
+    ///
+    /// The default is [`DEFAULT_TIMESTAMP_THRESHOLD`]. A threshold above `1.0`
+    /// disables timestamp inference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+    /// use datafusion::arrow::array::{Array, TimestampMillisecondArray};
+    /// use datafusion::arrow::datatypes::{DataType, TimeUnit};
+    /// use serde_json::json;
+    ///
+    /// let records = vec![
+    ///     json!({"published_at": "2024-05-01T12:00:00Z"}),
+    ///     json!({"published_at": "2024-05-02T08:30:00.250+02:00"}),
+    ///     json!({"published_at": "2024-05-03"}),
+    ///     json!({"published_at": "not a date"}),
+    /// ];
+    ///
+    /// // Three of four strings are timestamps: below the default threshold.
+    /// let batch = ApiToArrowConverter::new().convert(&records).unwrap();
+    /// assert_eq!(batch.schema().field(0).data_type(), &DataType::Utf8);
+    ///
+    /// let batch = ApiToArrowConverter::new()
+    ///     .with_timestamp_threshold(0.75)
+    ///     .convert(&records)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     batch.schema().field(0).data_type(),
+    ///     &DataType::Timestamp(TimeUnit::Millisecond, None)
+    /// );
+    /// let column = batch
+    ///     .column(0)
+    ///     .as_any()
+    ///     .downcast_ref::<TimestampMillisecondArray>()
+    ///     .unwrap();
+    /// assert_eq!(column.value(0), 1_714_564_800_000);
+    /// assert_eq!(column.value(1), 1_714_631_400_250);
+    /// assert!(column.is_null(3));
+    /// ```
+    pub fn with_timestamp_threshold(mut self, threshold: f64) -> Self {
+        self.timestamp_threshold = threshold;
+        self
+    }
+
+    /// Infer `Decimal128` columns for fields that consistently hold decimals.
+    ///
+    /// Monetary values are often sent as strings like `"19.99"`, or as numbers
+    /// with more precision than `f64` keeps. When enabled, a field whose
+    /// values are all decimal strings or numbers, at least one with a
+    /// fractional part, becomes a `Decimal128` column sized to the widest
+    /// value seen (see [`FieldStats::decimal_type`]). Values that don't fit a
+    /// column's precision become null. Disabled by default, so such fields
+    /// stay `Utf8` or `Float64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+    /// use datafusion::arrow::array::{Array, AsArray};
+    /// use datafusion::arrow::datatypes::{DataType, Decimal128Type};
+    /// use serde_json::json;
+    ///
+    /// let records = vec![json!({"price": "19.99"}), json!({"price": "1234.5"})];
+    /// let batch = ApiToArrowConverter::new()
+    ///     .with_decimal_inference(true)
+    ///     .convert(&records)
+    ///     .unwrap();
+    /// assert_eq!(batch.schema().field(0).data_type(), &DataType::Decimal128(6, 2));
+    /// let prices = batch.column(0).as_primitive::<Decimal128Type>();
+    /// assert_eq!(prices.value(0), 1999);
+    /// assert_eq!(prices.value(1), 123450);
+    /// assert_eq!(prices.value_as_string(1), "1234.50");
+    /// ```
+    pub fn with_decimal_inference(mut self, enabled: bool) -> Self {
+        self.infer_decimals = enabled;
+        self
+    }
+
+    /// Fail instead of dropping values when a field mixes floats with
+    /// integers beyond ±2^53.
+    ///
+    /// Such a field becomes an integer column so the large integers, usually
+    /// IDs, keep their exact value (see [`FieldStats::determine_type`]), and
+    /// its floats become null. When enabled, [`convert`](Self::convert)
+    /// returns an error naming the field instead. Disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+    /// use serde_json::json;
+    ///
+    /// let records = vec![json!({"id": 9_007_199_254_740_993_i64}), json!({"id": 0.5})];
+    /// let err = ApiToArrowConverter::new()
+    ///     .with_strict_precision(true)
+    ///     .convert(&records)
+    ///     .unwrap_err();
+    /// assert!(err.to_string().contains("'id'"));
+    /// ```
+    pub fn with_strict_precision(mut self, strict: bool) -> Self {
+        self.strict_precision = strict;
+        self
+    }
+
+    /// Infer a schema and build a batch from `records`.
+    ///
+    /// # Parameters
+    ///
+    /// - `records`: The JSON records, normally objects; other values become all-null rows
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(RecordBatch)` with one row per record
+    /// - `Err(ExtractorError::EmptyRecords)` if `records` is empty, since no
+    ///   schema can be inferred; use [`convert_with_schema`](Self::convert_with_schema)
+    /// - `Err(ExtractorError)` if the batch could not be assembled, or with
+    ///   [`with_strict_precision`](Self::with_strict_precision) if a field
+    ///   mixes floats with integers beyond ±2^53
+    pub fn convert(&self, records: &[Value]) -> ExtractorResult<RecordBatch> {
+        if records.is_empty() {
+            return Err(ExtractorError::EmptyRecords);
+        }
+        let flattened: Vec<Map<String, Value>> = records.iter().map(flatten_record).collect();
+        let stats = field_stats(&flattened);
+        if self.strict_precision
+            && let Some((name, _)) = stats.iter().find(|(_, stats)| stats.has_precision_conflict())
+        {
+            return Err(ExtractorError::ExtractOpsError(format!(
+                "Field '{name}' mixes floats with integers too large for Float64; \
+                 convert it with an explicit schema"
+            )));
+        }
+        let schema = Arc::new(self.schema_from_stats(&stats));
+        self.build_batch(&flattened, schema)
+    }
+
+    /// Build a batch from `records` with a known `schema` instead of an inferred one.
+    ///
+    /// Columns are filled by flattened key as in [`convert`](Self::convert);
+    /// keys missing from `schema` are dropped and fields missing from a record
+    /// are null. Empty `records` give an empty batch, so incremental pulls
+    /// that return no new rows can reuse the schema of an earlier run.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(RecordBatch)` with one row per record
+    /// - `Err(ExtractorError)` if a field has an unsupported type, or a
+    ///   non-nullable field has nulls
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+    /// use serde_json::json;
+    ///
+    /// let converter = ApiToArrowConverter::new();
+    /// let first = converter.convert(&[json!({"id": 1, "name": "Ada"})]).unwrap();
+    ///
+    /// // A later run with no new rows.
+    /// let empty = converter.convert_with_schema(&[], first.schema()).unwrap();
+    /// assert_eq!(empty.num_rows(), 0);
+    /// assert_eq!(empty.schema(), first.schema());
+    /// ```
+    pub fn convert_with_schema(
+        &self,
+        records: &[Value],
+        schema: SchemaRef,
+    ) -> ExtractorResult<RecordBatch> {
+        if records.is_empty() {
+            return Ok(RecordBatch::new_empty(schema));
+        }
+        let flattened: Vec<Map<String, Value>> = records.iter().map(flatten_record).collect();
+        self.build_batch(&flattened, schema)
+    }
+
+    /// Build a column per field of `schema` from the flattened records.
+    fn build_batch(
+        &self,
+        flattened: &[Map<String, Value>],
+        schema: SchemaRef,
+    ) -> ExtractorResult<RecordBatch> {
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| self.create_array_for_field(flattened, field))
+            .collect::<ExtractorResult<Vec<_>>>()?;
+        Ok(RecordBatch::try_new(schema, columns)?)
+    }
+
+    /// Infer the schema [`convert`](Self::convert) would produce for `records`,
+    /// without building any columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+    /// use datafusion::arrow::datatypes::DataType;
+    /// use serde_json::json;
+    ///
+    /// let schema = ApiToArrowConverter::new()
+    ///     .infer_record_schema(&[json!({"id": 1, "user": {"name": "ada"}})]);
+    /// assert_eq!(schema.field_with_name("id").unwrap().data_type(), &DataType::Int64);
+    /// assert_eq!(schema.field_with_name("user.name").unwrap().data_type(), &DataType::Utf8);
+    /// ```
+    pub fn infer_record_schema(&self, records: &[Value]) -> Schema {
+        let flattened: Vec<Map<String, Value>> = records.iter().map(flatten_record).collect();
+        self.infer_schema(&flattened)
+    }
+
+    /// Infer one nullable field per flattened key seen in any record.
+    ///
+    /// Fields are ordered by where their key first appears, scanning the
+    /// records in order (and each record in its map's iteration order), so the
+    /// same input always gives the same schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+    /// use datafusion::arrow::datatypes::DataType;
+    /// use serde_json::{json, Map, Value};
+    ///
+    /// let records: Vec<Map<String, Value>> = [
+    ///     json!({"id": 1, "score": 1}),
+    ///     json!({"id": 2, "score": 2.5, "tag": "x"}),
+    /// ]
+    /// .into_iter()
+    /// .map(|record| record.as_object().unwrap().clone())
+    /// .collect();
+    ///
+    /// let converter = ApiToArrowConverter::new();
+    /// let first = converter.infer_schema(&records);
+    /// for _ in 0..10 {
+    ///     assert_eq!(converter.infer_schema(&records), first);
+    /// }
+    /// let names: Vec<&str> = first.fields().iter().map(|f| f.name().as_str()).collect();
+    /// assert_eq!(names, ["id", "score", "tag"]);
+    ///
+    /// // One integer and one float: the tie goes to the wider type.
+    /// assert_eq!(first.field(1).data_type(), &DataType::Float64);
+    /// ```
+    pub fn infer_schema(&self, records: &[Map<String, Value>]) -> Schema {
+        self.schema_from_stats(&field_stats(records))
+    }
+
+    /// One nullable field per entry of `stats`, in order.
+    fn schema_from_stats(&self, stats: &[(String, FieldStats)]) -> Schema {
+        let fields: Vec<Field> = stats
+            .iter()
+            .map(|(name, stats)| {
+                let decimal = if self.infer_decimals { stats.decimal_type() } else { None };
+                let data_type = decimal.unwrap_or_else(|| {
+                    stats.determine_type_with_threshold(self.timestamp_threshold)
+                });
+                Field::new(name, data_type, true)
+            })
+            .collect();
+        Schema::new(fields)
+    }
+
+    /// Build the column for `field` from the flattened records.
+    pub fn create_array_for_field(
+        &self,
+        records: &[Map<String, Value>],
+        field: &Field,
+    ) -> ExtractorResult<ArrayRef> {
+        let values: Vec<Option<&Value>> =
+            records.iter().map(|record| record.get(field.name())).collect();
+        match field.data_type() {
+            DataType::List(item) => build_list_array(&values, item),
+            data_type => build_scalar_array(&values, data_type),
+        }
+    }
+}
+
+/// Convert `values` to a batch with `schema`, or an empty batch if there are none.
+///
+/// Shorthand for [`ApiToArrowConverter::convert_with_schema`] with the default
+/// converter.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use anduflow_core::extract::json_to_arrow::{json_to_arrow_with_schema, ApiToArrowConverter};
+/// use anduflow_utils::error::ExtractorError;
+/// use datafusion::arrow::datatypes::{DataType, Field, Schema};
+/// use serde_json::json;
+///
+/// let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, true)]));
+/// let batch = json_to_arrow_with_schema(&[json!({"id": 1}), json!({"id": 2})], schema.clone()).unwrap();
+/// assert_eq!(batch.num_rows(), 2);
+///
+/// let empty = json_to_arrow_with_schema(&[], schema.clone()).unwrap();
+/// assert_eq!(empty.num_rows(), 0);
+/// assert_eq!(empty.schema(), schema);
+///
+/// // Without a schema there is nothing to infer one from.
+/// let err = ApiToArrowConverter::new().convert(&[]).unwrap_err();
+/// assert!(matches!(err, ExtractorError::EmptyRecords));
+/// ```
+pub fn json_to_arrow_with_schema(values: &[Value], schema: SchemaRef) -> ExtractorResult<RecordBatch> {
+    ApiToArrowConverter::new().convert_with_schema(values, schema)
+}
+
+/// The stats of every flattened key seen in any record, ordered by where the
+/// key first appears.
+fn field_stats(records: &[Map<String, Value>]) -> Vec<(String, FieldStats)> {
+    let mut order: HashMap<String, usize> = HashMap::new();
+    let mut stats: Vec<(String, FieldStats)> = Vec::new();
+    for record in records {
+        for (key, value) in record {
+            let index = *order.entry(key.clone()).or_insert_with(|| {
+                stats.push((key.clone(), FieldStats::default()));
+                stats.len() - 1
+            });
+            stats[index].1.update(value);
+        }
+    }
+    stats
+}
+
+/// Flatten nested objects into a single map keyed by dotted paths.
+fn flatten_record(record: &Value) -> Map<String, Value> {
+    fn walk(prefix: &str, object: &Map<String, Value>, out: &mut Map<String, Value>) {
+        for (key, value) in object {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+            match value {
+                Value::Object(nested) => walk(&path, nested, out),
+                other => {
+                    out.insert(path, other.clone());
+                }
+            }
+        }
+    }
+
+    let mut out = Map::new();
+    if let Value::Object(object) = record {
+        walk("", object, &mut out);
+    }
+    out
+}
+
+fn build_scalar_array(values: &[Option<&Value>], data_type: &DataType) -> ExtractorResult<ArrayRef> {
+    let array: ArrayRef = match data_type {
+        DataType::Boolean => Arc::new(
+            values
+                .iter()
+                .map(|v| v.and_then(Value::as_bool))
+                .collect::<BooleanArray>(),
+        ),
+        DataType::Int64 => Arc::new(
+            values
+                .iter()
+                .map(|v| v.and_then(Value::as_i64))
+                .collect::<Int64Array>(),
+        ),
+        DataType::UInt64 => Arc::new(
+            values
+                .iter()
+                .map(|v| v.and_then(Value::as_u64))
+                .collect::<UInt64Array>(),
+        ),
+        DataType::Float64 => Arc::new(
+            values
+                .iter()
+                .map(|v| v.and_then(Value::as_f64))
+                .collect::<Float64Array>(),
+        ),
+        DataType::Timestamp(TimeUnit::Millisecond, None) => Arc::new(build_timestamp_array(values)),
+        DataType::Decimal128(precision, scale) => {
+            Arc::new(build_decimal_array(values, *precision, *scale)?)
+        }
+        DataType::Utf8 => Arc::new(
+            values
+                .iter()
+                .map(|v| match v {
+                    None | Some(Value::Null) => None,
+                    Some(Value::String(s)) => Some(s.clone()),
+                    Some(other) => Some(other.to_string()),
+                })
+                .collect::<StringArray>(),
+        ),
+        other => {
+            return Err(ExtractorError::ExtractOpsError(format!(
+                "Unsupported inferred type {other}"
+            )));
+        }
+    };
+    Ok(array)
+}
+
+/// Build a decimal array from decimal strings and numbers.
+///
+/// Values are rounded half away from zero to `scale` digits. Missing values,
+/// other types, strings that aren't decimals and values too wide for
+/// `precision` become null.
+fn build_decimal_array(
+    values: &[Option<&Value>],
+    precision: u8,
+    scale: i8,
+) -> ExtractorResult<Decimal128Array> {
+    let array: Decimal128Array = values
+        .iter()
+        .map(|v| match v {
+            Some(Value::String(s)) => parse_decimal(s, precision, scale),
+            Some(Value::Number(n)) => parse_decimal(&n.to_string(), precision, scale),
+            _ => None,
+        })
+        .collect();
+    Ok(array.with_precision_and_scale(precision, scale)?)
+}
+
+/// The digits before (ignoring leading zeros) and after the decimal point of
+/// `text`, if it is a plain decimal like `-12.50`.
+fn decimal_shape(text: &str) -> Option<(usize, usize)> {
+    let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if integer.is_empty() || !all_digits(integer) || !all_digits(fraction) {
+        return None;
+    }
+    Some((integer.trim_start_matches('0').len(), fraction.len()))
+}
+
+/// Parse a plain decimal into its unscaled value at `scale`, or `None` if it
+/// is not a decimal or does not fit `precision`.
+fn parse_decimal(text: &str, precision: u8, scale: i8) -> Option<i128> {
+    decimal_shape(text)?;
+    let scale = usize::try_from(scale).ok()?;
+    let negative = text.starts_with('-');
+    let digits = text.trim_start_matches(['-', '+']);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let mut value: i128 = 0;
+    let padded = fraction.bytes().chain(std::iter::repeat(b'0')).take(scale);
+    for digit in integer.bytes().chain(padded) {
+        value = value.checked_mul(10)?.checked_add(i128::from(digit - b'0'))?;
+    }
+    if fraction.as_bytes().get(scale).is_some_and(|digit| *digit >= b'5') {
+        value = value.checked_add(1)?;
+    }
+    if value >= 10_i128.checked_pow(u32::from(precision))? {
+        return None;
+    }
+    Some(if negative { -value } else { value })
+}
+
+/// Build a millisecond timestamp array from ISO-8601 strings.
+///
+/// Missing values, non-strings and strings that fail to parse become null.
+fn build_timestamp_array(values: &[Option<&Value>]) -> TimestampMillisecondArray {
+    values
+        .iter()
+        .map(|v| v.and_then(Value::as_str).and_then(parse_timestamp_millis))
+        .collect()
+}
+
+/// Parse an RFC 3339 / ISO-8601 timestamp or date into milliseconds since the
+/// Unix epoch.
+///
+/// Timestamps with an offset are converted to UTC; timestamps without one and
+/// plain dates (taken as midnight) are assumed to be UTC already.
+fn parse_timestamp_millis(s: &str) -> Option<i64> {
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(s) {
+        return Some(parsed.timestamp_millis());
+    }
+    if let Ok(parsed) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Some(parsed.and_utc().timestamp_millis());
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|midnight| midnight.and_utc().timestamp_millis())
+}
+
+fn build_list_array(values: &[Option<&Value>], item: &Arc<Field>) -> ExtractorResult<ArrayRef> {
+    let mut lengths = Vec::with_capacity(values.len());
+    let mut validity = Vec::with_capacity(values.len());
+    let mut items: Vec<Option<&Value>> = Vec::new();
+    for value in values {
+        match value {
+            Some(Value::Array(elements)) => {
+                lengths.push(elements.len());
+                validity.push(true);
+                items.extend(elements.iter().map(Some));
+            }
+            _ => {
+                lengths.push(0);
+                validity.push(false);
+            }
+        }
+    }
+    let child = build_scalar_array(&items, item.data_type())?;
+    let list = ListArray::try_new(
+        item.clone(),
+        OffsetBuffer::from_lengths(lengths),
+        child,
+        Some(NullBuffer::from(validity)),
+    )?;
+    Ok(Arc::new(list))
+}
@@ -0,0 +1,283 @@
+//! A canned-response extractor for testing pipelines.
+//!
+//! This module provides [`MockExtractor`], which returns preconfigured
+//! responses instead of contacting a source and counts how often each method
+//! is called. It can stand in for a [`RestExtractor`](super::rest_extractor::RestExtractor)
+//! or any other extractor when unit-testing transform and load logic.
+//!
+//! Available with the `testing` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use anduflow_core::extract::Extractor;
+//! use anduflow_core::extract::mock_extractor::MockExtractor;
+//! use anduflow_utils::error::ExtractorError;
+//! use anduflow_utils::logger::store::LogStore;
+//! use serde_json::{json, Value};
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let extractor = MockExtractor::new()
+//!     .with_json(json!([{"id": 1}, {"id": 2}]))
+//!     .with_error(ExtractorError::ExtractOpsError("first call fails".into()));
+//! let mut logger = LogStore::new("test".into(), "extract".into());
+//!
+//! assert!(extractor.extract_json::<Value>(&mut logger).await.is_err());
+//! let data: Value = extractor.extract_json(&mut logger).await.unwrap();
+//! assert_eq!(data, json!([{"id": 1}, {"id": 2}]));
+//! assert_eq!(extractor.json_calls(), 2);
+//! assert_eq!(extractor.text_calls(), 0);
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::LogStore;
+
+use super::{Checkpoint, Extractor};
+
+/// An extractor that returns canned responses.
+///
+/// [`extract_json`](Extractor::extract_json) and
+/// [`extract_value`](Extractor::extract_value) return the value set with
+/// [`with_json`](Self::with_json), [`extract_text`](Extractor::extract_text)
+/// the text set with [`with_text`](Self::with_text), and
+/// [`extract_bytes`](Extractor::extract_bytes) and
+/// [`extract_raw`](Extractor::extract_raw) the bytes set with
+/// [`with_bytes`](Self::with_bytes). A method without a canned response fails
+/// with [`ExtractorError::ExtractOpsError`]. Errors queued with
+/// [`with_error`](Self::with_error) are returned first, one per call, by
+/// whichever method is called next.
+#[derive(Debug, Default)]
+pub struct MockExtractor {
+    json: Option<Value>,
+    text: Option<String>,
+    bytes: Option<Vec<u8>>,
+    errors: Mutex<VecDeque<ExtractorError>>,
+    incremental: bool,
+    checkpoint: Option<Checkpoint>,
+    delay: Option<Duration>,
+    ping_calls: AtomicUsize,
+    json_calls: AtomicUsize,
+    text_calls: AtomicUsize,
+    bytes_calls: AtomicUsize,
+    raw_calls: AtomicUsize,
+    metadata_calls: AtomicUsize,
+    close_calls: AtomicUsize,
+}
+
+impl MockExtractor {
+    /// Create a mock with no canned responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return `value` from [`extract_json`](Extractor::extract_json).
+    pub fn with_json(mut self, value: Value) -> Self {
+        self.json = Some(value);
+        self
+    }
+
+    /// Return `text` from [`extract_text`](Extractor::extract_text).
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Return `bytes` from [`extract_bytes`](Extractor::extract_bytes) and
+    /// [`extract_raw`](Extractor::extract_raw).
+    pub fn with_bytes(mut self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.bytes = Some(bytes.into());
+        self
+    }
+
+    /// Queue `error` to be returned by the next call.
+    ///
+    /// Queued errors are returned in the order they were added, each by a
+    /// single call; once the queue is empty the canned responses are returned.
+    /// This makes it easy to test retry logic.
+    pub fn with_error(self, error: ExtractorError) -> Self {
+        self.errors.lock().unwrap().push_back(error);
+        self
+    }
+
+    /// Wait `delay` before answering each extraction, like a slow source.
+    ///
+    /// Applies to [`extract_json`](Extractor::extract_json),
+    /// [`extract_value`](Extractor::extract_value) and the text and byte
+    /// methods, so timeouts can be tested.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Report incremental support, so checkpoints can be set and read back.
+    pub fn with_incremental(mut self, enabled: bool) -> Self {
+        self.incremental = enabled;
+        self
+    }
+
+    /// The number of [`ping`](Extractor::ping) calls.
+    pub fn ping_calls(&self) -> usize {
+        self.ping_calls.load(Ordering::SeqCst)
+    }
+
+    /// The number of [`extract_json`](Extractor::extract_json) and
+    /// [`extract_value`](Extractor::extract_value) calls, including those made
+    /// through [`extract`](Extractor::extract).
+    pub fn json_calls(&self) -> usize {
+        self.json_calls.load(Ordering::SeqCst)
+    }
+
+    /// The number of [`extract_text`](Extractor::extract_text) calls.
+    pub fn text_calls(&self) -> usize {
+        self.text_calls.load(Ordering::SeqCst)
+    }
+
+    /// The number of [`extract_bytes`](Extractor::extract_bytes) calls.
+    pub fn bytes_calls(&self) -> usize {
+        self.bytes_calls.load(Ordering::SeqCst)
+    }
+
+    /// The number of [`extract_raw`](Extractor::extract_raw) calls.
+    pub fn raw_calls(&self) -> usize {
+        self.raw_calls.load(Ordering::SeqCst)
+    }
+
+    /// The number of [`metadata`](Extractor::metadata) calls.
+    pub fn metadata_calls(&self) -> usize {
+        self.metadata_calls.load(Ordering::SeqCst)
+    }
+
+    /// The number of [`close`](Extractor::close) calls.
+    pub fn close_calls(&self) -> usize {
+        self.close_calls.load(Ordering::SeqCst)
+    }
+
+    /// Wait for the configured delay, if any.
+    async fn respond_slowly(&self) {
+        if let Some(delay) = self.delay {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Count a call and return the next queued error, if any.
+    fn call(&self, counter: &AtomicUsize) -> ExtractorResult<()> {
+        counter.fetch_add(1, Ordering::SeqCst);
+        match self.errors.lock().unwrap().pop_front() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The canned response for `method`, or an error if none was configured.
+fn canned<'a, T: ?Sized>(response: Option<&'a T>, method: &str) -> ExtractorResult<&'a T> {
+    response.ok_or_else(|| {
+        ExtractorError::ExtractOpsError(format!("MockExtractor has no canned response for {method}"))
+    })
+}
+
+#[async_trait]
+impl Extractor for MockExtractor {
+    async fn ping(&self) -> ExtractorResult<()> {
+        self.call(&self.ping_calls)
+    }
+
+    async fn close(&mut self) -> ExtractorResult<()> {
+        self.call(&self.close_calls)
+    }
+
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+        logger.mark_in_progress();
+        self.respond_slowly().await;
+        let result = self.call(&self.json_calls).and_then(|()| {
+            let value = canned(self.json.as_ref(), "extract_json")?;
+            Ok(T::deserialize(value)?)
+        });
+        match &result {
+            Ok(_) => {
+                logger.update_progress(1, 1);
+                logger.mark_completed();
+            }
+            Err(err) => logger.mark_failed(err.to_string()),
+        }
+        result
+    }
+
+    async fn extract_value(&self) -> ExtractorResult<Value> {
+        self.respond_slowly().await;
+        self.call(&self.json_calls)?;
+        Ok(canned(self.json.as_ref(), "extract_value")?.clone())
+    }
+
+    async fn extract_text(&self) -> ExtractorResult<String> {
+        self.respond_slowly().await;
+        self.call(&self.text_calls)?;
+        Ok(canned(self.text.as_deref(), "extract_text")?.to_string())
+    }
+
+    async fn extract_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        self.respond_slowly().await;
+        self.call(&self.bytes_calls)?;
+        Ok(canned(self.bytes.as_deref(), "extract_bytes")?.to_vec())
+    }
+
+    async fn extract_raw(&self) -> ExtractorResult<Bytes> {
+        self.respond_slowly().await;
+        self.call(&self.raw_calls)?;
+        Ok(Bytes::copy_from_slice(canned(self.bytes.as_deref(), "extract_raw")?))
+    }
+
+    fn source_name(&self) -> ExtractorResult<&str> {
+        Ok("MockExtractor")
+    }
+
+    async fn metadata(&self) -> ExtractorResult<String> {
+        self.call(&self.metadata_calls)?;
+        Ok("{}".to_string())
+    }
+
+    fn supports_incremental(&self) -> bool {
+        self.incremental
+    }
+
+    fn checkpoint(&self) -> Option<Checkpoint> {
+        self.checkpoint.clone()
+    }
+
+    /// Store `chk` so [`checkpoint`](Extractor::checkpoint) returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_core::extract::{Checkpoint, Extractor};
+    /// use anduflow_core::extract::mock_extractor::MockExtractor;
+    ///
+    /// let mut extractor = MockExtractor::new().with_incremental(true);
+    /// extractor.set_checkpoint(Checkpoint("2024-01-01".into())).unwrap();
+    /// assert_eq!(extractor.checkpoint().unwrap().0, "2024-01-01");
+    ///
+    /// let mut plain = MockExtractor::new();
+    /// assert!(plain.set_checkpoint(Checkpoint("2024-01-01".into())).is_err());
+    /// ```
+    fn set_checkpoint(&mut self, chk: Checkpoint) -> ExtractorResult<()> {
+        if !self.incremental {
+            return Err(ExtractorError::ExtractOpsError(
+                "Source does not support incremental".into(),
+            ));
+        }
+        self.checkpoint = Some(chk);
+        Ok(())
+    }
+}
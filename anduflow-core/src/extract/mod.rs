@@ -19,11 +19,31 @@
 use async_trait::async_trait;
 use serde::de::DeserializeOwned;
 use bytes::Bytes;
+use datafusion::arrow::datatypes::SchemaRef;
+use serde_json::Value;
 use anduflow_utils::error;
 use anduflow_utils::error::ExtractorResult;
 use anduflow_utils::logger::store::LogStore;
 
+pub mod byte_budget;
+pub mod chaos_extractor;
+pub mod csv_extractor;
+pub mod db_extractor;
+pub mod file_extractor;
+pub mod graphql_extractor;
+pub mod json_to_arrow;
+#[cfg(feature = "testing")]
+pub mod mock_extractor;
+pub mod object_store_extractor;
+pub mod polling;
 pub mod rest_extractor;
+pub mod sql_extractor;
+#[cfg(feature = "tracing-spans")]
+pub mod telemetry;
+#[cfg(feature = "websocket")]
+pub mod websocket_extractor;
+
+mod json_array_parser;
 
 
 
@@ -31,6 +51,20 @@ pub mod rest_extractor;
 #[derive(Debug, Clone)]
 pub struct Checkpoint(pub String);
 
+/// The result of a source health check.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealthStatus {
+    /// The source is reachable and responding successfully.
+    Healthy,
+    /// The source is unreachable or responding with an error.
+    Unhealthy {
+        /// The HTTP status returned, if the source was reachable.
+        status: Option<u16>,
+        /// A description of the failure.
+        reason: String,
+    },
+}
+
 /// Format for data extraction.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ExtractFormat {
@@ -62,10 +96,41 @@ pub enum ExtractFormat {
 /// # Default Methods
 ///
 /// - [`extract`](Extractor::extract): Extract data as JSON (convenience wrapper)
+/// - [`extract_value`](Extractor::extract_value): Extract data as an untyped JSON value
 /// - [`schema`](Extractor::schema): Get the schema of the source (default: None)
 /// - [`supports_incremental`](Extractor::supports_incremental): Check if incremental extraction is supported (default: false)
 /// - [`checkpoint`](Extractor::checkpoint): Get the current checkpoint (default: None)
 /// - [`set_checkpoint`](Extractor::set_checkpoint): Set the checkpoint (default: error if not supported)
+///
+/// # Trait Objects
+///
+/// The trait can be used as `dyn Extractor`, so pipelines can hold extractors
+/// of different types in one collection. The generic
+/// [`extract`](Extractor::extract) and [`extract_json`](Extractor::extract_json)
+/// require `Self: Sized` and are only available on concrete types; through a
+/// trait object, use [`extract_value`](Extractor::extract_value) and
+/// deserialize the result.
+///
+/// ```no_run
+/// use anduflow_core::extract::Extractor;
+/// use anduflow_core::extract::file_extractor::FileExtractor;
+/// use anduflow_core::extract::rest_extractor::RestExtractor;
+///
+/// # async fn example() -> anduflow_utils::error::ExtractorResult<()> {
+/// let mut extractors: Vec<Box<dyn Extractor + Send + Sync>> = vec![
+///     Box::new(RestExtractor::new("https://api.example.com", "data")),
+///     Box::new(FileExtractor::new("data.json")),
+/// ];
+/// for extractor in &extractors {
+///     let value: serde_json::Value = extractor.extract_value().await?;
+///     println!("{}: {value}", extractor.source_name()?);
+/// }
+/// for extractor in &mut extractors {
+///     extractor.close().await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[async_trait]
 pub trait Extractor {
     // Lifecycle functions
@@ -84,13 +149,13 @@ pub trait Extractor {
     /// Close the extractor and release resources.
     ///
     /// This method should clean up any resources used by the extractor,
-    /// such as network connections or file handles.
+    /// such as network connections, cached credentials or file handles.
     ///
     /// # Returns
     ///
     /// - `Ok(())` if the extractor was closed successfully
     /// - `Err(ExtractorError)` if an error occurred while closing
-    async fn close() -> ExtractorResult<()>;
+    async fn close(&mut self) -> ExtractorResult<()>;
     
     // Data Retrieval
     /// Extract data from the source as JSON.
@@ -105,7 +170,10 @@ pub trait Extractor {
     ///
     /// - `Ok(T)` with the deserialized data
     /// - `Err(ExtractorError)` if an error occurred during extraction or deserialization
-    async fn extract<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+    async fn extract<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T>
+    where
+        Self: Sized,
+    {
         self.extract_json(logger).await
     }
     
@@ -121,7 +189,45 @@ pub trait Extractor {
     ///
     /// - `Ok(T)` with the deserialized data
     /// - `Err(ExtractorError)` if an error occurred during extraction or deserialization
-    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T>;
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T>
+    where
+        Self: Sized;
+
+    /// Extract data from the source as an untyped JSON value.
+    ///
+    /// This is the non-generic counterpart of [`extract_json`](Extractor::extract_json),
+    /// callable through `dyn Extractor`. The default implementation parses the
+    /// output of [`extract_text`](Extractor::extract_text); extractors whose
+    /// [`extract_json`](Extractor::extract_json) does more than parse the text,
+    /// such as unwrapping an envelope or advancing a checkpoint, override it.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Value)` with the extracted data
+    /// - `Err(ExtractorError)` if an error occurred during extraction or parsing
+    async fn extract_value(&self) -> ExtractorResult<Value> {
+        Ok(serde_json::from_str(&self.extract_text().await?)?)
+    }
+
+    /// Extract the records of the source as a list of JSON values.
+    ///
+    /// The default implementation takes the elements of the array returned by
+    /// [`extract_value`](Extractor::extract_value), treating `null` as no
+    /// records and any other value as a single record. Extractors that unwrap
+    /// records from an envelope such as `{"data": [...]}` override it, so the
+    /// records match those their checkpoint advances over.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Value>)` with the records
+    /// - `Err(ExtractorError)` if an error occurred during extraction or parsing
+    async fn extract_records(&self) -> ExtractorResult<Vec<Value>> {
+        Ok(match self.extract_value().await? {
+            Value::Array(records) => records,
+            Value::Null => Vec::new(),
+            record => vec![record],
+        })
+    }
     
     /// Extract data from the source as text.
     ///
@@ -156,14 +262,17 @@ pub trait Extractor {
     // Schema/Metadata
     /// Get the schema of the source.
     ///
-    /// This method should return a string representation of the schema of the source,
-    /// if available. The default implementation returns `None`.
+    /// This method should return the Arrow schema of the records the source
+    /// produces, if it is known, so pipelines can validate the data's shape
+    /// before a full pull. Use [`json_schema_from_schema`](crate::arrow::json_schema_from_schema)
+    /// to turn it into a JSON Schema document. The default implementation
+    /// returns `None`.
     ///
     /// # Returns
     ///
-    /// - `Some(String)` with the schema representation
-    /// - `None` if the schema is not available or not implemented
-    fn schema() -> Option<String> {
+    /// - `Some(SchemaRef)` with the schema
+    /// - `None` if the schema is not known or not implemented
+    fn schema(&self) -> Option<SchemaRef> {
         None
     }
     
@@ -0,0 +1,149 @@
+//! Extraction from cloud object stores.
+//!
+//! This module provides [`ObjectStoreExtractor`], an [`Extractor`] that reads a
+//! single object from any [`ObjectStore`] implementation: Amazon S3, Google
+//! Cloud Storage, Azure Blob Storage, the local filesystem or memory.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::Arc;
+//! use object_store::memory::InMemory;
+//! use object_store::path::Path;
+//! use object_store::{ObjectStore, PutPayload};
+//! use anduflow_core::extract::Extractor;
+//! use anduflow_core::extract::object_store_extractor::ObjectStoreExtractor;
+//! use anduflow_utils::logger::store::LogStore;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let store = Arc::new(InMemory::new());
+//! store
+//!     .put(&Path::from("exports/users.json"), PutPayload::from(r#"[{"id": 1}, {"id": 2}]"#))
+//!     .await
+//!     .unwrap();
+//!
+//! let extractor = ObjectStoreExtractor::new(store, "exports/users.json");
+//! extractor.ping().await.unwrap();
+//!
+//! let mut logger = LogStore::new("users".to_string(), "extract".to_string());
+//! let users: serde_json::Value = extractor.extract_json(&mut logger).await.unwrap();
+//! assert_eq!(users.as_array().unwrap().len(), 2);
+//!
+//! let metadata: serde_json::Value =
+//!     serde_json::from_str(&extractor.metadata().await.unwrap()).unwrap();
+//! assert_eq!(metadata["size"], 22);
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use object_store::ObjectStore;
+use object_store::path::Path;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::LogStore;
+
+use super::Extractor;
+
+/// An object store extractor.
+///
+/// Every extraction downloads the whole object. Store failures, such as a
+/// missing object or denied access, are reported as
+/// [`ExtractorError::ObjectStoreError`].
+#[derive(Debug, Clone)]
+pub struct ObjectStoreExtractor {
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+    source: String,
+}
+
+impl ObjectStoreExtractor {
+    /// Create an extractor for the object at `path` in `store`.
+    ///
+    /// Nothing is requested until the object is pinged or extracted.
+    pub fn new(store: Arc<dyn ObjectStore>, path: impl Into<Path>) -> Self {
+        let path = path.into();
+        Self {
+            source: format!("{store}/{path}"),
+            store,
+            path,
+        }
+    }
+
+    /// The path of the object within the store.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl Extractor for ObjectStoreExtractor {
+    /// Check that the object exists with a `head` request.
+    async fn ping(&self) -> ExtractorResult<()> {
+        self.store.head(&self.path).await?;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> ExtractorResult<()> {
+        Ok(())
+    }
+
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+        logger.mark_in_progress();
+        logger.set_source_destination(Some(self.source.clone()), None);
+        let result = async {
+            let contents = self.extract_raw().await?;
+            Ok::<T, ExtractorError>(serde_json::from_slice(&contents)?)
+        }
+        .await;
+        match &result {
+            Ok(_) => {
+                logger.update_progress(1, 1);
+                logger.mark_completed();
+            }
+            Err(err) => logger.mark_failed(err.to_string()),
+        }
+        result
+    }
+
+    /// The object's contents as text; fails if they are not valid UTF-8.
+    async fn extract_text(&self) -> ExtractorResult<String> {
+        let contents = self.extract_raw().await?;
+        String::from_utf8(contents.to_vec()).map_err(|err| {
+            ExtractorError::ExtractOpsError(format!(
+                "Object '{}' is not valid UTF-8: {err}",
+                self.path
+            ))
+        })
+    }
+
+    async fn extract_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        Ok(self.extract_raw().await?.to_vec())
+    }
+
+    async fn extract_raw(&self) -> ExtractorResult<Bytes> {
+        Ok(self.store.get(&self.path).await?.bytes().await?)
+    }
+
+    /// The store and object path.
+    fn source_name(&self) -> ExtractorResult<&str> {
+        Ok(&self.source)
+    }
+
+    /// The object's path, size in bytes, last modification time and ETag as a JSON object.
+    async fn metadata(&self) -> ExtractorResult<String> {
+        let meta = self.store.head(&self.path).await?;
+        Ok(json!({
+            "path": meta.location.to_string(),
+            "size": meta.size,
+            "last_modified": meta.last_modified.to_rfc3339(),
+            "e_tag": meta.e_tag,
+        })
+        .to_string())
+    }
+}
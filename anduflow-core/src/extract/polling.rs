@@ -0,0 +1,140 @@
+//! Periodic extraction for long-running ingestion services.
+//!
+//! [`PollingExtractor::into_polling_stream`] turns any [`Extractor`] into a
+//! stream that extracts once per interval, so a service can poll a source with
+//! a plain `while let Some(result) = stream.next().await` loop.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use futures::StreamExt;
+//! use anduflow_core::clock::{Clock, TestClock};
+//! use anduflow_core::extract::file_extractor::FileExtractor;
+//! use anduflow_core::extract::polling::PollingExtractor;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let path = std::env::temp_dir().join("anduflow-polling-doctest.json");
+//! std::fs::write(&path, r#"{"count": 3}"#).unwrap();
+//!
+//! let clock = Arc::new(TestClock::new());
+//! let start = clock.now();
+//! let interval = Duration::from_secs(60);
+//! let results: Vec<_> = FileExtractor::new(&path)
+//!     .into_polling_stream_with_clock(interval, clock.clone())
+//!     .take(2)
+//!     .collect()
+//!     .await;
+//!
+//! assert_eq!(results.len(), 2);
+//! assert_eq!(results[0].as_ref().unwrap()["count"], 3);
+//! assert_eq!(clock.now() - start, 2 * interval);
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::{Stream, stream};
+use serde_json::Value;
+
+use anduflow_utils::error::ExtractorResult;
+use anduflow_utils::logger::store::LogStore;
+
+use super::{Checkpoint, Extractor};
+use crate::clock::{Clock, SystemClock};
+
+/// Extension methods for polling an [`Extractor`] on a fixed interval.
+///
+/// Implemented for every extractor that can be moved into a stream.
+pub trait PollingExtractor: Extractor + Send + Sync + Sized + 'static {
+    /// Extract once per `interval`, yielding each result as JSON.
+    ///
+    /// The first extraction runs one `interval` after the stream is created.
+    /// Extractions never overlap: ticks that pass while an extraction
+    /// is still running (or while the consumer is not polling) are skipped, and
+    /// the next extraction runs on the following tick. Failures are yielded as
+    /// `Err` items and polling continues.
+    ///
+    /// For extractors that [support incremental extraction](Extractor::supports_incremental),
+    /// the checkpoint after each successful extraction is restored before the
+    /// next one, so a failed extraction never moves the checkpoint forward.
+    ///
+    /// The stream never ends; stop polling it (or use e.g. `take`) to stop.
+    fn into_polling_stream(
+        self,
+        interval: Duration,
+    ) -> impl Stream<Item = ExtractorResult<Value>> + Send {
+        self.into_polling_stream_with_clock(interval, Arc::new(SystemClock))
+    }
+
+    /// Like [`into_polling_stream`](Self::into_polling_stream), timing ticks
+    /// with `clock`, e.g. a [`TestClock`](crate::clock::TestClock) in tests.
+    fn into_polling_stream_with_clock(
+        self,
+        interval: Duration,
+        clock: Arc<dyn Clock>,
+    ) -> impl Stream<Item = ExtractorResult<Value>> + Send {
+        let next_tick = clock.now() + interval;
+        let state = PollState {
+            extractor: self,
+            clock,
+            interval,
+            next_tick,
+            checkpoint: None,
+        };
+        stream::unfold(state, |mut state| async move {
+            let result = state.tick().await;
+            Some((result, state))
+        })
+    }
+}
+
+impl<E: Extractor + Send + Sync + 'static> PollingExtractor for E {}
+
+/// The state carried between ticks of a polling stream.
+struct PollState<E> {
+    extractor: E,
+    clock: Arc<dyn Clock>,
+    interval: Duration,
+    next_tick: Instant,
+    checkpoint: Option<Checkpoint>,
+}
+
+impl<E: Extractor + Send + Sync> PollState<E> {
+    /// Wait for the next tick, extract, and schedule the tick after.
+    async fn tick(&mut self) -> ExtractorResult<Value> {
+        let now = self.clock.now();
+        if now < self.next_tick {
+            self.clock.sleep(self.next_tick - now).await;
+        }
+
+        let result = self.extract().await;
+
+        let now = self.clock.now();
+        self.next_tick += self.interval;
+        while self.next_tick <= now {
+            tracing::debug!("Skipping polling tick; previous extraction overran the interval");
+            self.next_tick += self.interval;
+        }
+        result
+    }
+
+    async fn extract(&mut self) -> ExtractorResult<Value> {
+        let incremental = self.extractor.supports_incremental();
+        if incremental && let Some(checkpoint) = self.checkpoint.clone() {
+            self.extractor.set_checkpoint(checkpoint)?;
+        }
+
+        let source = self.extractor.source_name().unwrap_or("extractor").to_string();
+        let mut logger = LogStore::new(format!("poll {source}"), "extract".to_string());
+        let value = self.extractor.extract_json::<Value>(&mut logger).await?;
+
+        if incremental {
+            self.checkpoint = self.extractor.checkpoint();
+        }
+        Ok(value)
+    }
+}
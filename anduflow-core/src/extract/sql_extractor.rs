@@ -0,0 +1,224 @@
+//! Extraction by running SQL over record batches and files with DataFusion.
+//!
+//! This module provides [`SqlExtractor`], which registers record batches and
+//! CSV or Parquet files as tables in a DataFusion session and runs a SQL query
+//! over them. This lets extracted data be filtered, joined or aggregated with
+//! SQL before it is loaded.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::Arc;
+//! use datafusion::arrow::array::{AsArray, Int64Array, StringArray};
+//! use datafusion::arrow::datatypes::{DataType, Field, Int64Type, Schema};
+//! use datafusion::arrow::record_batch::RecordBatch;
+//! use anduflow_core::extract::sql_extractor::SqlExtractor;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let schema = Arc::new(Schema::new(vec![
+//!     Field::new("id", DataType::Int64, false),
+//!     Field::new("country", DataType::Utf8, false),
+//! ]));
+//! let batch = RecordBatch::try_new(
+//!     schema,
+//!     vec![
+//!         Arc::new(Int64Array::from(vec![1, 2, 3])),
+//!         Arc::new(StringArray::from(vec!["NZ", "US", "NZ"])),
+//!     ],
+//! )
+//! .unwrap();
+//!
+//! let extractor = SqlExtractor::new("SELECT count(*) AS n FROM users WHERE country = 'NZ'")
+//!     .with_batches("users", vec![batch]);
+//! let result = extractor.extract_batches().await.unwrap();
+//! assert_eq!(result[0].column(0).as_primitive::<Int64Type>().value(0), 2);
+//! # }
+//! ```
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use datafusion::arrow::json::ArrayWriter;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::MemTable;
+use datafusion::prelude::{CsvReadOptions, ParquetReadOptions, SessionContext};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::LogStore;
+
+use super::Extractor;
+
+/// Where the data of a registered table comes from.
+#[derive(Debug, Clone)]
+enum TableSource {
+    Batches(Vec<RecordBatch>),
+    Csv(PathBuf),
+    Parquet(PathBuf),
+}
+
+/// A SQL query over registered tables.
+///
+/// Every extraction creates a fresh DataFusion session, registers the tables
+/// and runs the query. [`extract_batches`](Self::extract_batches) returns the
+/// result as record batches; [`extract_json`](Extractor::extract_json) returns
+/// it as an array of JSON objects, one per row. Planning and execution
+/// failures are reported as [`ExtractorError::DataFusionError`].
+#[derive(Debug, Clone)]
+pub struct SqlExtractor {
+    sql: String,
+    tables: Vec<(String, TableSource)>,
+}
+
+impl SqlExtractor {
+    /// Create an extractor running `sql`.
+    pub fn new(sql: &str) -> Self {
+        Self {
+            sql: sql.to_string(),
+            tables: Vec::new(),
+        }
+    }
+
+    /// Register `batches` as the table `name`.
+    ///
+    /// The batches must all have the same schema. An empty list registers
+    /// nothing, since it has no schema.
+    pub fn with_batches(mut self, name: &str, batches: Vec<RecordBatch>) -> Self {
+        self.tables.push((name.to_string(), TableSource::Batches(batches)));
+        self
+    }
+
+    /// Register the CSV file at `path` (with a header row) as the table `name`.
+    pub fn with_csv(mut self, name: &str, path: impl Into<PathBuf>) -> Self {
+        self.tables.push((name.to_string(), TableSource::Csv(path.into())));
+        self
+    }
+
+    /// Register the Parquet file at `path` as the table `name`.
+    pub fn with_parquet(mut self, name: &str, path: impl Into<PathBuf>) -> Self {
+        self.tables.push((name.to_string(), TableSource::Parquet(path.into())));
+        self
+    }
+
+    /// The query.
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Run the query and collect its result.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<RecordBatch>)` with the result rows
+    /// - `Err(ExtractorError)` if a table cannot be registered or the query fails
+    pub async fn extract_batches(&self) -> ExtractorResult<Vec<RecordBatch>> {
+        let ctx = self.session().await?;
+        Ok(ctx.sql(&self.sql).await?.collect().await?)
+    }
+
+    /// Create a session with every table registered.
+    async fn session(&self) -> ExtractorResult<SessionContext> {
+        let ctx = SessionContext::new();
+        for (name, source) in &self.tables {
+            match source {
+                TableSource::Batches(batches) => {
+                    let Some(first) = batches.first() else {
+                        continue;
+                    };
+                    let table = MemTable::try_new(first.schema(), vec![batches.clone()])?;
+                    ctx.register_table(name.as_str(), Arc::new(table))?;
+                }
+                TableSource::Csv(path) => {
+                    ctx.register_csv(name.as_str(), path_str(path)?, CsvReadOptions::new())
+                        .await?;
+                }
+                TableSource::Parquet(path) => {
+                    ctx.register_parquet(name.as_str(), path_str(path)?, ParquetReadOptions::default())
+                        .await?;
+                }
+            }
+        }
+        Ok(ctx)
+    }
+
+    /// Run the query and serialize the result as a JSON array of row objects.
+    async fn extract_json_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        let batches = self.extract_batches().await?;
+        let mut writer = ArrayWriter::new(Vec::new());
+        writer.write_batches(&batches.iter().collect::<Vec<_>>())?;
+        writer.finish()?;
+        let bytes = writer.into_inner();
+        if bytes.is_empty() {
+            return Ok(b"[]".to_vec());
+        }
+        Ok(bytes)
+    }
+}
+
+fn path_str(path: &std::path::Path) -> ExtractorResult<&str> {
+    path.to_str().ok_or_else(|| {
+        ExtractorError::ExtractOpsError(format!("Path '{}' is not valid UTF-8", path.display()))
+    })
+}
+
+#[async_trait]
+impl Extractor for SqlExtractor {
+    /// Check that the query plans against the registered tables, without running it.
+    async fn ping(&self) -> ExtractorResult<()> {
+        let ctx = self.session().await?;
+        ctx.sql(&self.sql).await?;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> ExtractorResult<()> {
+        Ok(())
+    }
+
+    /// Run the query and deserialize its rows, as an array of JSON objects.
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+        logger.mark_in_progress();
+        let result = async {
+            let bytes = self.extract_json_bytes().await?;
+            Ok::<T, ExtractorError>(serde_json::from_slice(&bytes)?)
+        }
+        .await;
+        match &result {
+            Ok(_) => {
+                logger.update_progress(1, 1);
+                logger.mark_completed();
+            }
+            Err(err) => logger.mark_failed(err.to_string()),
+        }
+        result
+    }
+
+    /// The result rows as a JSON array of objects.
+    async fn extract_text(&self) -> ExtractorResult<String> {
+        let bytes = self.extract_json_bytes().await?;
+        String::from_utf8(bytes).map_err(|err| ExtractorError::ExtractOpsError(err.to_string()))
+    }
+
+    /// The result rows as a JSON array of objects.
+    async fn extract_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        self.extract_json_bytes().await
+    }
+
+    /// The result rows as a JSON array of objects.
+    async fn extract_raw(&self) -> ExtractorResult<Bytes> {
+        Ok(Bytes::from(self.extract_json_bytes().await?))
+    }
+
+    fn source_name(&self) -> ExtractorResult<&str> {
+        Ok("SqlExtractor")
+    }
+
+    /// The query and the names of the registered tables as a JSON object.
+    async fn metadata(&self) -> ExtractorResult<String> {
+        let tables: Vec<&str> = self.tables.iter().map(|(name, _)| name.as_str()).collect();
+        Ok(json!({ "sql": self.sql, "tables": tables }).to_string())
+    }
+}
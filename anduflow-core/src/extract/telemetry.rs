@@ -0,0 +1,108 @@
+//! `tracing` spans around extractions.
+//!
+//! With the `tracing-spans` feature enabled, every `extract_*` call of a
+//! [`RestExtractor`](super::rest_extractor::RestExtractor) runs inside an
+//! `INFO` span named [`EXTRACT_SPAN`] with these fields:
+//!
+//! - `otel.name`: the source name and method, e.g. `RestExtractor.extract_json`,
+//!   which OpenTelemetry exporters use as the span name
+//! - `source`: the extractor's [`source_name`](super::Extractor::source_name)
+//! - `method`: the extractor method called
+//! - `url`: the request URL, with sensitive query parameters redacted
+//! - `http.status_code`: the response status, unless the response was cached
+//! - `bytes`: the size of the response body, unless the response was cached
+//! - `elapsed_ms`: the duration of the call
+//!
+//! The call ends with an `INFO` event when it succeeds, or an `ERROR` event
+//! carrying the error when it fails. This complements the
+//! [`LogStore`](anduflow_utils::logger::store::LogStore) record for pipelines
+//! that already run a `tracing` subscriber.
+//!
+//! # Examples
+//!
+//! ```
+//! use tokio::io::{AsyncReadExt, AsyncWriteExt};
+//! use tokio::net::TcpListener;
+//! use tracing_test::traced_test;
+//! use anduflow_core::extract::Extractor;
+//! use anduflow_core::extract::rest_extractor::RestExtractor;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! #     extract_traced().await;
+//! # }
+//! #[traced_test]
+//! async fn extract_traced() {
+//!     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+//!     let addr = listener.local_addr().unwrap();
+//!     tokio::spawn(async move {
+//!         let (mut socket, _) = listener.accept().await.unwrap();
+//!         let mut request = [0; 1024];
+//!         socket.read(&mut request).await.unwrap();
+//!         socket
+//!             .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 5\r\nconnection: close\r\n\r\nhello")
+//!             .await
+//!             .unwrap();
+//!     });
+//!
+//!     let extractor = RestExtractor::new(&format!("http://{addr}"), "items");
+//!     assert_eq!(extractor.extract_text().await.unwrap(), "hello");
+//!
+//!     assert!(logs_contain("otel.name=RestExtractor.extract_text"));
+//!     assert!(logs_contain("source=RestExtractor"));
+//!     assert!(logs_contain(&format!("url=http://{addr}/items")));
+//!     assert!(logs_contain("http.status_code=200"));
+//!     assert!(logs_contain("bytes=5"));
+//!     assert!(logs_contain("elapsed_ms="));
+//!     assert!(logs_contain("Extraction completed"));
+//! }
+//! ```
+
+use std::time::Instant;
+
+use tracing::{Instrument, Span};
+
+use anduflow_utils::error::ExtractorResult;
+
+/// The name of the span wrapping each extraction.
+pub const EXTRACT_SPAN: &str = "extract";
+
+/// Create the span for a call to `method` of the extractor named `source`.
+pub(crate) fn extract_span(source: &str, method: &str, url: &str) -> Span {
+    tracing::info_span!(
+        EXTRACT_SPAN,
+        otel.name = %format!("{source}.{method}"),
+        source = %source,
+        method = %method,
+        url = %url,
+        http.status_code = tracing::field::Empty,
+        bytes = tracing::field::Empty,
+        elapsed_ms = tracing::field::Empty,
+    )
+}
+
+/// Run `extract` inside `span`, recording its duration and outcome.
+pub(crate) async fn traced<T>(
+    span: Span,
+    extract: impl Future<Output = ExtractorResult<T>>,
+) -> ExtractorResult<T> {
+    let start = Instant::now();
+    let result = extract.instrument(span.clone()).await;
+    span.record("elapsed_ms", start.elapsed().as_millis() as u64);
+    let _entered = span.enter();
+    match &result {
+        Ok(_) => tracing::info!("Extraction completed"),
+        Err(err) => tracing::error!(error = %err, "Extraction failed"),
+    }
+    result
+}
+
+/// Record the status of a response on the current extraction span.
+pub(crate) fn record_status(status: u16) {
+    Span::current().record("http.status_code", status);
+}
+
+/// Record the size of a response body on the current extraction span.
+pub(crate) fn record_bytes(bytes: usize) {
+    Span::current().record("bytes", bytes as u64);
+}
@@ -0,0 +1,281 @@
+//! Extraction from WebSocket streams.
+//!
+//! This module provides [`WebSocketExtractor`], which connects to a `ws://` or
+//! `wss://` URL, optionally sends a subscribe message, and reads the text and
+//! binary frames the server pushes. Ping and pong frames are answered and
+//! skipped transparently; a close frame ends the stream.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use bytes::Bytes;
+//! use futures::TryStreamExt;
+//! use anduflow_core::extract::websocket_extractor::WebSocketExtractor;
+//!
+//! # async fn example() -> anduflow_utils::error::ExtractorResult<()> {
+//! let extractor = WebSocketExtractor::new("wss://stream.example.com/trades")
+//!     .with_subscribe_message(r#"{"subscribe":"trades"}"#);
+//! let frames: Vec<Bytes> = extractor.extract_stream().await?.try_collect().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{future, SinkExt, Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio::time::{timeout_at, Instant};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::LogStore;
+
+use super::Extractor;
+
+/// The default number of messages collected by one extraction.
+const DEFAULT_MAX_MESSAGES: usize = 100;
+
+/// The default time allowed for one extraction.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A WebSocket source.
+///
+/// [`extract_stream`](Self::extract_stream) opens a connection and yields
+/// every data frame until the server closes it. Since a socket may stay open
+/// indefinitely, the [`Extractor`] methods instead collect at most
+/// [`with_max_messages`](Self::with_max_messages) frames, stopping early when
+/// the [`with_timeout`](Self::with_timeout) deadline passes or the server
+/// closes the connection. Connection and protocol failures are reported as
+/// [`ExtractorError::ExtractOpsError`].
+#[derive(Debug, Clone)]
+pub struct WebSocketExtractor {
+    url: String,
+    subscribe: Option<String>,
+    max_messages: usize,
+    timeout: Duration,
+}
+
+impl WebSocketExtractor {
+    /// Create an extractor for the `ws://` or `wss://` URL `url`.
+    ///
+    /// Extractions collect up to 100 messages within 10 seconds.
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            subscribe: None,
+            max_messages: DEFAULT_MAX_MESSAGES,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Send `message` as a text frame right after connecting.
+    ///
+    /// Most streaming APIs expect a subscribe message naming the channels to
+    /// push before they send any data.
+    pub fn with_subscribe_message(mut self, message: &str) -> Self {
+        self.subscribe = Some(message.to_string());
+        self
+    }
+
+    /// Collect at most `max_messages` frames per extraction.
+    pub fn with_max_messages(mut self, max_messages: usize) -> Self {
+        self.max_messages = max_messages;
+        self
+    }
+
+    /// Stop collecting frames once `timeout` has passed since the extraction began.
+    ///
+    /// The frames received so far are returned. Failing to connect within
+    /// `timeout` is an error.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The URL connected to.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Connect, send the subscribe message and stream the data frames.
+    ///
+    /// Text and binary frames are yielded as their payload. Ping frames are
+    /// answered automatically and, like pong frames, are not yielded. The
+    /// stream ends when the server sends a close frame or the connection
+    /// closes.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(impl Stream)` once connected; the stream yields an
+    ///   `Err(ExtractorError)` if reading a frame fails
+    /// - `Err(ExtractorError)` if the connection or the subscribe message fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use anduflow_core::extract::websocket_extractor::WebSocketExtractor;
+    ///
+    /// # async fn example() -> anduflow_utils::error::ExtractorResult<()> {
+    /// let extractor = WebSocketExtractor::new("wss://stream.example.com/ws")
+    ///     .with_subscribe_message(r#"{"op":"subscribe","channel":"trades"}"#);
+    /// let mut frames = Box::pin(extractor.extract_stream().await?);
+    /// while let Some(frame) = frames.try_next().await? {
+    ///     println!("{} bytes", frame.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn extract_stream(
+        &self,
+    ) -> ExtractorResult<impl Stream<Item = ExtractorResult<Bytes>> + Send + use<>> {
+        let (mut socket, _) = connect_async(self.url.as_str())
+            .await
+            .map_err(|err| ws_error(&self.url, err))?;
+        if let Some(message) = &self.subscribe {
+            socket
+                .send(Message::text(message.as_str()))
+                .await
+                .map_err(|err| ws_error(&self.url, err))?;
+        }
+        let url = self.url.clone();
+        Ok(socket
+            .take_while(|message| future::ready(!matches!(message, Ok(Message::Close(_)))))
+            .filter_map(move |message| {
+                future::ready(match message {
+                    Ok(Message::Text(text)) => Some(Ok(Bytes::from(text))),
+                    Ok(Message::Binary(data)) => Some(Ok(data)),
+                    Ok(_) => None,
+                    Err(err) => Some(Err(ws_error(&url, err))),
+                })
+            }))
+    }
+
+    /// Collect frames until the limit, the deadline or the end of the stream.
+    async fn collect_frames(&self) -> ExtractorResult<Vec<Bytes>> {
+        let deadline = Instant::now() + self.timeout;
+        let frames = timeout_at(deadline, self.extract_stream())
+            .await
+            .map_err(|_| self.timed_out())??;
+        let mut frames = Box::pin(frames);
+        let mut collected = Vec::new();
+        while collected.len() < self.max_messages {
+            let Ok(next) = timeout_at(deadline, frames.try_next()).await else {
+                break;
+            };
+            match next? {
+                Some(frame) => collected.push(frame),
+                None => break,
+            }
+        }
+        Ok(collected)
+    }
+
+    fn timed_out(&self) -> ExtractorError {
+        ExtractorError::ExtractOpsError(format!(
+            "WebSocket connection to '{}' timed out after {:?}",
+            self.url, self.timeout
+        ))
+    }
+
+    /// The collected frames, one per line.
+    async fn collect_lines(&self) -> ExtractorResult<Vec<u8>> {
+        let frames = self.collect_frames().await?;
+        let mut lines = Vec::new();
+        for (i, frame) in frames.iter().enumerate() {
+            if i > 0 {
+                lines.push(b'\n');
+            }
+            lines.extend_from_slice(frame);
+        }
+        Ok(lines)
+    }
+}
+
+fn ws_error(url: &str, err: WsError) -> ExtractorError {
+    ExtractorError::ExtractOpsError(format!("WebSocket error for '{url}': {err}"))
+}
+
+#[async_trait]
+impl Extractor for WebSocketExtractor {
+    /// Check that a connection can be opened, then close it.
+    async fn ping(&self) -> ExtractorResult<()> {
+        let connect = connect_async(self.url.as_str());
+        let (mut socket, _) = timeout_at(Instant::now() + self.timeout, connect)
+            .await
+            .map_err(|_| self.timed_out())?
+            .map_err(|err| ws_error(&self.url, err))?;
+        socket.close(None).await.map_err(|err| ws_error(&self.url, err))
+    }
+
+    async fn close(&mut self) -> ExtractorResult<()> {
+        Ok(())
+    }
+
+    /// Collect frames and deserialize them, as a JSON array of the parsed frames.
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+        logger.mark_in_progress();
+        let result = async {
+            let value = self.extract_value().await?;
+            let count = value.as_array().map_or(0, Vec::len);
+            Ok::<(T, usize), ExtractorError>((serde_json::from_value(value)?, count))
+        }
+        .await;
+        match result {
+            Ok((data, count)) => {
+                logger.update_progress(count, count);
+                logger.mark_completed();
+                Ok(data)
+            }
+            Err(err) => {
+                logger.mark_failed(err.to_string());
+                Err(err)
+            }
+        }
+    }
+
+    /// Collect frames and parse each one as JSON, returning them as an array.
+    async fn extract_value(&self) -> ExtractorResult<Value> {
+        let frames = self.collect_frames().await?;
+        let values = frames
+            .iter()
+            .map(|frame| serde_json::from_slice(frame))
+            .collect::<Result<Vec<Value>, _>>()?;
+        Ok(Value::Array(values))
+    }
+
+    /// The collected frames, one per line.
+    async fn extract_text(&self) -> ExtractorResult<String> {
+        let lines = self.collect_lines().await?;
+        String::from_utf8(lines).map_err(|err| ExtractorError::ExtractOpsError(err.to_string()))
+    }
+
+    /// The collected frames, one per line.
+    async fn extract_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        self.collect_lines().await
+    }
+
+    /// The collected frames, one per line.
+    async fn extract_raw(&self) -> ExtractorResult<Bytes> {
+        Ok(Bytes::from(self.collect_lines().await?))
+    }
+
+    fn source_name(&self) -> ExtractorResult<&str> {
+        Ok("WebSocketExtractor")
+    }
+
+    /// The URL and collection limits as a JSON object.
+    async fn metadata(&self) -> ExtractorResult<String> {
+        Ok(json!({
+            "url": self.url,
+            "subscribe": self.subscribe,
+            "max_messages": self.max_messages,
+            "timeout_ms": self.timeout.as_millis() as u64,
+        })
+        .to_string())
+    }
+}
@@ -1,2 +1,6 @@
+pub mod arrow;
+pub mod clock;
 pub mod extract;
-pub mod load;
\ No newline at end of file
+pub mod load;
+pub mod pipeline;
+pub mod transform;
@@ -0,0 +1,123 @@
+//! Loading of record batches into Delta Lake tables.
+//!
+//! This module provides [`DeltaLoader`], which appends each [`RecordBatch`]
+//! to a Delta table as a new commit, creating the table from the first batch's
+//! schema if it does not exist yet. Requires the `delta` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use datafusion::arrow::array::{Int64Array, StringArray};
+//! use datafusion::arrow::datatypes::{DataType, Field, Schema};
+//! use datafusion::arrow::record_batch::RecordBatch;
+//! use anduflow_core::load::delta_loader::DeltaLoader;
+//!
+//! # async fn example() -> anduflow_utils::error::ExtractorResult<()> {
+//! let schema = Arc::new(Schema::new(vec![
+//!     Field::new("id", DataType::Int64, false),
+//!     Field::new("name", DataType::Utf8, true),
+//! ]));
+//! let batch = |ids: Vec<i64>, names: Vec<&str>| {
+//!     RecordBatch::try_new(
+//!         schema.clone(),
+//!         vec![Arc::new(Int64Array::from(ids)), Arc::new(StringArray::from(names))],
+//!     )
+//! };
+//!
+//! let loader = DeltaLoader::new("/tmp/anduflow/users");
+//! loader.load(batch(vec![1, 2], vec!["Ada", "Grace"])?).await?;
+//! loader.load(batch(vec![3], vec!["Linus"])?).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use async_trait::async_trait;
+use datafusion::arrow::record_batch::RecordBatch;
+use deltalake::DeltaOps;
+use deltalake::protocol::SaveMode;
+use reqwest::Url;
+
+use super::Loader;
+
+/// Appends record batches to a Delta table.
+///
+/// The table location may be a local path or an object-store URL such as
+/// `s3://bucket/table`. Object-store schemes other than the local filesystem
+/// need their `deltalake` storage handlers registered (e.g.
+/// `deltalake::aws::register_handlers`) before the first load. Delta Lake
+/// failures are reported as [`ExtractorError::ExtractOpsError`].
+#[derive(Debug, Clone)]
+pub struct DeltaLoader {
+    location: String,
+}
+
+impl DeltaLoader {
+    /// Create a loader for the Delta table at `location`.
+    ///
+    /// Nothing is read or created until the first [`load`](Self::load).
+    ///
+    /// # Parameters
+    ///
+    /// - `location`: A local directory or object-store URL for the table
+    pub fn new(location: &str) -> Self {
+        Self {
+            location: location.to_string(),
+        }
+    }
+
+    /// The table location as given to [`new`](Self::new).
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Append `batch` to the table as a single commit.
+    ///
+    /// If no table exists at the location, one is created with the batch's
+    /// schema. Later batches must have a compatible schema.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` once the commit has been written
+    /// - `Err(ExtractorError)` if the location is invalid or the write fails
+    pub async fn load(&self, batch: RecordBatch) -> ExtractorResult<()> {
+        let url = self.table_url().await?;
+        let ops = DeltaOps::try_from_uri(url).await.map_err(delta_error)?;
+        ops.write(vec![batch])
+            .with_save_mode(SaveMode::Append)
+            .await
+            .map_err(delta_error)?;
+        Ok(())
+    }
+
+    /// Resolve the location to a URL, creating the directory for local tables.
+    async fn table_url(&self) -> ExtractorResult<Url> {
+        if let Ok(url) = Url::parse(&self.location)
+            && url.scheme().len() > 1
+        {
+            return Ok(url);
+        }
+        let path = std::path::absolute(Path::new(&self.location))?;
+        tokio::fs::create_dir_all(&path).await?;
+        Url::from_directory_path(&path).map_err(|_| {
+            ExtractorError::ExtractOpsError(format!(
+                "Invalid Delta table path '{}'",
+                path.display()
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl Loader for DeltaLoader {
+    async fn load(&self, batch: RecordBatch) -> ExtractorResult<()> {
+        DeltaLoader::load(self, batch).await
+    }
+}
+
+fn delta_error(err: deltalake::DeltaTableError) -> ExtractorError {
+    ExtractorError::ExtractOpsError(format!("Delta Lake error: {err}"))
+}
@@ -1 +1,41 @@
-pub mod s3_loader;
\ No newline at end of file
+//! Loaders that write extracted and transformed data to its destination.
+//!
+//! Each loader lives in its own module. Loaders that write whole batches
+//! implement [`Loader`]; loads that can partially succeed report their outcome
+//! as a [`LoadReport`].
+
+use async_trait::async_trait;
+use datafusion::arrow::record_batch::RecordBatch;
+
+use anduflow_utils::error::ExtractorResult;
+
+#[cfg(feature = "delta")]
+pub mod delta_loader;
+pub mod parquet_loader;
+pub mod s3_loader;
+pub mod sqlite_loader;
+
+/// A destination that record batches are written to.
+#[async_trait]
+pub trait Loader: Send + Sync {
+    /// Write `batch` to the destination.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` once the batch has been accepted
+    /// - `Err(ExtractorError)` if the batch could not be written
+    async fn load(&self, batch: RecordBatch) -> ExtractorResult<()>;
+}
+
+/// The outcome of a best-effort load.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadReport {
+    /// Rows written successfully.
+    pub loaded: usize,
+    /// Rows that failed to be written.
+    pub failed: usize,
+    /// One message per failed row, in row order.
+    pub errors: Vec<String>,
+    /// `true` if the load stopped early because too many rows failed.
+    pub aborted: bool,
+}
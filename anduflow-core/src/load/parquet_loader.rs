@@ -0,0 +1,119 @@
+//! Loading of record batches into Parquet files.
+//!
+//! This module provides [`ParquetLoader`], which writes every batch it is given
+//! to a single Parquet file until it is [finished](ParquetLoader::finish).
+//!
+//! # Examples
+//!
+//! ```
+//! use std::fs::File;
+//! use std::sync::Arc;
+//! use datafusion::arrow::array::Int64Array;
+//! use datafusion::arrow::datatypes::{DataType, Field, Schema};
+//! use datafusion::arrow::record_batch::RecordBatch;
+//! use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+//! use anduflow_core::load::Loader;
+//! use anduflow_core::load::parquet_loader::ParquetLoader;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+//! let batch = |ids: Vec<i64>| {
+//!     RecordBatch::try_new(schema.clone(), vec![Arc::new(Int64Array::from(ids))]).unwrap()
+//! };
+//!
+//! let path = std::env::temp_dir().join("anduflow-parquet-doctest.parquet");
+//! let loader = ParquetLoader::new(&path);
+//! loader.load(batch(vec![1, 2, 3])).await.unwrap();
+//! loader.load(batch(vec![4, 5])).await.unwrap();
+//! loader.finish().unwrap();
+//!
+//! let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+//!     .unwrap()
+//!     .build()
+//!     .unwrap();
+//! let rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+//! assert_eq!(rows, 5);
+//! # }
+//! ```
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::parquet::arrow::ArrowWriter;
+
+use super::Loader;
+
+/// Writes record batches to a Parquet file.
+///
+/// The file is created (truncating any existing file) on the first load, with
+/// the first batch's schema; every later batch is appended to it and must have
+/// the same schema. The file is only valid Parquet once
+/// [`finish`](Self::finish) has written its footer.
+#[derive(Debug)]
+pub struct ParquetLoader {
+    path: PathBuf,
+    writer: Mutex<Option<(ArrowWriter<File>, SchemaRef)>>,
+}
+
+impl ParquetLoader {
+    /// Create a loader writing to `path`.
+    ///
+    /// Nothing is written until the first [`load`](Loader::load).
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            writer: Mutex::new(None),
+        }
+    }
+
+    /// The path of the output file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Flush buffered rows and write the file footer.
+    ///
+    /// Does nothing if no batch has been loaded. A later load starts a new
+    /// file at the same path.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` once the file is complete
+    /// - `Err(ExtractorError)` if the file could not be written
+    pub fn finish(&self) -> ExtractorResult<()> {
+        if let Some((writer, _)) = self.writer.lock().unwrap().take() {
+            writer.close()?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Loader for ParquetLoader {
+    /// Append `batch` to the file, creating it on the first load.
+    async fn load(&self, batch: RecordBatch) -> ExtractorResult<()> {
+        let mut guard = self.writer.lock().unwrap();
+        let (writer, schema) = match guard.as_mut() {
+            Some(open) => open,
+            None => {
+                let file = File::create(&self.path)?;
+                let writer = ArrowWriter::try_new(file, batch.schema(), None)?;
+                guard.insert((writer, batch.schema()))
+            }
+        };
+        if *schema != batch.schema() {
+            return Err(ExtractorError::ExtractOpsError(format!(
+                "Batch schema does not match the schema of '{}'",
+                self.path.display()
+            )));
+        }
+        writer.write(&batch)?;
+        Ok(())
+    }
+}
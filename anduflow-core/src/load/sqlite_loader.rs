@@ -0,0 +1,215 @@
+//! Loading of record batches into SQLite tables.
+//!
+//! This module provides [`SqliteLoader`], which inserts each row of a
+//! [`RecordBatch`] into an existing table, tolerating a configurable fraction
+//! of failed rows.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::{Arc, Mutex};
+//! use rusqlite::Connection;
+//! use anduflow_core::load::sqlite_loader::SqliteLoader;
+//!
+//! let conn = Arc::new(Mutex::new(Connection::open_in_memory().unwrap()));
+//! let loader = SqliteLoader::new(conn, "users").with_max_error_fraction(0.01);
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use async_trait::async_trait;
+use datafusion::arrow::array::{Array, ArrayRef, AsArray};
+use datafusion::arrow::datatypes::{
+    DataType, Float32Type, Float64Type, Int8Type, Int16Type, Int32Type, Int64Type, UInt8Type,
+    UInt16Type, UInt32Type, UInt64Type,
+};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::arrow::util::display::{ArrayFormatter, FormatOptions};
+use rusqlite::Connection;
+use rusqlite::types::Value as SqlValue;
+
+use super::{LoadReport, Loader};
+
+/// Inserts the rows of a batch into a SQLite table, one row at a time.
+///
+/// Columns are matched to table columns by name. Integers, floats, booleans
+/// and strings are stored natively; other types are stored as their display
+/// text. Rows that fail (e.g. on a constraint violation) are counted and
+/// skipped until the fraction of failed rows in the batch exceeds
+/// [`with_max_error_fraction`](SqliteLoader::with_max_error_fraction), at which
+/// point the load stops. Rows loaded before that point are kept.
+///
+/// As a [`Loader`], an aborted load is an error; the report of the most recent
+/// load is available from [`last_report`](SqliteLoader::last_report).
+#[derive(Debug)]
+pub struct SqliteLoader {
+    conn: Arc<Mutex<Connection>>,
+    table: String,
+    max_error_fraction: f64,
+    last_report: Mutex<Option<LoadReport>>,
+}
+
+impl SqliteLoader {
+    /// Create a loader for `table`, which must already exist.
+    ///
+    /// By default any failed row aborts the load.
+    ///
+    /// # Parameters
+    ///
+    /// - `conn`: The SQLite connection, locked for the duration of each load
+    /// - `table`: The name of the destination table
+    pub fn new(conn: Arc<Mutex<Connection>>, table: &str) -> Self {
+        Self {
+            conn,
+            table: table.to_string(),
+            max_error_fraction: 0.0,
+            last_report: Mutex::new(None),
+        }
+    }
+
+    /// Tolerate up to this fraction (`0.0..=1.0`) of a batch's rows failing.
+    ///
+    /// For example, `0.05` continues past failures as long as no more than
+    /// 5% of the rows have failed.
+    pub fn with_max_error_fraction(mut self, fraction: f64) -> Self {
+        self.max_error_fraction = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Insert every row of `batch` into the table.
+    ///
+    /// All inserts run in a single transaction, which is committed even when
+    /// the load is aborted so the rows loaded so far are kept.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(LoadReport)` with the number of loaded and failed rows; `aborted`
+    ///   is set if the failure threshold was exceeded
+    /// - `Err(ExtractorError)` if the insert could not be prepared or the
+    ///   transaction failed, e.g. because the table does not exist
+    pub fn load_with_report(&self, batch: &RecordBatch) -> ExtractorResult<LoadReport> {
+        let schema = batch.schema();
+        let columns: Vec<String> = schema
+            .fields()
+            .iter()
+            .map(|field| quote_identifier(field.name()))
+            .collect();
+        let placeholders: Vec<String> = (1..=columns.len()).map(|i| format!("?{i}")).collect();
+        let sql = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            quote_identifier(&self.table),
+            columns.join(", "),
+            placeholders.join(", ")
+        );
+
+        let max_failed = (batch.num_rows() as f64 * self.max_error_fraction).floor() as usize;
+        let mut report = LoadReport::default();
+
+        let conn = self.conn.lock().unwrap();
+        let tx = conn.unchecked_transaction()?;
+        {
+            let mut stmt = tx.prepare(&sql)?;
+            for row in 0..batch.num_rows() {
+                let result = batch
+                    .columns()
+                    .iter()
+                    .map(|column| sql_value(column, row))
+                    .collect::<ExtractorResult<Vec<_>>>()
+                    .and_then(|values| Ok(stmt.execute(rusqlite::params_from_iter(values))?));
+                match result {
+                    Ok(_) => report.loaded += 1,
+                    Err(err) => {
+                        report.failed += 1;
+                        report.errors.push(format!("row {row}: {err}"));
+                        if report.failed > max_failed {
+                            report.aborted = true;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        tx.commit()?;
+
+        if report.aborted {
+            tracing::warn!(
+                "Aborted load into '{}' after {} of {} rows failed",
+                self.table,
+                report.failed,
+                batch.num_rows()
+            );
+        }
+        *self.last_report.lock().unwrap() = Some(report.clone());
+        Ok(report)
+    }
+
+    /// The report of the most recent load, if any.
+    pub fn last_report(&self) -> Option<LoadReport> {
+        self.last_report.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Loader for SqliteLoader {
+    /// Insert every row of `batch`, failing if the load was aborted.
+    async fn load(&self, batch: RecordBatch) -> ExtractorResult<()> {
+        let report = self.load_with_report(&batch)?;
+        if report.aborted {
+            return Err(ExtractorError::ExtractOpsError(format!(
+                "Aborted load into '{}' after {} of {} rows failed: {}",
+                self.table,
+                report.failed,
+                batch.num_rows(),
+                report.errors.join("; ")
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Convert one cell of an Arrow column to a SQLite value.
+fn sql_value(column: &ArrayRef, row: usize) -> ExtractorResult<SqlValue> {
+    if column.is_null(row) {
+        return Ok(SqlValue::Null);
+    }
+    let value = match column.data_type() {
+        DataType::Boolean => SqlValue::Integer(column.as_boolean().value(row) as i64),
+        DataType::Int8 => SqlValue::Integer(column.as_primitive::<Int8Type>().value(row).into()),
+        DataType::Int16 => SqlValue::Integer(column.as_primitive::<Int16Type>().value(row).into()),
+        DataType::Int32 => SqlValue::Integer(column.as_primitive::<Int32Type>().value(row).into()),
+        DataType::Int64 => SqlValue::Integer(column.as_primitive::<Int64Type>().value(row)),
+        DataType::UInt8 => SqlValue::Integer(column.as_primitive::<UInt8Type>().value(row).into()),
+        DataType::UInt16 => {
+            SqlValue::Integer(column.as_primitive::<UInt16Type>().value(row).into())
+        }
+        DataType::UInt32 => {
+            SqlValue::Integer(column.as_primitive::<UInt32Type>().value(row).into())
+        }
+        DataType::UInt64 => {
+            let value = column.as_primitive::<UInt64Type>().value(row);
+            SqlValue::Integer(i64::try_from(value).map_err(|_| {
+                ExtractorError::ExtractOpsError(format!(
+                    "Value {value} does not fit in a SQLite integer"
+                ))
+            })?)
+        }
+        DataType::Float32 => SqlValue::Real(column.as_primitive::<Float32Type>().value(row).into()),
+        DataType::Float64 => SqlValue::Real(column.as_primitive::<Float64Type>().value(row)),
+        DataType::Utf8 => SqlValue::Text(column.as_string::<i32>().value(row).to_string()),
+        DataType::LargeUtf8 => SqlValue::Text(column.as_string::<i64>().value(row).to_string()),
+        DataType::Binary => SqlValue::Blob(column.as_binary::<i32>().value(row).to_vec()),
+        DataType::LargeBinary => SqlValue::Blob(column.as_binary::<i64>().value(row).to_vec()),
+        _ => {
+            let options = FormatOptions::default();
+            let formatter = ArrayFormatter::try_new(column.as_ref(), &options)?;
+            SqlValue::Text(formatter.value(row).to_string())
+        }
+    };
+    Ok(value)
+}
+
+/// Quote a table or column name for use in SQL.
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
@@ -0,0 +1,407 @@
+//! Extract, transform and load runs.
+//!
+//! A [`Pipeline`] extracts JSON records from one [`Extractor`], converts them
+//! to a [`RecordBatch`] with an [`ApiToArrowConverter`], passes the batch
+//! through its [`Transform`]s in order and writes the result with a
+//! [`Loader`]. Each run is recorded in a [`LogStore`], and the extractor is
+//! closed once the run is over. [`Pipeline::run_incremental`] additionally
+//! keeps an incremental extractor's checkpoint in a SQLite table between runs,
+//! so scheduled runs only fetch new data even across process restarts.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use anduflow_core::extract::rest_extractor::RestExtractor;
+//! use anduflow_core::load::parquet_loader::ParquetLoader;
+//! use anduflow_core::pipeline::{Pipeline, Stage};
+//! use anduflow_core::transform::select_columns::SelectColumns;
+//! use anduflow_utils::logger::store::LogStore;
+//!
+//! # async fn example() -> anduflow_utils::error::ExtractorResult<()> {
+//! let mut pipeline = Pipeline::new(
+//!     RestExtractor::new("https://api.example.com", "users"),
+//!     ParquetLoader::new("users.parquet"),
+//! )
+//! .with_transform(SelectColumns::new(&["id", "email"]))
+//! .with_stage_timeout(Stage::Extract, Duration::from_secs(30));
+//!
+//! let mut log = LogStore::new("users".to_string(), "pipeline".to_string());
+//! let rows = pipeline.run(&mut log).await?;
+//! println!("Loaded {rows} rows");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use datafusion::arrow::record_batch::RecordBatch;
+use rusqlite::{Connection, OptionalExtension};
+use serde_json::Value;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::{ensure_table_matches, format_timestamp, LogStore};
+
+use crate::extract::{Checkpoint, Extractor};
+use crate::extract::json_to_arrow::ApiToArrowConverter;
+use crate::load::Loader;
+use crate::transform::Transform;
+
+/// The table [`Pipeline::run_incremental`] keeps checkpoints in.
+pub const CHECKPOINTS_TABLE: &str = "etl_checkpoints";
+
+/// A stage of a [`Pipeline`] run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    /// Extracting records from the source and converting them to Arrow.
+    Extract,
+    /// Applying the transforms.
+    Transform,
+    /// Writing the batch to the destination.
+    Load,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Stage::Extract => "extract",
+            Stage::Transform => "transform",
+            Stage::Load => "load",
+        })
+    }
+}
+
+/// Runs one extractor's records through a chain of transforms into a loader.
+pub struct Pipeline {
+    name: Option<String>,
+    extractor: Box<dyn Extractor + Send + Sync>,
+    converter: ApiToArrowConverter,
+    transforms: Vec<Box<dyn Transform>>,
+    loader: Box<dyn Loader>,
+    stage_timeouts: HashMap<Stage, Duration>,
+}
+
+impl fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("name", &self.name)
+            .field("extractor", &self.extractor.source_name().unwrap_or("<unnamed>"))
+            .field("converter", &self.converter)
+            .field("transforms", &self.transforms.len())
+            .field("stage_timeouts", &self.stage_timeouts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Pipeline {
+    /// Create a pipeline loading the records of `extractor` with `loader`.
+    ///
+    /// # Parameters
+    ///
+    /// - `extractor`: The source of the records
+    /// - `loader`: The destination of the converted batch
+    pub fn new(
+        extractor: impl Extractor + Send + Sync + 'static,
+        loader: impl Loader + 'static,
+    ) -> Self {
+        Self {
+            name: None,
+            extractor: Box::new(extractor),
+            converter: ApiToArrowConverter::new(),
+            transforms: Vec::new(),
+            loader: Box::new(loader),
+            stage_timeouts: HashMap::new(),
+        }
+    }
+
+    /// Name the pipeline, keying its checkpoint in
+    /// [`run_incremental`](Self::run_incremental).
+    ///
+    /// Without a name the extractor's [`source_name`](Extractor::source_name)
+    /// is used, which is only unique if no other pipeline sharing the
+    /// checkpoint table reads from the same kind of source.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Convert extracted records with `converter` instead of the default one.
+    pub fn with_converter(mut self, converter: ApiToArrowConverter) -> Self {
+        self.converter = converter;
+        self
+    }
+
+    /// Apply `transform` to the batch after the transforms added before it.
+    pub fn with_transform(mut self, transform: impl Transform + 'static) -> Self {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+
+    /// Fail the run if `stage` takes longer than `timeout`.
+    ///
+    /// Each stage is bounded separately; the transform stage's timeout covers
+    /// all transforms together. A stage without a timeout may run indefinitely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use anduflow_core::extract::file_extractor::FileExtractor;
+    /// use anduflow_core::load::parquet_loader::ParquetLoader;
+    /// use anduflow_core::pipeline::{Pipeline, Stage};
+    ///
+    /// let extractor = FileExtractor::new("users.json");
+    /// let pipeline = Pipeline::new(extractor, ParquetLoader::new("users.parquet"))
+    ///     .with_stage_timeout(Stage::Extract, Duration::from_secs(30))
+    ///     .with_stage_timeout(Stage::Load, Duration::from_secs(60));
+    /// ```
+    pub fn with_stage_timeout(mut self, stage: Stage, timeout: Duration) -> Self {
+        self.stage_timeouts.insert(stage, timeout);
+        self
+    }
+
+    /// Extract, transform and load once, recording the run in `log`.
+    ///
+    /// The extracted JSON is treated as an array of records, or as a single
+    /// record if it is an object. An empty result skips the transform and
+    /// load stages. The log is marked in progress, given the number of rows
+    /// loaded as its progress and marked completed, or marked failed with a
+    /// message naming the stage that failed or timed out.
+    ///
+    /// Whatever the outcome, the extractor is then [closed](Extractor::close)
+    /// to release its connections. A close error does not fail the run; it is
+    /// stored under the `close_error` metadata key of `log`, and appended to
+    /// the failure message of a failed run.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(usize)` with the number of rows loaded
+    /// - `Err(ExtractorError)` from the first stage that failed, or an
+    ///   `ExtractOpsError` naming the stage that exceeded its timeout
+    pub async fn run(&mut self, log: &mut LogStore) -> ExtractorResult<usize> {
+        self.run_logged(log, None).await
+    }
+
+    /// Like [`run`](Self::run), resuming from the checkpoint stored in `conn`
+    /// and storing the advanced checkpoint once the batch is loaded.
+    ///
+    /// Before extracting, the checkpoint stored under this pipeline's
+    /// [name](Self::with_name) in the [`CHECKPOINTS_TABLE`] table, created if
+    /// needed, is applied with [`set_checkpoint`](Extractor::set_checkpoint).
+    /// After the load, the extractor's [`checkpoint`](Extractor::checkpoint)
+    /// is written back, so the next run, in this process or another, only
+    /// asks for newer data. A failed run keeps the stored checkpoint. Loading
+    /// the checkpoint counts towards the extract stage and storing it towards
+    /// the load stage, so failures are logged under those stages.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(usize)` with the number of rows loaded
+    /// - `Err(ExtractorError)` as for [`run`](Self::run), or if the extractor
+    ///   does not [support incremental](Extractor::supports_incremental)
+    ///   extraction or the checkpoint could not be read or written
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    /// use rusqlite::Connection;
+    /// use anduflow_core::extract::rest_extractor::RestExtractor;
+    /// use anduflow_core::load::parquet_loader::ParquetLoader;
+    /// use anduflow_core::pipeline::Pipeline;
+    /// use anduflow_utils::logger::store::LogStore;
+    ///
+    /// # async fn example() -> anduflow_utils::error::ExtractorResult<()> {
+    /// let conn = Arc::new(Connection::open("anduflow_logs.db")?);
+    /// let extractor = RestExtractor::new("https://api.example.com", "events")
+    ///     .with_incremental("since", "updated_at");
+    /// let mut pipeline = Pipeline::new(extractor, ParquetLoader::new("events.parquet"))
+    ///     .with_name("events");
+    ///
+    /// let mut log = LogStore::new("events".to_string(), "pipeline".to_string());
+    /// pipeline.run_incremental(&conn, &mut log).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_incremental(
+        &mut self,
+        conn: &Arc<Connection>,
+        log: &mut LogStore,
+    ) -> ExtractorResult<usize> {
+        self.run_logged(log, Some(conn)).await
+    }
+
+    /// Run, with checkpoints kept in `checkpoints` if given, recording the
+    /// outcome in `log` and closing the extractor afterwards.
+    async fn run_logged(
+        &mut self,
+        log: &mut LogStore,
+        checkpoints: Option<&Arc<Connection>>,
+    ) -> ExtractorResult<usize> {
+        log.mark_in_progress();
+        let mut stage = Stage::Extract;
+        let result = self.run_stages(&mut stage, checkpoints).await;
+        let close_error = self.close(log).await;
+        match result {
+            Ok(rows) => {
+                log.update_progress(rows, rows);
+                log.mark_completed();
+                Ok(rows)
+            }
+            Err(err) => {
+                log.mark_failed(match close_error {
+                    Some(close_error) => format!(
+                        "{stage} stage failed: {err}; closing the extractor also failed: {close_error}"
+                    ),
+                    None => format!("{stage} stage failed: {err}"),
+                });
+                Err(err)
+            }
+        }
+    }
+
+    /// Close the extractor, recording any error in `log` and returning it.
+    async fn close(&mut self, log: &mut LogStore) -> Option<String> {
+        let err = self.extractor.close().await.err()?.to_string();
+        tracing::warn!("Failed to close the pipeline's extractor: {err}");
+        log.insert_metadata("close_error", Value::String(err.clone()));
+        Some(err)
+    }
+
+    /// Run every stage, setting `stage` to each one as it starts, and return
+    /// the number of rows loaded.
+    async fn run_stages(
+        &mut self,
+        stage: &mut Stage,
+        checkpoints: Option<&Arc<Connection>>,
+    ) -> ExtractorResult<usize> {
+        if let Some(conn) = checkpoints {
+            self.restore_checkpoint(conn)?;
+        }
+        let rows = match self.stage(Stage::Extract, self.extract()).await? {
+            Some(batch) => {
+                *stage = Stage::Transform;
+                let batch = self.stage(Stage::Transform, self.transform(batch)).await?;
+                let rows = batch.num_rows();
+                *stage = Stage::Load;
+                self.stage(Stage::Load, self.loader.load(batch)).await?;
+                rows
+            }
+            None => 0,
+        };
+        *stage = Stage::Load;
+        if let Some(conn) = checkpoints
+            && let Some(checkpoint) = self.extractor.checkpoint()
+        {
+            save_checkpoint(conn, self.checkpoint_key()?, &checkpoint)?;
+        }
+        Ok(rows)
+    }
+
+    /// The key of this pipeline's row in the checkpoint table.
+    fn checkpoint_key(&self) -> ExtractorResult<&str> {
+        match &self.name {
+            Some(name) => Ok(name),
+            None => self.extractor.source_name(),
+        }
+    }
+
+    /// Apply the stored checkpoint, if any, to the extractor.
+    fn restore_checkpoint(&mut self, conn: &Arc<Connection>) -> ExtractorResult<()> {
+        if !self.extractor.supports_incremental() {
+            return Err(ExtractorError::ExtractOpsError(format!(
+                "Source '{}' does not support incremental extraction",
+                self.checkpoint_key()?
+            )));
+        }
+        if let Some(checkpoint) = load_checkpoint(conn, self.checkpoint_key()?)? {
+            self.extractor.set_checkpoint(checkpoint)?;
+        }
+        Ok(())
+    }
+
+    /// Extract the records and convert them, or `None` if there are none.
+    async fn extract(&self) -> ExtractorResult<Option<RecordBatch>> {
+        let records = self.extractor.extract_records().await?;
+        if records.is_empty() {
+            return Ok(None);
+        }
+        self.converter.convert(&records).map(Some)
+    }
+
+    /// Apply every transform in order.
+    async fn transform(&self, mut batch: RecordBatch) -> ExtractorResult<RecordBatch> {
+        for transform in &self.transforms {
+            batch = transform.transform(batch).await?;
+        }
+        Ok(batch)
+    }
+
+    /// Run `work` under the timeout configured for `stage`, if any.
+    async fn stage<T>(
+        &self,
+        stage: Stage,
+        work: impl Future<Output = ExtractorResult<T>>,
+    ) -> ExtractorResult<T> {
+        match self.stage_timeouts.get(&stage) {
+            Some(&timeout) => tokio::time::timeout(timeout, work).await.unwrap_or_else(|_| {
+                Err(ExtractorError::ExtractOpsError(format!(
+                    "{stage} stage timed out after {timeout:?}"
+                )))
+            }),
+            None => work.await,
+        }
+    }
+}
+
+/// Create the [`CHECKPOINTS_TABLE`] table if it does not exist.
+///
+/// # Returns
+///
+/// - `Ok(true)` if the table was created, `Ok(false)` if it already existed
+/// - `Err(ExtractorError::SchemaMismatch)` if an existing table has drifted
+pub fn ensure_checkpoints_table_exists(conn: &Arc<Connection>) -> ExtractorResult<bool> {
+    ensure_table_matches(
+        conn,
+        CHECKPOINTS_TABLE,
+        r#"
+        CREATE TABLE etl_checkpoints (
+            pipeline TEXT PRIMARY KEY,
+            checkpoint TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+    )
+}
+
+/// Read the checkpoint stored for `pipeline`, if any.
+fn load_checkpoint(conn: &Arc<Connection>, pipeline: &str) -> ExtractorResult<Option<Checkpoint>> {
+    ensure_checkpoints_table_exists(conn)?;
+    let checkpoint = conn
+        .query_row(
+            "SELECT checkpoint FROM etl_checkpoints WHERE pipeline = ?1",
+            [pipeline],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(checkpoint.map(Checkpoint))
+}
+
+/// Store `checkpoint` for `pipeline`, replacing any earlier one.
+fn save_checkpoint(
+    conn: &Arc<Connection>,
+    pipeline: &str,
+    checkpoint: &Checkpoint,
+) -> ExtractorResult<()> {
+    conn.execute(
+        "INSERT INTO etl_checkpoints (pipeline, checkpoint, updated_at) VALUES (?1, ?2, ?3)
+         ON CONFLICT(pipeline) DO UPDATE SET
+             checkpoint = excluded.checkpoint, updated_at = excluded.updated_at",
+        [pipeline, checkpoint.0.as_str(), format_timestamp(&Utc::now()).as_str()],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//! Coalescing of several source columns into one.
+//!
+//! This module provides [`CoalesceTransformer`], which builds a column from
+//! the first non-null value across a list of input columns, like SQL's
+//! `COALESCE`.
+//!
+//! # Examples
+//!
+//! ```
+//! use anduflow_core::transform::coalesce_transformer::CoalesceTransformer;
+//!
+//! let coalesce = CoalesceTransformer::new(
+//!     "email".to_string(),
+//!     vec!["work_email".to_string(), "personal_email".to_string()],
+//! );
+//! ```
+
+use std::sync::Arc;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use async_trait::async_trait;
+use datafusion::arrow::array::ArrayRef;
+use datafusion::arrow::compute::kernels::zip::zip;
+use datafusion::arrow::compute::{cast_with_options, is_not_null};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+
+use super::Transform;
+use crate::arrow::{widen_type, LOSSLESS_CAST};
+
+/// Produces a column holding, per row, the first non-null value across the
+/// input columns.
+///
+/// Inputs with different but compatible types (e.g. `Int32` and `Int64`, or
+/// `Utf8` and `LargeUtf8`) are widened to a common type first. The output
+/// column replaces a column of the same name, or is appended otherwise; the
+/// input columns are kept.
+#[derive(Debug, Clone)]
+pub struct CoalesceTransformer {
+    output: String,
+    inputs: Vec<String>,
+}
+
+impl CoalesceTransformer {
+    /// Create a transformer that coalesces `inputs`, in priority order, into `output`.
+    ///
+    /// # Parameters
+    ///
+    /// - `output`: The name of the produced column
+    /// - `inputs`: The source columns, highest priority first
+    pub fn new(output: String, inputs: Vec<String>) -> Self {
+        Self { output, inputs }
+    }
+
+    /// Add the coalesced column to `batch`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(RecordBatch)` with the output column added or replaced
+    /// - `Err(ExtractorError)` if no inputs were configured, an input is missing,
+    ///   or the inputs have incompatible types
+    pub fn coalesce(&self, batch: &RecordBatch) -> ExtractorResult<RecordBatch> {
+        let schema = batch.schema();
+        let mut inputs = Vec::with_capacity(self.inputs.len());
+        for name in &self.inputs {
+            let (index, field) = schema.column_with_name(name).ok_or_else(|| {
+                ExtractorError::ExtractOpsError(format!("Column '{name}' not found"))
+            })?;
+            inputs.push((field, batch.column(index)));
+        }
+
+        let (first, rest) = inputs.split_first().ok_or_else(|| {
+            ExtractorError::ExtractOpsError("CoalesceTransformer needs at least one input".into())
+        })?;
+        let mut target = first.0.data_type().clone();
+        for (field, _) in rest {
+            target = widen_type(&target, field.data_type()).ok_or_else(|| {
+                ExtractorError::ExtractOpsError(format!(
+                    "Cannot coalesce column '{}' ({}) with the preceding inputs ({target})",
+                    field.name(),
+                    field.data_type()
+                ))
+            })?;
+        }
+        let nullable = inputs.iter().all(|(field, _)| field.is_nullable());
+
+        let mut result: ArrayRef = cast_with_options(first.1, &target, &LOSSLESS_CAST)?;
+        for &(_, column) in rest {
+            let fallback = cast_with_options(column, &target, &LOSSLESS_CAST)?;
+            result = zip(&is_not_null(&result)?, &result, &fallback)?;
+        }
+
+        let mut fields: Vec<Arc<Field>> = schema.fields().iter().cloned().collect();
+        let mut columns = batch.columns().to_vec();
+        let output_field = Arc::new(Field::new(&self.output, target, nullable));
+        match schema.index_of(&self.output) {
+            Ok(index) => {
+                fields[index] = output_field;
+                columns[index] = result;
+            }
+            Err(_) => {
+                fields.push(output_field);
+                columns.push(result);
+            }
+        }
+
+        let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+        Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+    }
+}
+
+#[async_trait]
+impl Transform for CoalesceTransformer {
+    async fn transform(&self, input: RecordBatch) -> ExtractorResult<RecordBatch> {
+        self.coalesce(&input)
+    }
+}
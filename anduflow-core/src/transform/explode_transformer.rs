@@ -0,0 +1,136 @@
+//! Unnesting of list columns.
+//!
+//! This module provides [`ExplodeTransformer`], which turns each element of a
+//! `List` column into its own row, duplicating the values of the other columns.
+//!
+//! # Examples
+//!
+//! ```
+//! use anduflow_core::transform::explode_transformer::ExplodeTransformer;
+//!
+//! let explode = ExplodeTransformer::new("tags").with_keep_empty(true);
+//! ```
+
+use std::sync::Arc;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use async_trait::async_trait;
+use datafusion::arrow::array::{Array, ArrayRef, AsArray, UInt64Array};
+use datafusion::arrow::compute::take;
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+
+use super::Transform;
+
+/// Explodes a list column so that each list element becomes its own row.
+///
+/// Every other column is repeated once per element. Rows whose list is empty
+/// or null are dropped by default, or kept as a single row with a null element
+/// when [`with_keep_empty`](ExplodeTransformer::with_keep_empty) is enabled.
+#[derive(Debug, Clone)]
+pub struct ExplodeTransformer {
+    column: String,
+    keep_empty: bool,
+}
+
+impl ExplodeTransformer {
+    /// Create a transformer that explodes `column`.
+    ///
+    /// # Parameters
+    ///
+    /// - `column`: The name of a `List` or `LargeList` column
+    pub fn new(column: &str) -> Self {
+        Self {
+            column: column.to_string(),
+            keep_empty: false,
+        }
+    }
+
+    /// Keep rows whose list is empty or null, with a null in the exploded column.
+    pub fn with_keep_empty(mut self, keep_empty: bool) -> Self {
+        self.keep_empty = keep_empty;
+        self
+    }
+
+    /// Explode the configured column of `batch`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(RecordBatch)` with one row per list element; the exploded column
+    ///   has the list's element type
+    /// - `Err(ExtractorError)` if the column is missing or is not a list
+    pub fn explode(&self, batch: &RecordBatch) -> ExtractorResult<RecordBatch> {
+        let schema = batch.schema();
+        let (index, field) = schema.column_with_name(&self.column).ok_or_else(|| {
+            ExtractorError::ExtractOpsError(format!("Column '{}' not found", self.column))
+        })?;
+        let column = batch.column(index);
+
+        let (values, offsets, element): (ArrayRef, Vec<usize>, &Arc<Field>) =
+            match field.data_type() {
+                DataType::List(element) => {
+                    let list = column.as_list::<i32>();
+                    let offsets = list.value_offsets().iter().map(|o| *o as usize).collect();
+                    (list.values().clone(), offsets, element)
+                }
+                DataType::LargeList(element) => {
+                    let list = column.as_list::<i64>();
+                    let offsets = list.value_offsets().iter().map(|o| *o as usize).collect();
+                    (list.values().clone(), offsets, element)
+                }
+                other => {
+                    return Err(ExtractorError::ExtractOpsError(format!(
+                        "Column '{}' is {other}, not a list",
+                        self.column
+                    )));
+                }
+            };
+
+        let mut row_indices: Vec<u64> = Vec::new();
+        let mut value_indices: Vec<Option<u64>> = Vec::new();
+        for row in 0..batch.num_rows() {
+            let (start, end) = (offsets[row], offsets[row + 1]);
+            if column.is_null(row) || start == end {
+                if self.keep_empty {
+                    row_indices.push(row as u64);
+                    value_indices.push(None);
+                }
+                continue;
+            }
+            for value in start..end {
+                row_indices.push(row as u64);
+                value_indices.push(Some(value as u64));
+            }
+        }
+        let row_indices = UInt64Array::from(row_indices);
+        let value_indices = UInt64Array::from(value_indices);
+
+        let mut fields = Vec::with_capacity(schema.fields().len());
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for (i, f) in schema.fields().iter().enumerate() {
+            if i == index {
+                fields.push(Arc::new(Field::new(
+                    f.name(),
+                    element.data_type().clone(),
+                    true,
+                )));
+                columns.push(take(values.as_ref(), &value_indices, None)?);
+            } else {
+                fields.push(f.clone());
+                columns.push(take(batch.column(i).as_ref(), &row_indices, None)?);
+            }
+        }
+
+        Ok(RecordBatch::try_new(
+            Arc::new(Schema::new(fields)),
+            columns,
+        )?)
+    }
+}
+
+#[async_trait]
+impl Transform for ExplodeTransformer {
+    async fn transform(&self, input: RecordBatch) -> ExtractorResult<RecordBatch> {
+        self.explode(&input)
+    }
+}
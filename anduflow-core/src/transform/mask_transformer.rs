@@ -0,0 +1,123 @@
+//! Masking of personally identifiable information.
+//!
+//! This module provides [`MaskTransformer`], which hashes or masks configured
+//! string columns so sensitive values never reach the destination.
+//!
+//! # Examples
+//!
+//! ```
+//! use anduflow_core::transform::mask_transformer::{MaskMode, MaskTransformer};
+//!
+//! let mask = MaskTransformer::new(vec![
+//!     ("email".to_string(), MaskMode::Sha256),
+//!     ("phone".to_string(), MaskMode::Partial(4)),
+//! ]);
+//! ```
+
+use std::sync::Arc;
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use async_trait::async_trait;
+use datafusion::arrow::array::{ArrayRef, AsArray, GenericStringArray, OffsetSizeTrait};
+use datafusion::arrow::datatypes::DataType;
+use datafusion::arrow::record_batch::RecordBatch;
+use sha2::{Digest, Sha256};
+
+use super::Transform;
+
+/// Replacement value used by [`MaskMode::Redact`].
+const REDACTED: &str = "REDACTED";
+
+/// How the values of a masked column are rewritten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaskMode {
+    /// Replace the value with its lowercase hex SHA-256 digest.
+    ///
+    /// Equal inputs hash to equal outputs, so masked columns can still be joined.
+    Sha256,
+    /// Replace the value with a fixed `REDACTED` marker.
+    Redact,
+    /// Replace every character except the last `keep_last` with `*`.
+    Partial(usize),
+}
+
+impl MaskMode {
+    fn apply(&self, value: &str) -> String {
+        match self {
+            MaskMode::Sha256 => format!("{:x}", Sha256::digest(value.as_bytes())),
+            MaskMode::Redact => REDACTED.to_string(),
+            MaskMode::Partial(keep_last) => {
+                let len = value.chars().count();
+                let masked = len.saturating_sub(*keep_last);
+                value
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| if i < masked { '*' } else { c })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Hashes or masks configured string columns of a batch.
+///
+/// Nulls are left as nulls, and columns that are not configured pass through
+/// unchanged.
+#[derive(Debug, Clone)]
+pub struct MaskTransformer {
+    columns: Vec<(String, MaskMode)>,
+}
+
+impl MaskTransformer {
+    /// Create a transformer that masks each column with its paired mode.
+    ///
+    /// # Parameters
+    ///
+    /// - `columns`: Column names and how to mask them; each must be `Utf8` or `LargeUtf8`
+    pub fn new(columns: Vec<(String, MaskMode)>) -> Self {
+        Self { columns }
+    }
+
+    /// Mask the configured columns of `batch`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(RecordBatch)` with the same schema and the configured columns rewritten
+    /// - `Err(ExtractorError)` if a configured column is missing or is not a string column
+    pub fn mask(&self, batch: &RecordBatch) -> ExtractorResult<RecordBatch> {
+        let schema = batch.schema();
+        let mut columns = batch.columns().to_vec();
+
+        for (name, mode) in &self.columns {
+            let (index, field) = schema.column_with_name(name).ok_or_else(|| {
+                ExtractorError::ExtractOpsError(format!("Column '{name}' not found"))
+            })?;
+            columns[index] = match field.data_type() {
+                DataType::Utf8 => mask_strings(columns[index].as_string::<i32>(), mode),
+                DataType::LargeUtf8 => mask_strings(columns[index].as_string::<i64>(), mode),
+                other => {
+                    return Err(ExtractorError::ExtractOpsError(format!(
+                        "Cannot mask column '{name}': expected a string column, found {other}"
+                    )));
+                }
+            };
+        }
+
+        Ok(RecordBatch::try_new(schema, columns)?)
+    }
+}
+
+fn mask_strings<O: OffsetSizeTrait>(values: &GenericStringArray<O>, mode: &MaskMode) -> ArrayRef {
+    let masked: GenericStringArray<O> = values
+        .iter()
+        .map(|value| value.map(|v| mode.apply(v)))
+        .collect();
+    Arc::new(masked)
+}
+
+#[async_trait]
+impl Transform for MaskTransformer {
+    async fn transform(&self, input: RecordBatch) -> ExtractorResult<RecordBatch> {
+        self.mask(&input)
+    }
+}
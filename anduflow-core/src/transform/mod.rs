@@ -0,0 +1,104 @@
+//! Transformations applied to extracted [`RecordBatch`]es between extraction
+//! and loading.
+//!
+//! Each transformer lives in its own module and operates on whole batches,
+//! except [`select_fields`], which narrows JSON records before they are
+//! converted to Arrow.
+//! Transformers that map one batch to another implement [`Transform`], so they
+//! can be used interchangeably and chained with [`Transform::then`].
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::Arc;
+//! use datafusion::arrow::array::{Int64Array, StringArray};
+//! use datafusion::arrow::datatypes::{DataType, Field, Schema};
+//! use datafusion::arrow::record_batch::RecordBatch;
+//! use anduflow_core::transform::Transform;
+//! use anduflow_core::transform::mask_transformer::{MaskMode, MaskTransformer};
+//! use anduflow_core::transform::select_columns::SelectColumns;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let schema = Arc::new(Schema::new(vec![
+//!     Field::new("id", DataType::Int64, false),
+//!     Field::new("email", DataType::Utf8, false),
+//!     Field::new("internal", DataType::Utf8, false),
+//! ]));
+//! let batch = RecordBatch::try_new(
+//!     schema,
+//!     vec![
+//!         Arc::new(Int64Array::from(vec![1])),
+//!         Arc::new(StringArray::from(vec!["ada@example.com"])),
+//!         Arc::new(StringArray::from(vec!["x"])),
+//!     ],
+//! )
+//! .unwrap();
+//!
+//! let pipeline = SelectColumns::new(&["email", "id"])
+//!     .then(MaskTransformer::new(vec![("email".to_string(), MaskMode::Redact)]));
+//! let output = pipeline.transform(batch).await.unwrap();
+//! assert_eq!(output.num_columns(), 2);
+//! assert_eq!(output.schema().field(0).name(), "email");
+//! # }
+//! ```
+
+use async_trait::async_trait;
+use datafusion::arrow::record_batch::RecordBatch;
+
+use anduflow_utils::error::ExtractorResult;
+
+pub mod explode_transformer;
+pub mod mask_transformer;
+pub mod coalesce_transformer;
+pub mod select_columns;
+pub mod select_fields;
+pub mod validate_transformer;
+
+/// A transformation from one record batch to another.
+#[async_trait]
+pub trait Transform: Send + Sync {
+    /// Transform `input` into a new batch.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(RecordBatch)` with the transformed data
+    /// - `Err(ExtractorError)` if the batch could not be transformed
+    async fn transform(&self, input: RecordBatch) -> ExtractorResult<RecordBatch>;
+
+    /// Chain `next` after this transform.
+    ///
+    /// The returned transform feeds the output of `self` into `next`, failing
+    /// with the first error from either.
+    fn then<U: Transform>(self, next: U) -> Chain<Self, U>
+    where
+        Self: Sized,
+    {
+        Chain {
+            first: self,
+            second: next,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Transform + ?Sized> Transform for Box<T> {
+    async fn transform(&self, input: RecordBatch) -> ExtractorResult<RecordBatch> {
+        (**self).transform(input).await
+    }
+}
+
+/// Two transforms applied one after the other; see [`Transform::then`].
+#[derive(Debug, Clone)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+#[async_trait]
+impl<A: Transform, B: Transform> Transform for Chain<A, B> {
+    async fn transform(&self, input: RecordBatch) -> ExtractorResult<RecordBatch> {
+        let intermediate = self.first.transform(input).await?;
+        self.second.transform(intermediate).await
+    }
+}
@@ -0,0 +1,87 @@
+//! Projection of record batches onto a subset of their columns.
+//!
+//! This module provides [`SelectColumns`], which keeps the named columns of a
+//! batch, in the order they are named, and drops the rest.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::Arc;
+//! use datafusion::arrow::array::{Int64Array, StringArray};
+//! use datafusion::arrow::datatypes::{DataType, Field, Schema};
+//! use datafusion::arrow::record_batch::RecordBatch;
+//! use anduflow_core::transform::select_columns::SelectColumns;
+//!
+//! let schema = Arc::new(Schema::new(vec![
+//!     Field::new("id", DataType::Int64, false),
+//!     Field::new("name", DataType::Utf8, true),
+//!     Field::new("email", DataType::Utf8, true),
+//! ]));
+//! let batch = RecordBatch::try_new(
+//!     schema,
+//!     vec![
+//!         Arc::new(Int64Array::from(vec![1, 2])),
+//!         Arc::new(StringArray::from(vec!["Ada", "Grace"])),
+//!         Arc::new(StringArray::from(vec!["ada@example.com", "grace@example.com"])),
+//!     ],
+//! )
+//! .unwrap();
+//!
+//! let projected = SelectColumns::new(&["email", "id"]).select(&batch).unwrap();
+//! let names: Vec<&str> = projected
+//!     .schema_ref()
+//!     .fields()
+//!     .iter()
+//!     .map(|field| field.name().as_str())
+//!     .collect();
+//! assert_eq!(names, ["email", "id"]);
+//! assert_eq!(projected.num_rows(), 2);
+//! ```
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use async_trait::async_trait;
+use datafusion::arrow::record_batch::RecordBatch;
+
+use super::Transform;
+
+/// Keeps the named columns of a batch, in the given order.
+#[derive(Debug, Clone)]
+pub struct SelectColumns {
+    columns: Vec<String>,
+}
+
+impl SelectColumns {
+    /// Create a projection onto `columns`, in that order.
+    pub fn new(columns: &[&str]) -> Self {
+        Self {
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+        }
+    }
+
+    /// Project `batch` onto the configured columns.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(RecordBatch)` with only the configured columns, in order
+    /// - `Err(ExtractorError)` if a configured column is missing
+    pub fn select(&self, batch: &RecordBatch) -> ExtractorResult<RecordBatch> {
+        let schema = batch.schema();
+        let indices = self
+            .columns
+            .iter()
+            .map(|name| {
+                schema.index_of(name).map_err(|_| {
+                    ExtractorError::ExtractOpsError(format!("Column '{name}' not found"))
+                })
+            })
+            .collect::<ExtractorResult<Vec<_>>>()?;
+        Ok(batch.project(&indices)?)
+    }
+}
+
+#[async_trait]
+impl Transform for SelectColumns {
+    async fn transform(&self, input: RecordBatch) -> ExtractorResult<RecordBatch> {
+        self.select(&input)
+    }
+}
@@ -0,0 +1,144 @@
+//! Projection of JSON records onto a subset of their fields.
+//!
+//! This module provides [`SelectFields`], which keeps only the fields at the
+//! given paths of each JSON record, preserving their nesting, before records
+//! are converted to Arrow. Selecting early keeps the inferred schema narrow
+//! and the records small.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde_json::json;
+//! use anduflow_core::transform::select_fields::SelectFields;
+//!
+//! let records = vec![
+//!     json!({"id": 1, "author": {"name": "Ada", "email": "ada@example.com"}, "body": "..."}),
+//!     json!({"id": 2, "body": "..."}),
+//! ];
+//! let selected = SelectFields::new(&["id", "$.author.name"]).select_all(&records);
+//! assert_eq!(
+//!     selected,
+//!     vec![
+//!         json!({"id": 1, "author": {"name": "Ada"}}),
+//!         json!({"id": 2, "author": {"name": null}}),
+//!     ]
+//! );
+//! ```
+
+use serde_json::{Map, Value};
+
+/// Keeps the fields at the given paths of JSON records.
+///
+/// Paths are dotted (`author.name`) or simple JSONPath (`$.author.name`,
+/// `$['author']['name']`); the bracket form allows keys containing dots.
+/// Every segment names an object key; array indices and wildcards are not
+/// supported. A path missing from a record, or running through a value that
+/// is not an object, gives `null` at that path, so no record is dropped and
+/// every output record has the same shape.
+#[derive(Debug, Clone)]
+pub struct SelectFields {
+    paths: Vec<Vec<String>>,
+}
+
+impl SelectFields {
+    /// Create a projection onto `paths`.
+    pub fn new(paths: &[&str]) -> Self {
+        Self {
+            paths: paths.iter().map(|path| parse_path(path)).collect(),
+        }
+    }
+
+    /// Project `record` onto the configured paths.
+    pub fn select(&self, record: &Value) -> Value {
+        let mut output = Map::new();
+        for path in &self.paths {
+            let value = path
+                .iter()
+                .try_fold(record, |value, key| value.get(key))
+                .cloned()
+                .unwrap_or(Value::Null);
+            insert_at(&mut output, path, value);
+        }
+        Value::Object(output)
+    }
+
+    /// Project every record in `records` onto the configured paths.
+    pub fn select_all(&self, records: &[Value]) -> Vec<Value> {
+        records.iter().map(|record| self.select(record)).collect()
+    }
+}
+
+/// Project every record in `records` onto `paths`.
+///
+/// Shorthand for [`SelectFields::new`] followed by [`SelectFields::select_all`].
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use anduflow_core::transform::select_fields::select_fields;
+///
+/// let records = vec![json!({"user": {"id": 7, "profile": {"city": "Oslo"}}, "raw": [1, 2, 3]})];
+/// let selected = select_fields(&records, &["user.profile.city", "$['user']['id']", "missing"]);
+/// assert_eq!(
+///     selected[0],
+///     json!({"user": {"profile": {"city": "Oslo"}, "id": 7}, "missing": null})
+/// );
+/// ```
+pub fn select_fields(records: &[Value], paths: &[&str]) -> Vec<Value> {
+    SelectFields::new(paths).select_all(records)
+}
+
+/// Split a dotted or JSONPath path into its keys.
+fn parse_path(path: &str) -> Vec<String> {
+    let path = path.strip_prefix('$').unwrap_or(path);
+    let mut keys = Vec::new();
+    let mut rest = path;
+    while !rest.is_empty() {
+        if let Some(bracketed) = rest.strip_prefix('[') {
+            let quote = bracketed.chars().next().filter(|c| *c == '\'' || *c == '"');
+            let (key, after) = match quote {
+                Some(quote) => {
+                    let inner = &bracketed[1..];
+                    let end = inner.find(quote).unwrap_or(inner.len());
+                    let after = inner[end..].trim_start_matches(quote);
+                    (&inner[..end], after.strip_prefix(']').unwrap_or(after))
+                }
+                None => {
+                    let end = bracketed.find(']').unwrap_or(bracketed.len());
+                    (&bracketed[..end], bracketed[end..].trim_start_matches(']'))
+                }
+            };
+            keys.push(key.to_string());
+            rest = after;
+        } else if let Some(dotted) = rest.strip_prefix('.') {
+            rest = dotted;
+        } else {
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            keys.push(rest[..end].to_string());
+            rest = &rest[end..];
+        }
+    }
+    keys
+}
+
+/// Set `value` at `path` in `output`, creating intermediate objects.
+fn insert_at(output: &mut Map<String, Value>, path: &[String], value: Value) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut current = output;
+    for key in parents {
+        let entry = current
+            .entry(key.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if !entry.is_object() {
+            *entry = Value::Object(Map::new());
+        }
+        let Value::Object(next) = entry else {
+            unreachable!("entry was just made an object");
+        };
+        current = next;
+    }
+    current.insert(last.clone(), value);
+}
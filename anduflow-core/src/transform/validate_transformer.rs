@@ -0,0 +1,226 @@
+//! Row validation against per-column constraints.
+//!
+//! This module provides [`ValidateTransformer`], which checks every row of a
+//! batch against a list of [`ColumnRule`]s and separates the rows that fail.
+//!
+//! # Examples
+//!
+//! ```
+//! use anduflow_core::transform::validate_transformer::{ColumnRule, ValidateTransformer};
+//!
+//! let validate = ValidateTransformer::new(vec![
+//!     ColumnRule::not_null("id"),
+//!     ColumnRule::matches("email", r"^[^@\s]+@[^@\s]+$").unwrap(),
+//!     ColumnRule::min("age", 0.0),
+//! ]);
+//! ```
+
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use datafusion::arrow::array::{ArrayRef, AsArray, BooleanArray};
+use datafusion::arrow::compute::{and, cast, filter_record_batch, is_not_null, not};
+use datafusion::arrow::datatypes::{DataType, Float64Type};
+use datafusion::arrow::record_batch::RecordBatch;
+use regex::Regex;
+
+/// A check applied to every value of a column.
+///
+/// Only [`NotNull`](Constraint::NotNull) rejects nulls; the other constraints
+/// pass null values.
+#[derive(Debug, Clone)]
+pub enum Constraint {
+    /// The value must not be null.
+    NotNull,
+    /// The numeric value must be at least this.
+    Min(f64),
+    /// The numeric value must be at most this.
+    Max(f64),
+    /// The string value must match this regular expression.
+    Matches(Regex),
+    /// The value, as a string, must be one of these.
+    OneOf(Vec<String>),
+}
+
+/// A [`Constraint`] on a named column.
+#[derive(Debug, Clone)]
+pub struct ColumnRule {
+    /// The column the constraint applies to.
+    pub column: String,
+    /// The constraint every row must satisfy.
+    pub constraint: Constraint,
+}
+
+impl ColumnRule {
+    /// Create a rule applying `constraint` to `column`.
+    pub fn new(column: &str, constraint: Constraint) -> Self {
+        Self {
+            column: column.to_string(),
+            constraint,
+        }
+    }
+
+    /// `column` must not be null.
+    pub fn not_null(column: &str) -> Self {
+        Self::new(column, Constraint::NotNull)
+    }
+
+    /// `column` must be numeric and at least `min`.
+    pub fn min(column: &str, min: f64) -> Self {
+        Self::new(column, Constraint::Min(min))
+    }
+
+    /// `column` must be numeric and at most `max`.
+    pub fn max(column: &str, max: f64) -> Self {
+        Self::new(column, Constraint::Max(max))
+    }
+
+    /// `column` must be a string matching `pattern`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(ColumnRule)` with the compiled pattern
+    /// - `Err(ExtractorError)` if `pattern` is not a valid regular expression
+    pub fn matches(column: &str, pattern: &str) -> ExtractorResult<Self> {
+        let regex = Regex::new(pattern).map_err(|err| {
+            ExtractorError::ExtractOpsError(format!("Invalid pattern for '{column}': {err}"))
+        })?;
+        Ok(Self::new(column, Constraint::Matches(regex)))
+    }
+
+    /// `column` must be one of `values`, compared as strings.
+    pub fn one_of(column: &str, values: &[&str]) -> Self {
+        Self::new(
+            column,
+            Constraint::OneOf(values.iter().map(|value| value.to_string()).collect()),
+        )
+    }
+}
+
+/// What [`ValidateTransformer::validate`] does with rows that fail a rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Move failing rows into the rejects batch and keep going.
+    #[default]
+    Reject,
+    /// Fail the whole batch on the first failing row.
+    Error,
+}
+
+/// The rows of a batch split by whether they passed validation.
+#[derive(Debug, Clone)]
+pub struct Validated {
+    /// Rows that satisfied every rule.
+    pub valid: RecordBatch,
+    /// Rows that failed at least one rule, with the same schema.
+    pub rejects: RecordBatch,
+}
+
+/// Checks each row of a batch against per-column constraints.
+#[derive(Debug, Clone)]
+pub struct ValidateTransformer {
+    rules: Vec<ColumnRule>,
+    mode: ValidationMode,
+}
+
+impl ValidateTransformer {
+    /// Create a transformer enforcing `rules` in [`ValidationMode::Reject`] mode.
+    pub fn new(rules: Vec<ColumnRule>) -> Self {
+        Self {
+            rules,
+            mode: ValidationMode::default(),
+        }
+    }
+
+    /// Set what happens to rows that fail a rule.
+    pub fn with_mode(mut self, mode: ValidationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Validate every row of `batch`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Validated)` with the passing and failing rows, in their original order
+    /// - `Err(ExtractorError)` if a rule's column is missing or has an unsuitable
+    ///   type (e.g. `Min` on a string column), or, in [`ValidationMode::Error`],
+    ///   if any row fails
+    pub fn validate(&self, batch: &RecordBatch) -> ExtractorResult<Validated> {
+        let mut passed = BooleanArray::from(vec![true; batch.num_rows()]);
+        for rule in &self.rules {
+            let column = batch.column_by_name(&rule.column).ok_or_else(|| {
+                ExtractorError::ExtractOpsError(format!("Column '{}' not found", rule.column))
+            })?;
+            let rule_passed = check(rule, column)?;
+            if self.mode == ValidationMode::Error
+                && let Some(row) = rule_passed.iter().position(|ok| ok != Some(true))
+            {
+                return Err(ExtractorError::ExtractOpsError(format!(
+                    "Row {row} failed {:?} on column '{}'",
+                    rule.constraint, rule.column
+                )));
+            }
+            passed = and(&passed, &rule_passed)?;
+        }
+
+        Ok(Validated {
+            valid: filter_record_batch(batch, &passed)?,
+            rejects: filter_record_batch(batch, &not(&passed)?)?,
+        })
+    }
+}
+
+/// Evaluate `rule` on every value of `column`; the result has no nulls.
+fn check(rule: &ColumnRule, column: &ArrayRef) -> ExtractorResult<BooleanArray> {
+    let unsuitable = |expected: &str| {
+        ExtractorError::ExtractOpsError(format!(
+            "Cannot apply {:?} to column '{}': expected {expected}, found {}",
+            rule.constraint,
+            rule.column,
+            column.data_type()
+        ))
+    };
+
+    let passed = match &rule.constraint {
+        Constraint::NotNull => is_not_null(column.as_ref())?,
+        Constraint::Min(bound) | Constraint::Max(bound) => {
+            if !column.data_type().is_numeric() {
+                return Err(unsuitable("a numeric column"));
+            }
+            let is_min = matches!(rule.constraint, Constraint::Min(_));
+            let values = cast(column, &DataType::Float64)?;
+            values
+                .as_primitive::<Float64Type>()
+                .iter()
+                .map(|value| {
+                    Some(value.is_none_or(|value| {
+                        if is_min { value >= *bound } else { value <= *bound }
+                    }))
+                })
+                .collect()
+        }
+        Constraint::Matches(regex) => {
+            if !matches!(
+                column.data_type(),
+                DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+            ) {
+                return Err(unsuitable("a string column"));
+            }
+            let values = cast(column, &DataType::Utf8)?;
+            values
+                .as_string::<i32>()
+                .iter()
+                .map(|value| Some(value.is_none_or(|value| regex.is_match(value))))
+                .collect()
+        }
+        Constraint::OneOf(allowed) => {
+            let values = cast(column, &DataType::Utf8)
+                .map_err(|_| unsuitable("a column castable to strings"))?;
+            values
+                .as_string::<i32>()
+                .iter()
+                .map(|value| Some(value.is_none_or(|value| allowed.iter().any(|a| a == value))))
+                .collect()
+        }
+    };
+    Ok(passed)
+}
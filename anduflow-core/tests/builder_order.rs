@@ -0,0 +1,91 @@
+//! Configuring a `RestExtractor` in any order produces the same request.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::json;
+
+type Step = fn(RestExtractor) -> RestExtractor;
+
+fn method(extractor: RestExtractor) -> RestExtractor {
+    extractor.with_method("POST")
+}
+
+fn header(extractor: RestExtractor) -> RestExtractor {
+    extractor.with_header("x-tenant", "acme")
+}
+
+fn query(extractor: RestExtractor) -> RestExtractor {
+    extractor.with_query_param(&[("page", "2")])
+}
+
+fn body(extractor: RestExtractor) -> RestExtractor {
+    extractor.with_json_body(&json!({"filter": "active"}))
+}
+
+fn auth(extractor: RestExtractor) -> RestExtractor {
+    extractor.with_basic_auth("user", "pass")
+}
+
+const ORDERS: [[Step; 5]; 4] = [
+    [method, header, query, body, auth],
+    [auth, body, query, header, method],
+    [query, auth, method, body, header],
+    [body, method, auth, header, query],
+];
+
+fn configure(base_url: &str, steps: &[Step]) -> RestExtractor {
+    steps
+        .iter()
+        .fold(RestExtractor::new(base_url, "users"), |extractor, step| step(extractor))
+}
+
+#[test]
+fn the_url_does_not_depend_on_the_order() {
+    for steps in &ORDERS {
+        let extractor = configure("https://api.example.com", steps);
+
+        assert_eq!(extractor.url(), "https://api.example.com/users?page=2");
+    }
+}
+
+#[tokio::test]
+async fn every_order_sends_the_same_request() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/users")
+                .query_param("page", "2")
+                .header("x-tenant", "acme")
+                .header("authorization", "Basic dXNlcjpwYXNz")
+                .json_body(json!({"filter": "active"}));
+            then.status(200).json_body(json!([]));
+        })
+        .await;
+
+    for steps in &ORDERS {
+        let extractor = configure(&server.base_url(), steps);
+
+        let response = extractor.extract_response().await.unwrap();
+
+        assert_eq!(response.status, 200);
+    }
+    mock.assert_hits_async(ORDERS.len()).await;
+}
+
+#[tokio::test]
+async fn the_extractor_can_be_sent_repeatedly() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/users").json_body(json!({"filter": "active"}));
+            then.status(200).json_body(json!([]));
+        })
+        .await;
+    let extractor = configure(&server.base_url(), &[body, method]);
+
+    extractor.extract_response().await.unwrap();
+    extractor.extract_response().await.unwrap();
+
+    mock.assert_hits_async(2).await;
+}
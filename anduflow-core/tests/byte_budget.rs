@@ -0,0 +1,42 @@
+//! Run-level byte budgets shared between extractions.
+
+use std::sync::Arc;
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::byte_budget::ByteBudget;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use httpmock::prelude::*;
+
+#[tokio::test]
+async fn the_second_extraction_is_refused_once_the_budget_is_spent() {
+    let server = MockServer::start_async().await;
+    let users = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).body("x".repeat(800));
+        })
+        .await;
+    let orders = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/orders");
+            then.status(200).body("y".repeat(800));
+        })
+        .await;
+    let budget = Arc::new(ByteBudget::new(1000));
+
+    let first = RestExtractor::new(&server.base_url(), "users").with_byte_budget(budget.clone());
+    let second = RestExtractor::new(&server.base_url(), "orders").with_byte_budget(budget.clone());
+
+    assert_eq!(first.extract_text().await.unwrap().len(), 800);
+    assert_eq!(budget.remaining(), 200);
+    let err = second.extract_text().await.unwrap_err();
+    assert!(matches!(err, ExtractorError::BudgetExceeded { limit: 1000, requested: 800 }));
+    assert!(budget.is_exhausted());
+
+    // Once exhausted, requests are refused before they are sent.
+    let err = first.extract_text().await.unwrap_err();
+    assert!(matches!(err, ExtractorError::BudgetExceeded { requested: 0, .. }));
+    users.assert_hits_async(1).await;
+    orders.assert_hits_async(1).await;
+}
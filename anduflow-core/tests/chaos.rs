@@ -0,0 +1,105 @@
+//! Seeded fault injection with `ChaosExtractor`.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anduflow_core::clock::{Clock, TestClock};
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::chaos_extractor::{ChaosConfig, ChaosExtractor};
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::logger::store::LogStore;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+fn log() -> LogStore {
+    LogStore::new("users".to_string(), "extract".to_string())
+}
+
+async fn serve_users(server: &MockServer) -> httpmock::Mock<'_> {
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(json!([{"id": 1}]));
+        })
+        .await
+}
+
+fn chaos(server: &MockServer, failure_probability: f64) -> ChaosExtractor<RestExtractor> {
+    ChaosExtractor::new(
+        RestExtractor::new(&server.base_url(), "users"),
+        ChaosConfig {
+            failure_probability,
+            seed: 7,
+            ..ChaosConfig::default()
+        },
+    )
+}
+
+#[tokio::test]
+async fn a_certain_failure_never_reaches_the_inner_extractor() {
+    let server = MockServer::start_async().await;
+    let mock = serve_users(&server).await;
+    let extractor = chaos(&server, 1.0);
+
+    for _ in 0..10 {
+        let err = extractor.extract_json::<Value>(&mut log()).await.unwrap_err();
+        assert!(err.to_string().contains("Chaos: injected failure in extract_json"));
+    }
+    assert!(extractor.ping().await.is_err());
+
+    mock.assert_hits_async(0).await;
+}
+
+#[tokio::test]
+async fn no_failures_pass_every_call_through() {
+    let server = MockServer::start_async().await;
+    let mock = serve_users(&server).await;
+    let extractor = chaos(&server, 0.0);
+
+    for _ in 0..10 {
+        let users: Value = extractor.extract_json(&mut log()).await.unwrap();
+        assert_eq!(users, json!([{"id": 1}]));
+    }
+
+    mock.assert_hits_async(10).await;
+}
+
+#[tokio::test]
+async fn the_same_seed_fails_the_same_calls() {
+    let server = MockServer::start_async().await;
+    serve_users(&server).await;
+
+    let mut runs = Vec::new();
+    for _ in 0..2 {
+        let extractor = chaos(&server, 0.5);
+        let mut outcomes = Vec::new();
+        for _ in 0..20 {
+            outcomes.push(extractor.extract_json::<Value>(&mut log()).await.is_ok());
+        }
+        runs.push(outcomes);
+    }
+
+    assert_eq!(runs[0], runs[1]);
+    assert!(runs[0].contains(&true) && runs[0].contains(&false));
+}
+
+#[tokio::test]
+async fn latency_is_added_before_each_call() {
+    let server = MockServer::start_async().await;
+    serve_users(&server).await;
+    let clock = Arc::new(TestClock::new());
+    let start = clock.now();
+    let extractor = ChaosExtractor::new(
+        RestExtractor::new(&server.base_url(), "users"),
+        ChaosConfig {
+            latency: Duration::from_millis(250),
+            ..ChaosConfig::default()
+        },
+    )
+    .with_clock(clock.clone());
+
+    extractor.extract_text().await.unwrap();
+    extractor.extract_text().await.unwrap();
+
+    assert_eq!(clock.now() - start, Duration::from_millis(500));
+}
@@ -0,0 +1,69 @@
+//! Client-level settings of `RestExtractor` against a mock server.
+
+use std::time::Duration;
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[tokio::test]
+async fn a_slow_response_fails_with_a_timeout_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .json_body(json!([]))
+                .delay(Duration::from_secs(5));
+        })
+        .await;
+
+    let extractor =
+        RestExtractor::new(&server.base_url(), "users").with_timeout(Duration::from_millis(100));
+    let err = extractor.extract_value().await.unwrap_err();
+
+    assert!(matches!(err, ExtractorError::TimeoutError(_)), "{err:?}");
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn a_timeout_set_after_a_shared_client_still_applies() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .json_body(json!([]))
+                .delay(Duration::from_secs(5));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users")
+        .with_client(reqwest::Client::new())
+        .with_timeout(Duration::from_millis(100));
+
+    assert!(matches!(
+        extractor.extract_value().await,
+        Err(ExtractorError::TimeoutError(_))
+    ));
+}
+
+#[tokio::test]
+async fn close_keeps_a_working_client() {
+    let server = MockServer::start_async().await;
+    let users = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(json!([{"id": 1}]));
+        })
+        .await;
+
+    let mut extractor =
+        RestExtractor::new(&server.base_url(), "users").with_timeout(Duration::from_secs(5));
+    extractor.close().await.unwrap();
+
+    assert_eq!(extractor.extract_value().await.unwrap(), json!([{"id": 1}]));
+    users.assert_async().await;
+}
@@ -0,0 +1,66 @@
+//! Releasing per-instance state with `close`, including through a trait object.
+
+use std::time::Duration;
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[tokio::test]
+async fn close_through_a_trait_object_drops_cached_responses() {
+    let server = MockServer::start_async().await;
+    let users = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([{"id": 1}]));
+        })
+        .await;
+    let mut extractor: Box<dyn Extractor + Send + Sync> = Box::new(
+        RestExtractor::new(&server.base_url(), "users").with_cache(Some(Duration::from_secs(300))),
+    );
+
+    extractor.extract_value().await.unwrap();
+    extractor.extract_value().await.unwrap();
+    users.assert_hits_async(1).await;
+
+    extractor.close().await.unwrap();
+    extractor.extract_value().await.unwrap();
+
+    users.assert_hits_async(2).await;
+}
+
+#[tokio::test]
+async fn close_forgets_the_oauth2_access_token() {
+    let server = MockServer::start_async().await;
+    let token = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/oauth/token");
+            then.status(200)
+                .json_body(json!({"access_token": "token-1", "expires_in": 3600}));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/invoices")
+                .header("authorization", "Bearer token-1");
+            then.status(200).json_body(json!([]));
+        })
+        .await;
+    let mut extractor = RestExtractor::new(&server.base_url(), "invoices")
+        .with_oauth2_client_credentials(
+            &server.url("/oauth/token"),
+            "my-client",
+            "my-secret",
+            &["invoices.read"],
+        );
+
+    extractor.extract_value().await.unwrap();
+    extractor.close().await.unwrap();
+    extractor.extract_value().await.unwrap();
+
+    token.assert_hits_async(2).await;
+}
@@ -0,0 +1,69 @@
+//! Coalescing several columns into one with `CoalesceTransformer`.
+
+use std::sync::Arc;
+
+use anduflow_core::transform::coalesce_transformer::CoalesceTransformer;
+use datafusion::arrow::array::{ArrayRef, AsArray, Int32Array, Int64Array, StringArray};
+use datafusion::arrow::datatypes::{DataType, Int64Type};
+use datafusion::arrow::record_batch::RecordBatch;
+
+fn coalesce(output: &str, inputs: &[&str]) -> CoalesceTransformer {
+    CoalesceTransformer::new(output.to_string(), inputs.iter().map(|s| s.to_string()).collect())
+}
+
+#[test]
+fn the_first_non_null_string_wins() {
+    let batch = RecordBatch::try_from_iter([
+        (
+            "work_email",
+            Arc::new(StringArray::from(vec![Some("ada@work.com"), None, None])) as ArrayRef,
+        ),
+        (
+            "personal_email",
+            Arc::new(StringArray::from(vec![Some("ada@home.com"), Some("grace@home.com"), None]))
+                as ArrayRef,
+        ),
+    ])
+    .unwrap();
+
+    let coalesced = coalesce("email", &["work_email", "personal_email"])
+        .coalesce(&batch)
+        .unwrap();
+
+    assert_eq!(coalesced.num_columns(), 3);
+    let field = coalesced.schema().field_with_name("email").unwrap().clone();
+    assert_eq!(field.data_type(), &DataType::Utf8);
+    assert!(field.is_nullable());
+    let emails = coalesced.column_by_name("email").unwrap().as_string::<i32>();
+    assert_eq!(
+        emails.iter().collect::<Vec<_>>(),
+        [Some("ada@work.com"), Some("grace@home.com"), None]
+    );
+}
+
+#[test]
+fn compatible_integer_inputs_are_widened() {
+    let batch = RecordBatch::try_from_iter([
+        ("a", Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef),
+        ("b", Arc::new(Int64Array::from(vec![Some(10), Some(i64::MAX)])) as ArrayRef),
+    ])
+    .unwrap();
+
+    let coalesced = coalesce("n", &["a", "b"]).coalesce(&batch).unwrap();
+
+    let n = coalesced.column_by_name("n").unwrap().as_primitive::<Int64Type>();
+    assert_eq!(n.values(), &[1, i64::MAX]);
+}
+
+#[test]
+fn incompatible_inputs_are_rejected() {
+    let batch = RecordBatch::try_from_iter([
+        ("name", Arc::new(StringArray::from(vec![Some("ada")])) as ArrayRef),
+        ("id", Arc::new(Int64Array::from(vec![Some(1)])) as ArrayRef),
+    ])
+    .unwrap();
+
+    let err = coalesce("label", &["name", "id"]).coalesce(&batch).unwrap_err();
+
+    assert!(err.to_string().contains("Cannot coalesce column 'id' (Int64)"));
+}
@@ -0,0 +1,40 @@
+//! Coercion of mixed-type JSON columns to a single Arrow type.
+
+use anduflow_core::arrow::coerce_column;
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::datatypes::{DataType, Float64Type, Int64Type};
+use serde_json::json;
+
+#[test]
+fn strings_are_parsed_into_an_integer_column_and_counted() {
+    let records = [
+        json!({"n": 1}),
+        json!({"n": "2"}),
+        json!({"n": 3}),
+        json!({"n": " 4 "}),
+        json!({"other": 5}),
+    ];
+
+    let (array, coerced) = coerce_column(&records, "n", DataType::Int64).unwrap();
+
+    assert_eq!(coerced, 2);
+    let n = array.as_primitive::<Int64Type>();
+    assert_eq!(n.len(), 5);
+    assert_eq!(n.value(0), 1);
+    assert_eq!(n.value(1), 2);
+    assert_eq!(n.value(3), 4);
+    assert!(n.is_null(4));
+}
+
+#[test]
+fn values_that_cannot_be_coerced_become_null() {
+    let records = [json!({"n": "1.5"}), json!({"n": "many"}), json!({"n": true})];
+
+    let (array, coerced) = coerce_column(&records, "n", DataType::Float64).unwrap();
+
+    let n = array.as_primitive::<Float64Type>();
+    assert_eq!(n.value(0), 1.5);
+    assert!(n.is_null(1));
+    assert!(n.is_null(2));
+    assert_eq!(coerced, 1);
+}
@@ -0,0 +1,124 @@
+//! Decoding of compressed responses against a mock server.
+
+use std::io::{ErrorKind, Write};
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::LogStore;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use futures::TryStreamExt;
+use httpmock::prelude::*;
+use serde_json::{Value, json};
+use tokio::io::AsyncReadExt;
+
+fn gzip(body: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body).unwrap();
+    encoder.finish().unwrap()
+}
+
+async fn serve_gzip(server: &MockServer, body: &[u8]) {
+    let compressed = gzip(body);
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/data");
+            then.status(200)
+                .header("content-encoding", "gzip")
+                .body(compressed);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn bodies_within_the_limit_are_decoded() {
+    let server = MockServer::start_async().await;
+    serve_gzip(&server, &[b'a'; 1024]).await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "data").with_max_decoded_size(1024);
+
+    assert_eq!(extractor.extract_bytes().await.unwrap(), vec![b'a'; 1024]);
+}
+
+#[tokio::test]
+async fn bodies_that_expand_past_the_limit_are_rejected() {
+    let server = MockServer::start_async().await;
+    serve_gzip(&server, &vec![0; 1024 * 1024]).await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "data").with_max_decoded_size(64 * 1024);
+    let err = extractor.extract_bytes().await.unwrap_err();
+
+    match err {
+        ExtractorError::StandardError(err) => assert_eq!(err.kind(), ErrorKind::InvalidData),
+        other => panic!("expected an InvalidData error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn the_raw_body_is_not_limited() {
+    let server = MockServer::start_async().await;
+    serve_gzip(&server, &vec![0; 1024 * 1024]).await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "data").with_max_decoded_size(64 * 1024);
+
+    assert_eq!(extractor.extract_raw().await.unwrap(), gzip(&vec![0; 1024 * 1024]));
+}
+
+#[tokio::test]
+async fn gzip_json_is_decoded_before_parsing() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/users")
+                .header("accept-encoding", "gzip, br, deflate");
+            then.status(200)
+                .header("content-type", "application/json")
+                .header("content-encoding", "gzip")
+                .body(gzip(br#"[{"id":1},{"id":2}]"#));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users").with_compression(true);
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+    let users: Value = extractor.extract_json(&mut log).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(users, json!([{"id": 1}, {"id": 2}]));
+}
+
+#[tokio::test]
+async fn streamed_bodies_are_decoded() {
+    let server = MockServer::start_async().await;
+    serve_gzip(&server, &[b'a'; 64 * 1024]).await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "data").with_compression(true);
+    let chunks: Vec<_> = extractor.extract_stream().await.unwrap().try_collect().await.unwrap();
+
+    assert_eq!(chunks.concat(), vec![b'a'; 64 * 1024]);
+}
+
+#[tokio::test]
+async fn streamed_readers_are_decoded() {
+    let server = MockServer::start_async().await;
+    serve_gzip(&server, b"id,name\n1,ada\n").await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "data").with_compression(true);
+    let mut reader = Box::pin(extractor.extract_reader().await.unwrap());
+    let mut body = String::new();
+    reader.read_to_string(&mut body).await.unwrap();
+
+    assert_eq!(body, "id,name\n1,ada\n");
+}
+
+#[tokio::test]
+async fn streamed_json_is_decoded() {
+    let server = MockServer::start_async().await;
+    serve_gzip(&server, br#"[{"id":1},{"id":2},{"id":3}]"#).await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "data").with_compression(true);
+    let records: Vec<Value> = extractor.extract_json_stream().try_collect().await.unwrap();
+
+    assert_eq!(records, vec![json!({"id": 1}), json!({"id": 2}), json!({"id": 3})]);
+}
@@ -0,0 +1,82 @@
+//! Concatenation of record batches with differing schemas.
+
+use std::sync::Arc;
+
+use anduflow_core::arrow::concat_batches;
+use datafusion::arrow::array::{
+    Array, ArrayRef, AsArray, Float64Array, Int32Array, Int64Array, StringArray, UInt64Array,
+};
+use datafusion::arrow::datatypes::{DataType, Decimal128Type, Int64Type};
+use datafusion::arrow::record_batch::RecordBatch;
+
+fn batch(columns: Vec<(&str, ArrayRef)>) -> RecordBatch {
+    RecordBatch::try_from_iter(columns).unwrap()
+}
+
+#[test]
+fn missing_columns_are_null_filled() {
+    let first = batch(vec![
+        ("id", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+        (
+            "email",
+            Arc::new(StringArray::from(vec!["a@example.com", "b@example.com"])) as ArrayRef,
+        ),
+    ]);
+    let second = batch(vec![("id", Arc::new(Int64Array::from(vec![3])) as ArrayRef)]);
+
+    let combined = concat_batches(&[first, second]).unwrap();
+
+    assert_eq!(combined.num_rows(), 3);
+    let schema = combined.schema();
+    let email = schema.field_with_name("email").unwrap();
+    assert!(email.is_nullable());
+    let emails = combined.column_by_name("email").unwrap();
+    assert_eq!(emails.null_count(), 1);
+    assert!(emails.is_null(2));
+    assert_eq!(emails.as_string::<i32>().value(0), "a@example.com");
+}
+
+#[test]
+fn integer_columns_are_widened() {
+    let first = batch(vec![("n", Arc::new(Int32Array::from(vec![1])) as ArrayRef)]);
+    let second = batch(vec![("n", Arc::new(Int64Array::from(vec![i64::MAX])) as ArrayRef)]);
+
+    let combined = concat_batches(&[first, second]).unwrap();
+
+    let n = combined.column(0).as_primitive::<Int64Type>();
+    assert_eq!(n.values(), &[1, i64::MAX]);
+}
+
+#[test]
+fn unsigned_and_signed_64_bit_columns_keep_every_value() {
+    let first = batch(vec![("n", Arc::new(UInt64Array::from(vec![u64::MAX])) as ArrayRef)]);
+    let second = batch(vec![("n", Arc::new(Int64Array::from(vec![-1])) as ArrayRef)]);
+
+    let combined = concat_batches(&[first, second]).unwrap();
+
+    assert_eq!(combined.schema().field(0).data_type(), &DataType::Decimal128(20, 0));
+    let n = combined.column(0).as_primitive::<Decimal128Type>();
+    assert_eq!(n.null_count(), 0);
+    assert_eq!(n.values(), &[u64::MAX as i128, -1]);
+}
+
+#[test]
+fn floats_and_64_bit_integers_are_incompatible() {
+    let first = batch(vec![("n", Arc::new(Int64Array::from(vec![(1 << 53) + 1])) as ArrayRef)]);
+    let second = batch(vec![("n", Arc::new(Float64Array::from(vec![0.5])) as ArrayRef)]);
+
+    let err = concat_batches(&[first, second]).unwrap_err();
+
+    assert!(err.to_string().contains("incompatible types"));
+}
+
+#[test]
+fn floats_and_32_bit_integers_are_widened_to_float64() {
+    let first = batch(vec![("n", Arc::new(Int32Array::from(vec![i32::MAX])) as ArrayRef)]);
+    let second = batch(vec![("n", Arc::new(Float64Array::from(vec![0.5])) as ArrayRef)]);
+
+    let combined = concat_batches(&[first, second]).unwrap();
+
+    assert_eq!(combined.schema().field(0).data_type(), &DataType::Float64);
+    assert_eq!(combined.num_rows(), 2);
+}
@@ -0,0 +1,65 @@
+//! Capping concurrent requests with a shared connection limiter.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::logger::store::LogStore;
+use httpmock::prelude::*;
+use serde_json::Value;
+use tokio::sync::Semaphore;
+
+const DELAY: Duration = Duration::from_millis(300);
+
+async fn extract(extractor: &RestExtractor) -> Value {
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+    extractor.extract_json(&mut log).await.unwrap()
+}
+
+#[tokio::test]
+async fn a_third_extraction_waits_for_a_permit() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(serde_json::json!([])).delay(DELAY);
+        })
+        .await;
+
+    let limiter = Arc::new(Semaphore::new(2));
+    let extractors: Vec<RestExtractor> = (0..3)
+        .map(|_| {
+            RestExtractor::new(&server.base_url(), "users").with_connection_limiter(limiter.clone())
+        })
+        .collect();
+
+    let started = Instant::now();
+    tokio::join!(extract(&extractors[0]), extract(&extractors[1]), extract(&extractors[2]));
+    let elapsed = started.elapsed();
+
+    mock.assert_hits_async(3).await;
+    assert!(elapsed >= DELAY * 2, "third request did not wait: {elapsed:?}");
+    assert_eq!(limiter.available_permits(), 2);
+}
+
+#[tokio::test]
+async fn extractions_within_the_limit_run_concurrently() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(serde_json::json!([])).delay(DELAY);
+        })
+        .await;
+
+    let limiter = Arc::new(Semaphore::new(2));
+    let first =
+        RestExtractor::new(&server.base_url(), "users").with_connection_limiter(limiter.clone());
+    let second = RestExtractor::new(&server.base_url(), "users").with_connection_limiter(limiter);
+
+    let started = Instant::now();
+    tokio::join!(extract(&first), extract(&second));
+
+    assert!(started.elapsed() < DELAY * 2);
+}
@@ -0,0 +1,57 @@
+//! Cookie sessions of `RestExtractor` against a mock server.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+
+async fn portal() -> MockServer {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/login");
+            then.status(200)
+                .header("set-cookie", "session=abc123; Path=/; HttpOnly");
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/reports/daily")
+                .cookie("session", "abc123");
+            then.status(200).body("report");
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/reports/daily");
+            then.status(401).body("login required");
+        })
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn a_session_cookie_is_shared_through_the_jar() {
+    let server = portal().await;
+    let login = RestExtractor::new(&server.base_url(), "login")
+        .with_method("POST")
+        .with_form_body(&[("user", "etl"), ("password", "secret")])
+        .with_cookie_store(true);
+    login.extract_text().await.unwrap();
+
+    let report = RestExtractor::new(&server.base_url(), "reports/daily")
+        .with_cookie_jar(login.cookie_jar().unwrap());
+
+    assert_eq!(report.extract_text().await.unwrap(), "report");
+}
+
+#[tokio::test]
+async fn cookies_are_not_kept_without_a_store() {
+    let server = portal().await;
+    let login = RestExtractor::new(&server.base_url(), "login").with_method("POST");
+    login.extract_text().await.unwrap();
+
+    assert!(login.cookie_jar().is_none());
+    let report = RestExtractor::new(&server.base_url(), "reports/daily");
+    assert!(report.extract_text().await.is_err());
+}
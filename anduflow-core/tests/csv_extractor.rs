@@ -0,0 +1,81 @@
+//! Parsing CSV files into record batches with `CsvExtractor`.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anduflow_core::extract::csv_extractor::CsvExtractor;
+use datafusion::arrow::array::AsArray;
+use datafusion::arrow::datatypes::{DataType, Field, Int64Type, Schema};
+
+fn temp_csv(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("anduflow-{name}-{}.csv", std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn headerless_files_get_numbered_columns() {
+    let path = temp_csv("headerless", "1,ada\n2,grace\n3,linus\n");
+
+    let batches = CsvExtractor::new(&path).with_header(false).extract_batches().unwrap();
+
+    assert_eq!(batches.len(), 1);
+    let schema = batches[0].schema();
+    assert_eq!(schema.field(0).name(), "column_1");
+    assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+    assert_eq!(schema.field(1).name(), "column_2");
+    assert_eq!(batches[0].column(1).as_string::<i32>().value(2), "linus");
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn quoted_fields_may_contain_commas() {
+    let path = temp_csv(
+        "quoted",
+        "id,name,address\n1,\"Lovelace, Ada\",\"12 St James's Square, London\"\n2,Grace,NYC\n",
+    );
+
+    let batches = CsvExtractor::new(&path).extract_batches().unwrap();
+
+    let batch = &batches[0];
+    assert_eq!(batch.num_columns(), 3);
+    assert_eq!(batch.num_rows(), 2);
+    let names = batch.column_by_name("name").unwrap().as_string::<i32>();
+    assert_eq!(names.value(0), "Lovelace, Ada");
+    let addresses = batch.column_by_name("address").unwrap().as_string::<i32>();
+    assert_eq!(addresses.value(0), "12 St James's Square, London");
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn delimiter_batch_size_and_explicit_schema_are_honoured() {
+    let path = temp_csv("semicolon", "id;score\n1;10\n2;20\n3;30\n4;40\n5;50\n");
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("user_id", DataType::Int64, false),
+        Field::new("points", DataType::Int64, true),
+    ]));
+
+    let batches = CsvExtractor::new(&path)
+        .with_delimiter(b';')
+        .with_schema(schema.clone())
+        .with_batch_size(2)
+        .extract_batches()
+        .unwrap();
+
+    assert_eq!(batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(), [2, 2, 1]);
+    assert_eq!(batches[0].schema(), schema);
+    let points = batches[2].column(1).as_primitive::<Int64Type>();
+    assert_eq!(points.value(0), 50);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn rows_that_do_not_match_the_schema_fail() {
+    let path = temp_csv("mismatch", "id\n1\nnot a number\n");
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+
+    let result = CsvExtractor::new(&path).with_schema(schema).extract_batches();
+
+    assert!(result.is_err());
+    std::fs::remove_file(path).unwrap();
+}
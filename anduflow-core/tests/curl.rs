@@ -0,0 +1,53 @@
+//! Extractors configured from `curl` command lines.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[tokio::test]
+async fn a_curl_command_is_replayed() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/search")
+                .header("authorization", "Bearer t0ken")
+                .header("content-type", "application/json")
+                .json_body(json!({"q": "rust", "limit": 10}));
+            then.status(200).body("ok");
+        })
+        .await;
+    let command = format!(
+        r#"curl -X POST '{}/search' \
+            -H 'Authorization: Bearer t0ken' \
+            -H "Content-Type: application/json" \
+            -d '{{"q": "rust", "limit": 10}}'"#,
+        server.base_url()
+    );
+
+    let extractor = RestExtractor::from_curl(&command).unwrap();
+    let plan = extractor.inspect().unwrap();
+
+    assert_eq!(plan.method, "POST");
+    assert_eq!(plan.url, format!("{}/search", server.base_url()));
+    assert_eq!(extractor.extract_text().await.unwrap(), "ok");
+    mock.assert_async().await;
+}
+
+#[test]
+fn a_body_implies_a_form_post() {
+    let extractor =
+        RestExtractor::from_curl("curl https://api.example.com/login -d user=ada").unwrap();
+    let plan = extractor.inspect().unwrap();
+
+    assert_eq!(plan.method, "POST");
+    assert_eq!(plan.headers["content-type"], "application/x-www-form-urlencoded");
+}
+
+#[test]
+fn unsupported_options_are_rejected() {
+    let proxied = RestExtractor::from_curl("curl --proxy http://proxy:8080 https://api.example.com");
+    assert!(proxied.is_err());
+    assert!(RestExtractor::from_curl("curl -H 'Accept: application/json'").is_err());
+}
@@ -0,0 +1,99 @@
+//! Extracting query results from SQLite through `DbExtractor`.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::db_extractor::DbExtractor;
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::LogStore;
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::datatypes::{DataType, Float64Type, Int64Type};
+use rusqlite::Connection;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct User {
+    id: i64,
+    name: String,
+    score: Option<f64>,
+}
+
+fn users(sql: &str) -> DbExtractor {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(
+        "CREATE TABLE users (id INTEGER NOT NULL, name TEXT, score REAL, avatar BLOB);
+         INSERT INTO users VALUES (1, 'Ada', 9.5, x'00ff'), (2, 'Grace', NULL, NULL);",
+    )
+    .unwrap();
+    DbExtractor::new(conn, sql)
+}
+
+#[tokio::test]
+async fn inserted_rows_are_extracted_as_json() {
+    let extractor = users("SELECT id, name, score FROM users ORDER BY id");
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    let rows: Vec<User> = extractor.extract_json(&mut log).await.unwrap();
+
+    assert_eq!(
+        rows,
+        [
+            User { id: 1, name: "Ada".to_string(), score: Some(9.5) },
+            User { id: 2, name: "Grace".to_string(), score: None },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn blobs_and_expressions_are_json_values() {
+    let extractor = users("SELECT avatar, id * 10 AS scaled FROM users ORDER BY id");
+
+    let rows = extractor.extract_value().await.unwrap();
+
+    assert_eq!(
+        rows,
+        json!([
+            {"avatar": "AP8=", "scaled": 10},
+            {"avatar": null, "scaled": 20},
+        ])
+    );
+}
+
+#[tokio::test]
+async fn rows_are_extracted_as_a_record_batch() {
+    let extractor = users("SELECT id, name, score FROM users ORDER BY id");
+
+    let batch = extractor.extract_arrow().await.unwrap();
+
+    assert_eq!(batch.num_rows(), 2);
+    let schema = batch.schema();
+    assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+    assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+    assert_eq!(schema.field(2).data_type(), &DataType::Float64);
+    assert_eq!(batch.column(0).as_primitive::<Int64Type>().values(), &[1, 2]);
+    assert_eq!(batch.column(1).as_string::<i32>().value(1), "Grace");
+    assert!(batch.column(2).as_primitive::<Float64Type>().is_null(1));
+}
+
+#[tokio::test]
+async fn metadata_lists_column_names_and_types() {
+    let extractor = users("SELECT id, name, score * 2 AS doubled FROM users");
+
+    let metadata: Value = serde_json::from_str(&extractor.metadata().await.unwrap()).unwrap();
+
+    assert_eq!(
+        metadata["columns"],
+        json!([
+            {"name": "id", "type": "INTEGER"},
+            {"name": "name", "type": "TEXT"},
+            {"name": "doubled", "type": null},
+        ])
+    );
+}
+
+#[tokio::test]
+async fn an_invalid_query_is_a_sqlite_error() {
+    let extractor = users("SELECT missing FROM users");
+
+    assert!(matches!(extractor.ping().await, Err(ExtractorError::SqliteError(_))));
+    assert!(matches!(extractor.extract_value().await, Err(ExtractorError::SqliteError(_))));
+}
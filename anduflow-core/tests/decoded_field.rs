@@ -0,0 +1,47 @@
+//! Decoding base64 payload fields with `extract_decoded_field`.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+async fn serve_file(server: &MockServer, body: Value) {
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/files/report.pdf");
+            then.status(200).json_body(body);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn the_content_field_is_decoded_to_bytes() {
+    let server = MockServer::start_async().await;
+    serve_file(&server, json!({"name": "report.pdf", "content": "JVBERi0x\nLjcKAAH+/w=="})).await;
+    let extractor = RestExtractor::new(&server.base_url(), "files/report.pdf");
+
+    let bytes = extractor.extract_decoded_field("/content").await.unwrap();
+
+    assert_eq!(&bytes[..], b"%PDF-1.7\n\x00\x01\xfe\xff");
+}
+
+#[tokio::test]
+async fn invalid_base64_is_a_clear_error() {
+    let server = MockServer::start_async().await;
+    serve_file(&server, json!({"content": "not*base64!"})).await;
+    let extractor = RestExtractor::new(&server.base_url(), "files/report.pdf");
+
+    let err = extractor.extract_decoded_field("/content").await.unwrap_err();
+
+    assert!(err.to_string().contains("Field at '/content' is not valid base64"));
+}
+
+#[tokio::test]
+async fn a_missing_or_non_string_field_is_an_error() {
+    let server = MockServer::start_async().await;
+    serve_file(&server, json!({"content": 42})).await;
+    let extractor = RestExtractor::new(&server.base_url(), "files/report.pdf");
+
+    let err = extractor.extract_decoded_field("/content").await.unwrap_err();
+
+    assert!(err.to_string().contains("No string field found at '/content'"));
+}
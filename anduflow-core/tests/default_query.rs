@@ -0,0 +1,45 @@
+//! Default query parameters merged into every request.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+
+#[test]
+fn defaults_are_appended_after_per_call_params() {
+    let extractor = RestExtractor::new("https://api.example.com", "users")
+        .with_default_query_param(&[("format", "json")])
+        .with_query_param(&[("page", "2")]);
+
+    assert_eq!(extractor.url(), "https://api.example.com/users?page=2&format=json");
+}
+
+#[test]
+fn a_per_call_param_overrides_a_conflicting_default() {
+    let extractor = RestExtractor::new("https://api.example.com", "users")
+        .with_query_param(&[("limit", "10")])
+        .with_default_query_param(&[("format", "json"), ("limit", "100")]);
+
+    assert_eq!(extractor.url(), "https://api.example.com/users?limit=10&format=json");
+}
+
+#[tokio::test]
+async fn every_request_carries_the_defaults() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/users")
+                .query_param("format", "json")
+                .query_param("limit", "10");
+            then.status(200).body("[]");
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users")
+        .with_default_query_param(&[("format", "json"), ("limit", "100")])
+        .with_query_param(&[("limit", "10")]);
+
+    extractor.ping().await.unwrap();
+    extractor.extract_text().await.unwrap();
+
+    mock.assert_hits_async(2).await;
+}
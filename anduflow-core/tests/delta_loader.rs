@@ -0,0 +1,72 @@
+//! Appending record batches to a Delta table with `DeltaLoader`.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anduflow_core::load::delta_loader::DeltaLoader;
+use datafusion::arrow::array::{Int64Array, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+fn table_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("anduflow-delta-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn users(ids: Vec<i64>) -> RecordBatch {
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+    RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(ids))]).unwrap()
+}
+
+/// The files in `dir` with the given extension.
+fn files(dir: &Path, extension: &str) -> Vec<PathBuf> {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+        .collect()
+}
+
+/// Total rows across the table's data files.
+fn row_count(dir: &Path) -> i64 {
+    files(dir, "parquet")
+        .iter()
+        .map(|path| {
+            let file = File::open(path).unwrap();
+            let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+            builder.metadata().file_metadata().num_rows()
+        })
+        .sum()
+}
+
+#[tokio::test]
+async fn two_batches_are_appended_as_two_commits() {
+    let dir = table_dir("users");
+    let loader = DeltaLoader::new(dir.to_str().unwrap());
+
+    loader.load(users(vec![1, 2, 3])).await.unwrap();
+    loader.load(users(vec![4, 5])).await.unwrap();
+
+    assert_eq!(row_count(&dir), 5);
+    assert_eq!(files(&dir.join("_delta_log"), "json").len(), 2);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn an_incompatible_batch_is_rejected() {
+    let dir = table_dir("mismatch");
+    let loader = DeltaLoader::new(dir.to_str().unwrap());
+    loader.load(users(vec![1])).await.unwrap();
+
+    let schema = Arc::new(Schema::new(vec![Field::new("name", DataType::Utf8, false)]));
+    let names =
+        RecordBatch::try_new(schema, vec![Arc::new(StringArray::from(vec!["ada"]))]).unwrap();
+    let err = loader.load(names).await.unwrap_err();
+
+    assert!(err.to_string().contains("Delta Lake error"));
+    assert_eq!(row_count(&dir), 1);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,52 @@
+//! Holding extractors of different types as `Box<dyn Extractor>`.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::file_extractor::FileExtractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::{Value, json};
+
+#[tokio::test]
+async fn extract_value_is_callable_through_a_trait_object() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([{"id": 1}]));
+        })
+        .await;
+    let path = std::env::temp_dir().join(format!("anduflow-dyn-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{"id": 2}"#).unwrap();
+
+    let extractors: Vec<Box<dyn Extractor + Send + Sync>> = vec![
+        Box::new(RestExtractor::new(&server.base_url(), "users")),
+        Box::new(FileExtractor::new(&path)),
+    ];
+    let mut values = Vec::new();
+    for extractor in &extractors {
+        extractor.ping().await.unwrap();
+        values.push(extractor.extract_value().await.unwrap());
+    }
+
+    assert_eq!(values, [json!([{"id": 1}]), json!({"id": 2})]);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn a_trait_object_value_deserializes_like_extract_json() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/count");
+            then.status(200).body("42");
+        })
+        .await;
+    let extractor: Box<dyn Extractor + Send + Sync> =
+        Box::new(RestExtractor::new(&server.base_url(), "count"));
+
+    let value: Value = extractor.extract_value().await.unwrap();
+
+    assert_eq!(serde_json::from_value::<u32>(value).unwrap(), 42);
+}
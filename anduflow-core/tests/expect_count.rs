@@ -0,0 +1,48 @@
+//! Reconciling extracted records with the total reported by the source.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+async fn serve(server: &MockServer, body: Value) {
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(body);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn a_truncated_response_is_a_count_mismatch() {
+    let server = MockServer::start_async().await;
+    serve(&server, json!({"count": 3, "results": [{"id": 1}, {"id": 2}]})).await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    let err = extractor.extract_json_expect_count::<Value>("/count").await.unwrap_err();
+
+    assert!(matches!(err, ExtractorError::CountMismatch { expected: 3, actual: 2 }));
+}
+
+#[tokio::test]
+async fn matching_counts_return_the_records() {
+    let server = MockServer::start_async().await;
+    serve(&server, json!({"meta": {"total": 2}, "data": [{"id": 1}, {"id": 2}]})).await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    let records: Vec<Value> = extractor.extract_json_expect_count("/meta/total").await.unwrap();
+
+    assert_eq!(records, vec![json!({"id": 1}), json!({"id": 2})]);
+}
+
+#[tokio::test]
+async fn a_missing_count_is_an_error() {
+    let server = MockServer::start_async().await;
+    serve(&server, json!({"results": []})).await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    let err = extractor.extract_json_expect_count::<Value>("/count").await.unwrap_err();
+
+    assert!(err.to_string().contains("No record count found at '/count'"));
+}
@@ -0,0 +1,57 @@
+//! Building record batches from JSON against an explicit schema.
+
+use anduflow_core::arrow::{
+    json_to_record_batch_with_policy, json_to_record_batch_with_schema, NullPolicy,
+};
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::datatypes::{DataType, Field, Int64Type, Schema};
+use serde_json::json;
+
+fn schema() -> Schema {
+    Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("amount", DataType::Int64, true),
+    ])
+}
+
+#[test]
+fn numeric_strings_are_coerced_to_the_schema_type() {
+    let records = [json!({"id": "1", "amount": "250"}), json!({"id": 2, "amount": null})];
+
+    let batch = json_to_record_batch_with_schema(&records, &schema()).unwrap();
+
+    assert_eq!(batch.schema().as_ref(), &schema());
+    let amount = batch.column_by_name("amount").unwrap().as_primitive::<Int64Type>();
+    assert_eq!(amount.value(0), 250);
+    assert!(amount.is_null(1));
+    let id = batch.column_by_name("id").unwrap().as_primitive::<Int64Type>();
+    assert_eq!(id.values(), &[1, 2]);
+}
+
+#[test]
+fn a_value_that_cannot_be_coerced_is_an_error() {
+    let records = [json!({"id": 1, "amount": "lots"})];
+
+    let err = json_to_record_batch_with_schema(&records, &schema()).unwrap_err();
+
+    assert!(err.to_string().contains("Column 'amount': cannot coerce"));
+}
+
+#[test]
+fn null_on_error_replaces_bad_values_in_nullable_columns() {
+    let records = [json!({"id": 1, "amount": "lots"})];
+
+    let batch = json_to_record_batch_with_policy(&records, &schema(), NullPolicy::NullOnError)
+        .unwrap();
+
+    assert!(batch.column_by_name("amount").unwrap().is_null(0));
+}
+
+#[test]
+fn a_missing_value_in_a_non_nullable_column_is_an_error() {
+    let records = [json!({"amount": 1})];
+
+    let result = json_to_record_batch_with_policy(&records, &schema(), NullPolicy::NullOnError);
+
+    assert!(result.is_err());
+}
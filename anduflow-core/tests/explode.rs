@@ -0,0 +1,68 @@
+//! Unnesting list columns with `ExplodeTransformer`.
+
+use std::sync::Arc;
+
+use anduflow_core::transform::explode_transformer::ExplodeTransformer;
+use datafusion::arrow::array::{Array, ArrayRef, AsArray, Int64Array, ListArray};
+use datafusion::arrow::datatypes::{DataType, Int32Type, Int64Type};
+use datafusion::arrow::record_batch::RecordBatch;
+
+fn orders(items: Vec<Option<Vec<Option<i32>>>>) -> RecordBatch {
+    let ids: Vec<i64> = (1..=items.len() as i64).collect();
+    RecordBatch::try_from_iter([
+        ("order_id", Arc::new(Int64Array::from(ids)) as ArrayRef),
+        ("items", Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(items)) as ArrayRef),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn lists_of_two_and_three_explode_into_five_rows() {
+    let batch = orders(vec![
+        Some(vec![Some(10), Some(11)]),
+        Some(vec![Some(20), Some(21), Some(22)]),
+    ]);
+
+    let exploded = ExplodeTransformer::new("items").explode(&batch).unwrap();
+
+    assert_eq!(exploded.num_rows(), 5);
+    assert_eq!(exploded.schema().field_with_name("items").unwrap().data_type(), &DataType::Int32);
+    let order_ids = exploded.column_by_name("order_id").unwrap().as_primitive::<Int64Type>();
+    assert_eq!(order_ids.values(), &[1, 1, 2, 2, 2]);
+    let items = exploded.column_by_name("items").unwrap().as_primitive::<Int32Type>();
+    assert_eq!(items.values(), &[10, 11, 20, 21, 22]);
+}
+
+#[test]
+fn empty_and_null_lists_are_dropped_by_default() {
+    let batch = orders(vec![Some(vec![Some(10)]), Some(vec![]), None]);
+
+    let exploded = ExplodeTransformer::new("items").explode(&batch).unwrap();
+
+    assert_eq!(exploded.num_rows(), 1);
+}
+
+#[test]
+fn empty_and_null_lists_can_be_kept_as_null_rows() {
+    let batch = orders(vec![Some(vec![Some(10)]), Some(vec![]), None]);
+
+    let exploded = ExplodeTransformer::new("items")
+        .with_keep_empty(true)
+        .explode(&batch)
+        .unwrap();
+
+    assert_eq!(exploded.num_rows(), 3);
+    let order_ids = exploded.column_by_name("order_id").unwrap().as_primitive::<Int64Type>();
+    assert_eq!(order_ids.values(), &[1, 2, 3]);
+    let items = exploded.column_by_name("items").unwrap();
+    assert!(!items.is_null(0));
+    assert!(items.is_null(1));
+    assert!(items.is_null(2));
+}
+
+#[test]
+fn a_non_list_column_is_rejected() {
+    let batch = orders(vec![Some(vec![Some(10)])]);
+
+    assert!(ExplodeTransformer::new("order_id").explode(&batch).is_err());
+}
@@ -0,0 +1,75 @@
+//! Reading local files through `FileExtractor`.
+
+use std::path::PathBuf;
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::file_extractor::FileExtractor;
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::{LogStatus, LogStore};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct User {
+    id: u64,
+    name: String,
+}
+
+fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("anduflow-{name}-{}", std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[tokio::test]
+async fn json_round_trips_through_a_file() {
+    let users = vec![
+        User { id: 1, name: "ada".to_string() },
+        User { id: 2, name: "grace".to_string() },
+    ];
+    let path = temp_file("users.json", &serde_json::to_vec(&users).unwrap());
+    let extractor = FileExtractor::new(&path);
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    let read: Vec<User> = extractor.extract_json(&mut log).await.unwrap();
+
+    assert_eq!(read, users);
+    assert!(matches!(log.status(), LogStatus::Completed));
+    assert_eq!(extractor.source_name().unwrap(), path.to_str().unwrap());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn text_bytes_and_metadata_are_read() {
+    let path = temp_file("notes.txt", b"hello\nworld\n");
+    let extractor = FileExtractor::new(&path);
+
+    extractor.ping().await.unwrap();
+    assert_eq!(extractor.extract_text().await.unwrap(), "hello\nworld\n");
+    assert_eq!(extractor.extract_bytes().await.unwrap(), b"hello\nworld\n");
+    assert_eq!(&extractor.extract_raw().await.unwrap()[..], b"hello\nworld\n");
+
+    let metadata: Value = serde_json::from_str(&extractor.metadata().await.unwrap()).unwrap();
+    assert_eq!(metadata["size"], 12);
+    assert!(metadata["modified"].is_string());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn a_missing_file_fails_with_an_io_error() {
+    let path = std::env::temp_dir().join(format!("anduflow-missing-{}", std::process::id()));
+    let extractor = FileExtractor::new(&path);
+
+    let err = extractor.ping().await.unwrap_err();
+    assert!(matches!(err, ExtractorError::StandardError(_)));
+    assert!(matches!(extractor.extract_text().await, Err(ExtractorError::StandardError(_))));
+}
+
+#[tokio::test]
+async fn a_directory_is_not_a_readable_file() {
+    let extractor = FileExtractor::new(std::env::temp_dir());
+
+    let err = extractor.ping().await.unwrap_err();
+
+    assert!(err.to_string().contains("is not a regular file"));
+}
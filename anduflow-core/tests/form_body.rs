@@ -0,0 +1,63 @@
+//! Sending `application/x-www-form-urlencoded` request bodies.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+
+async fn token_endpoint(server: &MockServer) -> httpmock::Mock<'_> {
+    server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/token")
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body("grant_type=client_credentials&scope=read+write");
+            then.status(200).body("issued");
+        })
+        .await
+}
+
+#[tokio::test]
+async fn a_form_body_is_posted_urlencoded() {
+    let server = MockServer::start_async().await;
+    let mock = token_endpoint(&server).await;
+    let extractor = RestExtractor::new(&server.base_url(), "token")
+        .with_method("POST")
+        .with_form_body(&[("grant_type", "client_credentials"), ("scope", "read write")]);
+
+    assert_eq!(extractor.extract_text().await.unwrap(), "issued");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn the_form_survives_a_later_method_change() {
+    let server = MockServer::start_async().await;
+    let mock = token_endpoint(&server).await;
+    let extractor = RestExtractor::new(&server.base_url(), "token")
+        .with_form_body(&[("grant_type", "client_credentials"), ("scope", "read write")])
+        .with_method("POST");
+
+    assert_eq!(extractor.extract_text().await.unwrap(), "issued");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn a_form_field_can_be_matched_by_key() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/token")
+                .x_www_form_urlencoded_tuple("refresh_token", "r&d=1");
+            then.status(200).body("refreshed");
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "token")
+        .with_form_body(&[("grant_type", "refresh_token"), ("refresh_token", "r&d=1")])
+        .with_method("POST");
+
+    assert_eq!(extractor.extract_text().await.unwrap(), "refreshed");
+
+    mock.assert_async().await;
+}
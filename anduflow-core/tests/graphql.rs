@@ -0,0 +1,97 @@
+//! GraphQL queries through `GraphQLExtractor` against a mock endpoint.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::graphql_extractor::GraphQLExtractor;
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::LogStore;
+use httpmock::prelude::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const QUERY: &str = "query Launches($limit: Int!) { launches(limit: $limit) { id name } }";
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Launches {
+    launches: Vec<Launch>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Launch {
+    id: u32,
+    name: String,
+}
+
+fn log() -> LogStore {
+    LogStore::new("launches".to_string(), "extract".to_string())
+}
+
+#[tokio::test]
+async fn the_data_field_is_deserialized() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .header("authorization", "Bearer secret")
+                .json_body(json!({"query": QUERY, "variables": {"limit": 2}}));
+            then.status(200).json_body(json!({
+                "data": {"launches": [{"id": 1, "name": "FalconSat"}, {"id": 2, "name": "DemoSat"}]}
+            }));
+        })
+        .await;
+    let extractor = GraphQLExtractor::new(&server.url("/graphql"), QUERY, json!({"limit": 2}))
+        .with_auth_token("secret");
+
+    let data: Launches = extractor.extract_json(&mut log()).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(
+        data.launches,
+        [
+            Launch { id: 1, name: "FalconSat".to_string() },
+            Launch { id: 2, name: "DemoSat".to_string() },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn errors_in_a_200_response_fail_the_extraction() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200).json_body(json!({
+                "data": null,
+                "errors": [
+                    {"message": "Cannot query field \"nme\" on type \"Launch\"."},
+                    {"locations": [{"line": 1, "column": 3}]}
+                ]
+            }));
+        })
+        .await;
+    let extractor = GraphQLExtractor::new(&server.url("/graphql"), QUERY, json!({"limit": 2}));
+
+    let err = extractor.extract_json::<Value>(&mut log()).await.unwrap_err();
+
+    let ExtractorError::ExtractOpsError(message) = err else {
+        panic!("expected an operation error, got {err}");
+    };
+    assert!(message.starts_with("GraphQL errors: Cannot query field \"nme\""));
+    assert!(message.contains("locations"));
+}
+
+#[tokio::test]
+async fn a_response_without_data_is_an_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200).json_body(json!({"data": null}));
+        })
+        .await;
+    let extractor = GraphQLExtractor::new(&server.url("/graphql"), QUERY, Value::Null);
+
+    let err = extractor.extract_json::<Value>(&mut log()).await.unwrap_err();
+
+    assert!(err.to_string().contains("GraphQL response has no data"));
+}
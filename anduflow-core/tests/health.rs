@@ -0,0 +1,67 @@
+//! Health checks of `RestExtractor` against a mock server.
+
+use anduflow_core::extract::HealthStatus;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::Method::HEAD;
+use httpmock::prelude::*;
+
+#[tokio::test]
+async fn the_health_check_url_gets_the_configured_headers() {
+    let server = MockServer::start_async().await;
+    let health = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/health").header("x-api-key", "secret");
+            then.status(200);
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "items")
+        .with_header("x-api-key", "secret")
+        .with_health_check_url(&server.url("/health"));
+
+    assert_eq!(extractor.health().await, HealthStatus::Healthy);
+    health.assert_async().await;
+}
+
+#[tokio::test]
+async fn an_error_status_from_the_health_check_url_is_unhealthy() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/health");
+            then.status(503);
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "items")
+        .with_health_check_url(&server.url("/health"));
+
+    assert!(matches!(
+        extractor.health().await,
+        HealthStatus::Unhealthy { status: Some(503), .. }
+    ));
+}
+
+#[tokio::test]
+async fn without_a_health_check_url_the_extraction_url_is_probed() {
+    let server = MockServer::start_async().await;
+    let head = server
+        .mock_async(|when, then| {
+            when.method(HEAD).path("/items").header("x-api-key", "secret");
+            then.status(405);
+        })
+        .await;
+    let get = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items").header("x-api-key", "secret");
+            then.status(200);
+        })
+        .await;
+
+    let extractor =
+        RestExtractor::new(&server.base_url(), "items").with_header("x-api-key", "secret");
+
+    assert_eq!(extractor.health().await, HealthStatus::Healthy);
+    head.assert_async().await;
+    get.assert_async().await;
+}
@@ -0,0 +1,82 @@
+//! Request and response hooks run around every request.
+
+use std::sync::{Arc, Mutex};
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use reqwest::header::HeaderValue;
+
+#[tokio::test]
+async fn a_request_hook_can_add_a_header() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users").header("x-correlation-id", "abc-123");
+            then.status(200).body("ok");
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users").with_request_hook(Arc::new(
+        |request: &mut reqwest::Request| {
+            request
+                .headers_mut()
+                .insert("x-correlation-id", HeaderValue::from_static("abc-123"));
+        },
+    ));
+
+    assert_eq!(extractor.extract_text().await.unwrap(), "ok");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn hooks_run_for_ping_and_every_extraction() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).body("[]");
+        })
+        .await;
+    let requests = Arc::new(Mutex::new(0));
+    let statuses = Arc::new(Mutex::new(Vec::new()));
+    let counted = requests.clone();
+    let observed = statuses.clone();
+    let extractor = RestExtractor::new(&server.base_url(), "users")
+        .with_request_hook(Arc::new(move |_: &mut reqwest::Request| {
+            *counted.lock().unwrap() += 1;
+        }))
+        .with_response_hook(Arc::new(move |response: &reqwest::Response| {
+            observed.lock().unwrap().push(response.status().as_u16());
+        }));
+
+    extractor.ping().await.unwrap();
+    extractor.extract_text().await.unwrap();
+    extractor.extract_bytes().await.unwrap();
+    extractor.extract_raw().await.unwrap();
+
+    assert_eq!(*requests.lock().unwrap(), 4);
+    assert_eq!(*statuses.lock().unwrap(), [200, 200, 200, 200]);
+}
+
+#[tokio::test]
+async fn response_hooks_observe_error_responses() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(503);
+        })
+        .await;
+    let statuses = Arc::new(Mutex::new(Vec::new()));
+    let observed = statuses.clone();
+    let extractor = RestExtractor::new(&server.base_url(), "users").with_response_hook(Arc::new(
+        move |response: &reqwest::Response| {
+            observed.lock().unwrap().push(response.status().as_u16());
+        },
+    ));
+
+    assert!(extractor.extract_text().await.is_err());
+
+    assert_eq!(*statuses.lock().unwrap(), [503]);
+}
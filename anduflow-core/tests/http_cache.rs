@@ -0,0 +1,62 @@
+//! Conditional requests of the on-disk HTTP cache against a mock server.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+
+#[tokio::test]
+async fn a_not_modified_response_is_answered_from_the_cache() {
+    let dir = std::env::temp_dir().join(format!("anduflow-http-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let server = MockServer::start_async().await;
+    let revalidated = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/countries")
+                .header("if-none-match", "\"v1\"");
+            then.status(304);
+        })
+        .await;
+    let fetched = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/countries");
+            then.status(200)
+                .header("etag", "\"v1\"")
+                .body("[\"NL\", \"SE\"]");
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "countries").with_http_cache(dir.clone());
+    let first = extractor.extract_text().await.unwrap();
+    let second = extractor.extract_text().await.unwrap();
+
+    assert_eq!(first, "[\"NL\", \"SE\"]");
+    assert_eq!(second, first);
+    fetched.assert_hits_async(1).await;
+    revalidated.assert_hits_async(1).await;
+    let entry = extractor.http_cache_entry().await.unwrap().unwrap();
+    assert_eq!(entry.status, 200);
+    assert_eq!(entry.etag.as_deref(), Some("\"v1\""));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn responses_without_validators_are_not_cached() {
+    let dir = std::env::temp_dir().join(format!("anduflow-http-cache-none-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let server = MockServer::start_async().await;
+    let fetched = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/countries");
+            then.status(200).body("[]");
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "countries").with_http_cache(dir.clone());
+    extractor.extract_text().await.unwrap();
+    extractor.extract_text().await.unwrap();
+
+    fetched.assert_hits_async(2).await;
+    assert!(extractor.http_cache_entry().await.unwrap().is_none());
+    let _ = std::fs::remove_dir_all(&dir);
+}
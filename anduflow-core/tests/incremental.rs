@@ -0,0 +1,48 @@
+//! Incremental checkpoints of `RestExtractor` against a mock server.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_core::extract::{Checkpoint, Extractor};
+use httpmock::prelude::*;
+
+async fn checkpoint_after(start: &str, body: &str) -> String {
+    let server = MockServer::start_async().await;
+    let events = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events").query_param("since", start);
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(body);
+        })
+        .await;
+
+    let mut extractor =
+        RestExtractor::new(&server.base_url(), "events").with_incremental("since", "id");
+    extractor.set_checkpoint(Checkpoint(start.to_string())).unwrap();
+    extractor.extract_value().await.unwrap();
+    events.assert_async().await;
+
+    extractor.checkpoint().unwrap().0
+}
+
+#[tokio::test]
+async fn integer_ids_beyond_f64_precision_still_advance() {
+    // 2^53 + 1 rounds to 2^53 as an f64, so it would compare equal to the checkpoint.
+    let checkpoint =
+        checkpoint_after("9007199254740992", r#"[{"id": 9007199254740993}]"#).await;
+
+    assert_eq!(checkpoint, "9007199254740993");
+}
+
+#[tokio::test]
+async fn integer_ids_are_not_compared_as_strings() {
+    let checkpoint = checkpoint_after("9", r#"[{"id": 10}, {"id": 8}]"#).await;
+
+    assert_eq!(checkpoint, "10");
+}
+
+#[tokio::test]
+async fn fractional_values_are_compared_numerically() {
+    let checkpoint = checkpoint_after("9.5", r#"[{"id": 10}, {"id": 9.75}]"#).await;
+
+    assert_eq!(checkpoint, "10");
+}
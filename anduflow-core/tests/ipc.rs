@@ -0,0 +1,28 @@
+//! Round-trips of record batches through Arrow IPC files.
+
+use std::fs::File;
+use std::sync::Arc;
+
+use anduflow_core::arrow::{read_ipc, write_ipc};
+use datafusion::arrow::array::{ArrayRef, Float64Array, Int64Array, StringArray};
+use datafusion::arrow::record_batch::RecordBatch;
+
+#[test]
+fn an_ipc_file_keeps_the_schema_and_rows() {
+    let batch = RecordBatch::try_from_iter([
+        ("id", Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef),
+        ("name", Arc::new(StringArray::from(vec![Some("ada"), None, Some("grace")])) as ArrayRef),
+        ("score", Arc::new(Float64Array::from(vec![1.5, 2.5, 3.5])) as ArrayRef),
+    ])
+    .unwrap();
+    let path = std::env::temp_dir().join(format!("anduflow-ipc-{}.arrow", std::process::id()));
+
+    write_ipc(&batch, File::create(&path).unwrap()).unwrap();
+    let batches = read_ipc(File::open(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].schema(), batch.schema());
+    assert_eq!(batches[0].num_rows(), 3);
+    assert_eq!(batches[0], batch);
+}
@@ -0,0 +1,75 @@
+//! Opt-in inference of `Decimal128` columns for monetary values.
+
+use std::sync::Arc;
+
+use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::datatypes::{DataType, Decimal128Type, Field, Schema};
+use serde_json::json;
+
+#[test]
+fn decimal_strings_become_a_decimal128_column() {
+    let records = vec![json!({"price": "19.99"}), json!({"price": "1234.5"})];
+
+    let batch = ApiToArrowConverter::new()
+        .with_decimal_inference(true)
+        .convert(&records)
+        .unwrap();
+
+    assert_eq!(batch.schema().field(0).data_type(), &DataType::Decimal128(6, 2));
+    let prices = batch.column(0).as_primitive::<Decimal128Type>();
+    assert_eq!(prices.values(), &[1999, 123450]);
+    assert_eq!(prices.value_as_string(1), "1234.50");
+}
+
+#[test]
+fn decimal_numbers_and_strings_can_be_mixed() {
+    let records = vec![json!({"amount": 0.1}), json!({"amount": "-12.345"}), json!({})];
+
+    let batch = ApiToArrowConverter::new()
+        .with_decimal_inference(true)
+        .convert(&records)
+        .unwrap();
+
+    assert_eq!(batch.schema().field(0).data_type(), &DataType::Decimal128(5, 3));
+    let amounts = batch.column(0).as_primitive::<Decimal128Type>();
+    assert_eq!(amounts.value(0), 100);
+    assert_eq!(amounts.value(1), -12345);
+    assert!(amounts.is_null(2));
+}
+
+#[test]
+fn decimals_keep_their_old_types_by_default() {
+    let records = vec![json!({"price": "19.99", "rate": 0.5})];
+
+    let batch = ApiToArrowConverter::new().convert(&records).unwrap();
+
+    assert_eq!(batch.schema().field(0).data_type(), &DataType::Utf8);
+    assert_eq!(batch.schema().field(1).data_type(), &DataType::Float64);
+}
+
+#[test]
+fn non_decimal_values_rule_out_a_decimal_column() {
+    let records = vec![json!({"price": "19.99"}), json!({"price": "n/a"})];
+
+    let batch = ApiToArrowConverter::new()
+        .with_decimal_inference(true)
+        .convert(&records)
+        .unwrap();
+
+    assert_eq!(batch.schema().field(0).data_type(), &DataType::Utf8);
+}
+
+#[test]
+fn values_overflowing_the_precision_are_null() {
+    let schema = Arc::new(Schema::new(vec![Field::new("price", DataType::Decimal128(4, 2), true)]));
+    let records = vec![json!({"price": "19.99"}), json!({"price": "1234.5"})];
+
+    let batch = ApiToArrowConverter::new()
+        .convert_with_schema(&records, schema)
+        .unwrap();
+
+    let prices = batch.column(0).as_primitive::<Decimal128Type>();
+    assert_eq!(prices.value(0), 1999);
+    assert!(prices.is_null(1));
+}
@@ -0,0 +1,55 @@
+//! Converting an empty list of JSON records to Arrow.
+
+use std::sync::Arc;
+
+use anduflow_core::extract::json_to_arrow::{ApiToArrowConverter, json_to_arrow_with_schema};
+use anduflow_utils::error::ExtractorError;
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use serde_json::json;
+
+fn users_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("name", DataType::Utf8, true),
+    ]))
+}
+
+#[test]
+fn empty_records_with_a_schema_give_an_empty_batch() {
+    let batch = json_to_arrow_with_schema(&[], users_schema()).unwrap();
+
+    assert_eq!(batch.num_rows(), 0);
+    assert_eq!(batch.schema(), users_schema());
+}
+
+#[test]
+fn empty_records_without_a_schema_are_a_distinct_error() {
+    let err = ApiToArrowConverter::new().convert(&[]).unwrap_err();
+
+    assert!(matches!(err, ExtractorError::EmptyRecords));
+    assert!(err.to_string().contains("supply a schema"));
+}
+
+#[test]
+fn a_previously_inferred_schema_can_be_reused() {
+    let converter = ApiToArrowConverter::new();
+    let first = converter.convert(&[json!({"id": 1, "name": "Ada"})]).unwrap();
+
+    let empty = converter.convert_with_schema(&[], first.schema()).unwrap();
+
+    assert_eq!(empty.num_rows(), 0);
+    assert_eq!(empty.schema(), first.schema());
+}
+
+#[test]
+fn records_are_fitted_to_the_supplied_schema() {
+    let records = [json!({"id": 1, "extra": true}), json!({"name": "Grace"})];
+
+    let batch = json_to_arrow_with_schema(&records, users_schema()).unwrap();
+
+    assert_eq!(batch.num_columns(), 2);
+    assert!(batch.column_by_name("extra").is_none());
+    assert!(batch.column(0).is_null(1));
+    assert_eq!(batch.column(1).as_string::<i32>().value(1), "Grace");
+}
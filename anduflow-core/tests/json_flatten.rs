@@ -0,0 +1,48 @@
+//! Flattening of nested objects and arrays in JSON to Arrow conversion.
+
+use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::datatypes::{DataType, Int64Type};
+use serde_json::json;
+
+#[test]
+fn nested_objects_become_dotted_columns() {
+    let records = vec![
+        json!({"id": 1, "author": {"name": "Ada", "address": {"city": "London"}}}),
+        json!({"id": 2, "author": {"name": "Grace"}}),
+    ];
+
+    let batch = ApiToArrowConverter::new().convert(&records).unwrap();
+
+    let schema = batch.schema();
+    let name = schema.field_with_name("author.name").unwrap();
+    assert_eq!(name.data_type(), &DataType::Utf8);
+    assert!(schema.field_with_name("author").is_err());
+    let names = batch.column_by_name("author.name").unwrap().as_string::<i32>();
+    assert_eq!(names.value(0), "Ada");
+    assert_eq!(names.value(1), "Grace");
+    let cities = batch.column_by_name("author.address.city").unwrap().as_string::<i32>();
+    assert_eq!(cities.value(0), "London");
+    assert!(cities.is_null(1));
+}
+
+#[test]
+fn arrays_of_scalars_become_list_columns() {
+    let records = vec![
+        json!({"scores": [1, 2, 3]}),
+        json!({"scores": []}),
+        json!({"scores": null}),
+    ];
+
+    let batch = ApiToArrowConverter::new().convert(&records).unwrap();
+
+    let DataType::List(item) = batch.schema().field(0).data_type().clone() else {
+        panic!("expected a list column, got {}", batch.schema().field(0).data_type());
+    };
+    assert_eq!(item.data_type(), &DataType::Int64);
+    let scores = batch.column(0).as_list::<i32>();
+    let first = scores.value(0);
+    assert_eq!(first.as_primitive::<Int64Type>().values(), &[1, 2, 3]);
+    assert_eq!(scores.value(1).len(), 0);
+    assert!(scores.is_null(2));
+}
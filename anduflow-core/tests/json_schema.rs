@@ -0,0 +1,46 @@
+//! Arrow schemas derived from JSON Schema documents.
+
+use anduflow_core::arrow::{json_to_record_batch_with_schema, schema_from_json_schema};
+use datafusion::arrow::array::AsArray;
+use datafusion::arrow::datatypes::{DataType, TimeUnit, TimestampMicrosecondType};
+use serde_json::json;
+
+#[test]
+fn a_date_time_string_becomes_a_timestamp_column() {
+    let schema = schema_from_json_schema(&json!({
+        "type": "object",
+        "properties": {
+            "id": {"type": "integer"},
+            "price": {"type": "number"},
+            "active": {"type": "boolean"},
+            "name": {"type": ["string", "null"]},
+            "created_at": {"type": "string", "format": "date-time"}
+        },
+        "required": ["id", "name", "created_at"]
+    }))
+    .unwrap();
+
+    let created_at = schema.field_with_name("created_at").unwrap();
+    assert_eq!(
+        created_at.data_type(),
+        &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+    );
+    assert!(!created_at.is_nullable());
+    assert_eq!(schema.field_with_name("id").unwrap().data_type(), &DataType::Int64);
+    assert_eq!(schema.field_with_name("price").unwrap().data_type(), &DataType::Float64);
+    assert_eq!(schema.field_with_name("active").unwrap().data_type(), &DataType::Boolean);
+    assert!(schema.field_with_name("name").unwrap().is_nullable());
+
+    let batch = json_to_record_batch_with_schema(
+        &[json!({"id": 1, "name": "ada", "created_at": "2024-01-01T00:00:01Z"})],
+        &schema,
+    )
+    .unwrap();
+    let column = batch.column_by_name("created_at").unwrap();
+    assert_eq!(column.as_primitive::<TimestampMicrosecondType>().value(0), 1_704_067_201_000_000);
+}
+
+#[test]
+fn a_non_object_root_is_rejected() {
+    assert!(schema_from_json_schema(&json!({"type": "string"})).is_err());
+}
@@ -0,0 +1,41 @@
+//! Deterministic schema inference in JSON to Arrow conversion.
+
+use anduflow_core::extract::json_to_arrow::{ApiToArrowConverter, FieldStats};
+use datafusion::arrow::datatypes::DataType;
+use serde_json::{json, Value};
+
+fn names(records: &[Value]) -> Vec<String> {
+    let batch = ApiToArrowConverter::new().convert(records).unwrap();
+    batch.schema().fields().iter().map(|field| field.name().clone()).collect()
+}
+
+#[test]
+fn fields_keep_their_first_seen_order_across_runs() {
+    let records = vec![
+        json!({"zeta": 1}),
+        json!({"alpha": "a", "zeta": 2}),
+        json!({"mid": true, "alpha": "b"}),
+    ];
+
+    let first = names(&records);
+    for _ in 0..20 {
+        assert_eq!(names(&records), first);
+    }
+    assert_eq!(first, ["zeta", "alpha", "mid"]);
+}
+
+#[test]
+fn ties_go_to_the_wider_type() {
+    let stats = |values: &[Value]| {
+        let mut stats = FieldStats::default();
+        for value in values {
+            stats.update(value);
+        }
+        stats.determine_type()
+    };
+
+    assert_eq!(stats(&[json!(1), json!(2.5)]), DataType::Float64);
+    assert_eq!(stats(&[json!(1), json!("one")]), DataType::Utf8);
+    assert_eq!(stats(&[json!(true), json!(1)]), DataType::Int64);
+    assert_eq!(stats(&[json!(null)]), DataType::Utf8);
+}
@@ -0,0 +1,69 @@
+//! Incremental parsing of JSON array responses with `extract_json_stream`.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use futures::{StreamExt, TryStreamExt};
+use httpmock::prelude::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Event {
+    id: u64,
+    kind: String,
+}
+
+#[tokio::test]
+async fn elements_are_yielded_in_order() {
+    let server = MockServer::start_async().await;
+    let events: Vec<Value> = (0..2000)
+        .map(|id| {
+            let kind = if id % 2 == 0 { "even, [quoted]" } else { "odd" };
+            json!({"id": id, "kind": kind})
+        })
+        .collect();
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events");
+            then.status(200).json_body(Value::Array(events));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "events");
+    let streamed: Vec<Event> = extractor.extract_json_stream().try_collect().await.unwrap();
+
+    assert_eq!(streamed.len(), 2000);
+    assert!(streamed.iter().enumerate().all(|(i, event)| event.id == i as u64));
+    assert_eq!(streamed[0].kind, "even, [quoted]");
+}
+
+#[tokio::test]
+async fn a_body_that_is_not_an_array_ends_with_an_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events");
+            then.status(200).json_body(json!({"id": 1}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "events");
+    let mut stream = Box::pin(extractor.extract_json_stream::<Value>());
+
+    assert!(stream.next().await.unwrap().is_err());
+}
+
+#[tokio::test]
+async fn an_error_status_ends_the_stream_with_an_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events");
+            then.status(500);
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "events");
+    let result: Result<Vec<Value>, _> = extractor.extract_json_stream().try_collect().await;
+
+    assert!(result.is_err());
+}
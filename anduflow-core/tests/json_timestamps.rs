@@ -0,0 +1,56 @@
+//! Timestamp inference for ISO-8601 strings in JSON to Arrow conversion.
+
+use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::datatypes::{DataType, TimeUnit, TimestampMillisecondType};
+use serde_json::{json, Value};
+
+fn published(values: &[&str]) -> Vec<Value> {
+    values.iter().map(|value| json!({"published_at": value})).collect()
+}
+
+#[test]
+fn malformed_timestamps_become_null() {
+    let records = published(&[
+        "2024-05-01T12:00:00Z",
+        "2024-05-01T12:00:00.500",
+        "2024-05-02",
+        "yesterday",
+    ]);
+
+    let batch = ApiToArrowConverter::new()
+        .with_timestamp_threshold(0.75)
+        .convert(&records)
+        .unwrap();
+
+    assert_eq!(
+        batch.schema().field(0).data_type(),
+        &DataType::Timestamp(TimeUnit::Millisecond, None)
+    );
+    let column = batch.column(0).as_primitive::<TimestampMillisecondType>();
+    assert_eq!(column.value(0), 1_714_564_800_000);
+    assert_eq!(column.value(1), 1_714_564_800_500);
+    assert_eq!(column.value(2), 1_714_608_000_000);
+    assert!(column.is_null(3));
+}
+
+#[test]
+fn the_threshold_decides_between_timestamp_and_utf8() {
+    let records = published(&["2024-05-01T12:00:00Z", "2024-05-02T12:00:00Z", "n/a"]);
+
+    let default = ApiToArrowConverter::new().convert(&records).unwrap();
+    assert_eq!(default.schema().field(0).data_type(), &DataType::Utf8);
+    assert_eq!(default.column(0).as_string::<i32>().value(2), "n/a");
+
+    let lenient = ApiToArrowConverter::new()
+        .with_timestamp_threshold(0.5)
+        .convert(&records)
+        .unwrap();
+    assert!(matches!(lenient.schema().field(0).data_type(), DataType::Timestamp(..)));
+
+    let disabled = ApiToArrowConverter::new()
+        .with_timestamp_threshold(1.1)
+        .convert(&published(&["2024-05-01T12:00:00Z"]))
+        .unwrap();
+    assert_eq!(disabled.schema().field(0).data_type(), &DataType::Utf8);
+}
@@ -0,0 +1,113 @@
+//! JSON to Arrow conversion of 64-bit integer IDs.
+
+use anduflow_core::extract::json_to_arrow::ApiToArrowConverter;
+use anduflow_utils::error::ExtractorError;
+use datafusion::arrow::array::{Array, AsArray, Int64Array, UInt64Array};
+use datafusion::arrow::datatypes::{DataType, Decimal128Type};
+use datafusion::arrow::record_batch::RecordBatch;
+use serde_json::json;
+
+/// 2^53 + 1, the smallest positive integer an `f64` cannot hold.
+const INEXACT_AS_F64: i64 = 9_007_199_254_740_993;
+
+fn data_type(batch: &RecordBatch, name: &str) -> DataType {
+    batch
+        .schema()
+        .field_with_name(name)
+        .unwrap()
+        .data_type()
+        .clone()
+}
+
+#[test]
+fn ids_beyond_f64_precision_are_stored_exactly_as_int64() {
+    let records = vec![
+        json!({"id": INEXACT_AS_F64, "score": 0.5}),
+        json!({"id": i64::MAX, "score": 1.5}),
+        json!({"id": -INEXACT_AS_F64, "score": 2.5}),
+    ];
+
+    let batch = ApiToArrowConverter::new().convert(&records).unwrap();
+
+    assert_eq!(data_type(&batch, "id"), DataType::Int64);
+    let ids = batch.column_by_name("id").unwrap();
+    let ids = ids.as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(
+        ids.values().to_vec(),
+        vec![INEXACT_AS_F64, i64::MAX, -INEXACT_AS_F64]
+    );
+    assert_eq!(data_type(&batch, "score"), DataType::Float64);
+}
+
+#[test]
+fn ids_above_i64_max_are_stored_as_uint64() {
+    let records = vec![
+        json!({"id": u64::MAX}),
+        json!({"id": 1}),
+        json!({"id": null}),
+    ];
+
+    let batch = ApiToArrowConverter::new().convert(&records).unwrap();
+
+    assert_eq!(data_type(&batch, "id"), DataType::UInt64);
+    let ids = batch.column_by_name("id").unwrap();
+    let ids = ids.as_any().downcast_ref::<UInt64Array>().unwrap();
+    assert_eq!(ids.value(0), u64::MAX);
+    assert_eq!(ids.value(1), 1);
+    assert!(ids.is_null(2));
+}
+
+#[test]
+fn ids_above_i64_max_mixed_with_negative_ids_are_stored_as_decimal() {
+    let records = vec![json!({"id": u64::MAX}), json!({"id": -1})];
+
+    let batch = ApiToArrowConverter::new().convert(&records).unwrap();
+
+    assert_eq!(data_type(&batch, "id"), DataType::Decimal128(20, 0));
+    let ids = batch
+        .column_by_name("id")
+        .unwrap()
+        .as_primitive::<Decimal128Type>();
+    assert_eq!(ids.values(), &[u64::MAX as i128, -1]);
+}
+
+#[test]
+fn wide_ids_outvote_floats() {
+    let records = vec![
+        json!({"id": INEXACT_AS_F64}),
+        json!({"id": 1.5}),
+        json!({"id": 2.5}),
+    ];
+
+    let batch = ApiToArrowConverter::new().convert(&records).unwrap();
+
+    assert_eq!(data_type(&batch, "id"), DataType::Int64);
+    let ids = batch.column_by_name("id").unwrap();
+    let ids = ids.as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(ids.value(0), INEXACT_AS_F64);
+    assert_eq!(ids.null_count(), 2);
+}
+
+#[test]
+fn strict_precision_rejects_wide_ids_mixed_with_floats() {
+    let records = vec![json!({"id": INEXACT_AS_F64}), json!({"id": 1.5})];
+
+    let err = ApiToArrowConverter::new()
+        .with_strict_precision(true)
+        .convert(&records)
+        .unwrap_err();
+
+    assert!(matches!(&err, ExtractorError::ExtractOpsError(message) if message.contains("'id'")));
+}
+
+#[test]
+fn small_integers_mixed_with_floats_still_become_float64() {
+    let records = vec![json!({"amount": 1}), json!({"amount": 2.5})];
+
+    let batch = ApiToArrowConverter::new()
+        .with_strict_precision(true)
+        .convert(&records)
+        .unwrap();
+
+    assert_eq!(data_type(&batch, "amount"), DataType::Float64);
+}
@@ -0,0 +1,62 @@
+//! Hashing and masking PII columns with `MaskTransformer`.
+
+use std::sync::Arc;
+
+use anduflow_core::transform::mask_transformer::{MaskMode, MaskTransformer};
+use datafusion::arrow::array::{Array, ArrayRef, AsArray, Int64Array, StringArray};
+use datafusion::arrow::record_batch::RecordBatch;
+
+fn contacts() -> RecordBatch {
+    RecordBatch::try_from_iter([
+        ("id", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+        (
+            "email",
+            Arc::new(StringArray::from(vec![Some("ada@example.com"), None])) as ArrayRef,
+        ),
+        (
+            "phone",
+            Arc::new(StringArray::from(vec![Some("555-0142"), Some("12")])) as ArrayRef,
+        ),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn emails_are_hashed_and_phones_partially_masked() {
+    let mask = MaskTransformer::new(vec![
+        ("email".to_string(), MaskMode::Sha256),
+        ("phone".to_string(), MaskMode::Partial(4)),
+    ]);
+
+    let masked = mask.mask(&contacts()).unwrap();
+
+    let emails = masked.column_by_name("email").unwrap().as_string::<i32>();
+    assert_eq!(
+        emails.value(0),
+        "b5fc85e55755f9e0d030a10ab4429b6b2944855f9a0d60077fe832becbc41d72"
+    );
+    assert!(emails.is_null(1));
+    let phones = masked.column_by_name("phone").unwrap().as_string::<i32>();
+    assert_eq!(phones.value(0), "****0142");
+    assert_eq!(phones.value(1), "12");
+    assert_eq!(masked.column_by_name("id").unwrap(), contacts().column(0));
+}
+
+#[test]
+fn redacted_values_are_replaced_with_a_marker() {
+    let mask = MaskTransformer::new(vec![("phone".to_string(), MaskMode::Redact)]);
+
+    let masked = mask.mask(&contacts()).unwrap();
+
+    let phones = masked.column_by_name("phone").unwrap().as_string::<i32>();
+    assert_eq!(phones.iter().collect::<Vec<_>>(), [Some("REDACTED"), Some("REDACTED")]);
+}
+
+#[test]
+fn non_string_columns_are_rejected() {
+    let mask = MaskTransformer::new(vec![("id".to_string(), MaskMode::Sha256)]);
+
+    let err = mask.mask(&contacts()).unwrap_err();
+
+    assert!(err.to_string().contains("Cannot mask column 'id': expected a string column"));
+}
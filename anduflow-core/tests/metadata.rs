@@ -0,0 +1,95 @@
+//! Source metadata of `RestExtractor` against a mock server.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use httpmock::Method::HEAD;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+async fn metadata(extractor: &RestExtractor) -> Value {
+    serde_json::from_str(&extractor.metadata().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn metadata_comes_from_the_head_response_headers() {
+    let server = MockServer::start_async().await;
+    let head = server
+        .mock_async(|when, then| {
+            when.method(HEAD).path("/export.csv");
+            then.status(200)
+                .header("content-type", "text/csv")
+                .header("content-length", "2048")
+                .header("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+                .header("etag", "\"v42\"");
+        })
+        .await;
+    let get = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/export.csv");
+            then.status(200).body("id\n1\n");
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "export.csv");
+
+    assert_eq!(
+        metadata(&extractor).await,
+        json!({
+            "status": 200,
+            "content_type": "text/csv",
+            "content_length": 2048,
+            "last_modified": "Wed, 21 Oct 2015 07:28:00 GMT",
+            "etag": "\"v42\"",
+        })
+    );
+    head.assert_async().await;
+    get.assert_hits_async(0).await;
+}
+
+#[tokio::test]
+async fn a_rejected_head_falls_back_to_get() {
+    let server = MockServer::start_async().await;
+    let head = server
+        .mock_async(|when, then| {
+            when.method(HEAD).path("/items");
+            then.status(405);
+        })
+        .await;
+    let get = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([{"id": 1}]));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "items");
+    let metadata = metadata(&extractor).await;
+
+    assert_eq!(metadata["status"], json!(200));
+    assert_eq!(metadata["content_type"], json!("application/json"));
+    assert!(metadata.get("etag").is_none());
+    assert!(metadata.get("last_modified").is_none());
+    head.assert_async().await;
+    get.assert_async().await;
+}
+
+#[tokio::test]
+async fn an_error_status_fails_instead_of_panicking() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(HEAD).path("/items");
+            then.status(404);
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "items");
+
+    assert!(matches!(
+        extractor.metadata().await,
+        Err(ExtractorError::HttpStatusError { status: 404, .. })
+    ));
+}
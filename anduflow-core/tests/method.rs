@@ -0,0 +1,41 @@
+//! Changing the HTTP method without losing the rest of the request.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[test]
+fn query_params_set_before_and_after_the_method_survive() {
+    let extractor = RestExtractor::new("https://api.example.com", "search")
+        .with_query_param(&[("q", "rust")])
+        .with_method("POST")
+        .with_query_param(&[("page", "2")]);
+
+    assert_eq!(extractor.url(), "https://api.example.com/search?q=rust&page=2");
+}
+
+#[tokio::test]
+async fn the_sent_request_keeps_params_headers_and_body() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/search")
+                .query_param("q", "rust")
+                .query_param("page", "2")
+                .header("x-tenant", "acme")
+                .json_body(json!({"filter": "stars > 100"}));
+            then.status(200).json_body(json!([]));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "search")
+        .with_query_param(&[("q", "rust")])
+        .with_header("x-tenant", "acme")
+        .with_json_body(&json!({"filter": "stars > 100"}))
+        .with_method("POST")
+        .with_query_param(&[("page", "2")]);
+    extractor.extract_response().await.unwrap();
+
+    mock.assert_async().await;
+}
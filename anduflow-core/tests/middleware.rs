@@ -0,0 +1,60 @@
+//! Sending requests through a `reqwest-middleware` client.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::logger::store::LogStore;
+use async_trait::async_trait;
+use http::Extensions;
+use httpmock::prelude::*;
+use reqwest::{Request, Response};
+use reqwest_middleware::{ClientBuilder, Middleware, Next};
+use serde_json::{Value, json};
+
+/// Passes every request through unchanged, counting how many it saw.
+#[derive(Default)]
+struct CountingMiddleware {
+    seen: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Middleware for CountingMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        self.seen.fetch_add(1, Ordering::SeqCst);
+        next.run(req, extensions).await
+    }
+}
+
+#[tokio::test]
+async fn requests_succeed_through_a_no_op_middleware() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users").header("accept", "application/json");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([{"id": 1}]));
+        })
+        .await;
+
+    let middleware = CountingMiddleware::default();
+    let seen = middleware.seen.clone();
+    let client = ClientBuilder::new(reqwest::Client::new()).with(middleware).build();
+    let extractor = RestExtractor::new(&server.base_url(), "users")
+        .with_header("accept", "application/json")
+        .with_middleware_client(client);
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    let users: Value = extractor.extract_json(&mut log).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(users, json!([{"id": 1}]));
+    assert_eq!(seen.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,75 @@
+//! Canned responses and call counting in `MockExtractor`.
+
+use anduflow_core::extract::mock_extractor::MockExtractor;
+use anduflow_core::extract::{Checkpoint, Extractor};
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::{LogStatus, LogStore};
+use serde_json::{Value, json};
+
+#[tokio::test]
+async fn canned_responses_are_returned_by_their_methods() {
+    let extractor = MockExtractor::new()
+        .with_json(json!({"id": 1}))
+        .with_text("plain")
+        .with_bytes(vec![0, 1, 2]);
+    let mut log = LogStore::new("mock".to_string(), "extract".to_string());
+
+    let value: Value = extractor.extract_json(&mut log).await.unwrap();
+    assert_eq!(value, json!({"id": 1}));
+    assert_eq!(extractor.extract_text().await.unwrap(), "plain");
+    assert_eq!(extractor.extract_bytes().await.unwrap(), [0, 1, 2]);
+    assert_eq!(&extractor.extract_raw().await.unwrap()[..], [0, 1, 2]);
+    assert!(matches!(log.status(), LogStatus::Completed));
+}
+
+#[tokio::test]
+async fn calls_are_counted_per_method() {
+    let extractor = MockExtractor::new().with_text("plain");
+
+    extractor.ping().await.unwrap();
+    extractor.extract_text().await.unwrap();
+    extractor.extract_text().await.unwrap();
+
+    assert_eq!(extractor.ping_calls(), 1);
+    assert_eq!(extractor.text_calls(), 2);
+    assert_eq!(extractor.json_calls(), 0);
+    assert_eq!(extractor.bytes_calls(), 0);
+}
+
+#[tokio::test]
+async fn queued_errors_are_returned_before_the_canned_response() {
+    let extractor = MockExtractor::new()
+        .with_text("recovered")
+        .with_error(ExtractorError::ExtractOpsError("first".into()))
+        .with_error(ExtractorError::ExtractOpsError("second".into()));
+
+    let first = extractor.extract_text().await.unwrap_err();
+    let second = extractor.extract_text().await.unwrap_err();
+
+    assert!(first.to_string().contains("first"));
+    assert!(second.to_string().contains("second"));
+    assert_eq!(extractor.extract_text().await.unwrap(), "recovered");
+    assert_eq!(extractor.text_calls(), 3);
+}
+
+#[tokio::test]
+async fn a_method_without_a_canned_response_fails() {
+    let extractor = MockExtractor::new().with_json(json!([]));
+
+    let err = extractor.extract_bytes().await.unwrap_err();
+
+    assert!(err.to_string().contains("no canned response for extract_bytes"));
+}
+
+#[test]
+fn incremental_support_is_optional() {
+    let mut incremental = MockExtractor::new().with_incremental(true);
+    let mut plain = MockExtractor::new();
+
+    incremental.set_checkpoint(Checkpoint("2024-01-01".into())).unwrap();
+
+    assert!(incremental.supports_incremental());
+    assert_eq!(incremental.checkpoint().unwrap().0, "2024-01-01");
+    assert!(!plain.supports_incremental());
+    assert!(plain.set_checkpoint(Checkpoint("2024-01-01".into())).is_err());
+}
@@ -0,0 +1,61 @@
+//! Posting `multipart/form-data` bodies with text and file fields.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+
+#[tokio::test]
+async fn a_multipart_form_is_posted() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/imports")
+                .header_exists("content-type")
+                .body_matches(Regex::new("^--[^\r\n]+\r\n").unwrap())
+                .body_contains("Content-Disposition: form-data; name=\"dataset\"\r\n\r\norders")
+                .body_contains(
+                    "Content-Disposition: form-data; name=\"file\"; filename=\"orders.csv\"",
+                )
+                .body_contains("Content-Type: text/csv\r\n\r\nid,total\n1,9.5\n");
+            then.status(201).body("queued");
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "imports")
+        .with_method("POST")
+        .with_multipart_text_field("dataset", "orders")
+        .with_multipart_file("file", "orders.csv", "text/csv", "id,total\n1,9.5\n");
+
+    assert_eq!(extractor.extract_text().await.unwrap(), "queued");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn a_multipart_body_can_be_sent_more_than_once() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/imports").body_contains("name=\"dataset\"");
+            then.status(200);
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "imports")
+        .with_multipart_text_field("dataset", "orders")
+        .with_method("POST");
+
+    extractor.ping().await.unwrap();
+    extractor.extract_bytes().await.unwrap();
+
+    mock.assert_hits_async(2).await;
+}
+
+#[test]
+fn an_invalid_mime_type_fails_when_the_request_is_built() {
+    let result = RestExtractor::new("https://api.example.com", "imports")
+        .with_method("POST")
+        .with_multipart_file("file", "orders.csv", "not a mime type", "id\n")
+        .build_request();
+
+    assert!(result.is_err());
+}
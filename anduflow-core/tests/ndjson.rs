@@ -0,0 +1,74 @@
+//! Extracting newline-delimited JSON, with and without skipping malformed lines.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Event {
+    id: u64,
+    kind: String,
+}
+
+fn event(id: u64, kind: &str) -> Event {
+    Event { id, kind: kind.to_string() }
+}
+
+/// Three events, of which the second is cut off.
+const WITH_MALFORMED_LINE: &str =
+    "{\"id\":1,\"kind\":\"login\"}\n{\"id\":2,\n{\"id\":3,\"kind\":\"logout\"}";
+
+async fn serve(server: &MockServer, body: &str) {
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events");
+            then.status(200)
+                .header("content-type", "application/x-ndjson")
+                .body(body);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn clean_ndjson_is_one_item_per_line() {
+    let server = MockServer::start_async().await;
+    serve(&server, "{\"id\":1,\"kind\":\"login\"}\n{\"id\":2,\"kind\":\"logout\"}").await;
+    let extractor = RestExtractor::new(&server.base_url(), "events");
+
+    let events: Vec<Event> = extractor.extract_ndjson().await.unwrap();
+
+    assert_eq!(events, [event(1, "login"), event(2, "logout")]);
+}
+
+#[tokio::test]
+async fn a_blank_trailing_line_is_ignored() {
+    let server = MockServer::start_async().await;
+    serve(&server, "{\"id\":1,\"kind\":\"login\"}\n{\"id\":2,\"kind\":\"logout\"}\n\n").await;
+    let extractor = RestExtractor::new(&server.base_url(), "events");
+
+    let events: Vec<Event> = extractor.extract_ndjson().await.unwrap();
+
+    assert_eq!(events.len(), 2);
+}
+
+#[tokio::test]
+async fn a_malformed_line_fails_with_its_line_number() {
+    let server = MockServer::start_async().await;
+    serve(&server, WITH_MALFORMED_LINE).await;
+    let extractor = RestExtractor::new(&server.base_url(), "events");
+
+    let err = extractor.extract_ndjson::<Event>().await.unwrap_err();
+
+    assert!(err.to_string().contains("Malformed NDJSON on line 2"));
+}
+
+#[tokio::test]
+async fn a_malformed_line_is_skipped_when_enabled() {
+    let server = MockServer::start_async().await;
+    serve(&server, WITH_MALFORMED_LINE).await;
+    let extractor = RestExtractor::new(&server.base_url(), "events").with_skip_malformed(true);
+
+    let events: Vec<Event> = extractor.extract_ndjson().await.unwrap();
+
+    assert_eq!(events, [event(1, "login"), event(3, "logout")]);
+}
@@ -0,0 +1,72 @@
+//! OAuth2 client-credentials flow of `RestExtractor` against a mock server.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[tokio::test]
+async fn one_token_is_requested_and_reused() {
+    let server = MockServer::start_async().await;
+    let token = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/oauth/token")
+                .body_contains("grant_type=client_credentials")
+                .body_contains("client_id=my-client")
+                .body_contains("scope=invoices.read");
+            then.status(200)
+                .json_body(json!({"access_token": "token-1", "expires_in": 3600}));
+        })
+        .await;
+    let invoices = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/invoices")
+                .header("authorization", "Bearer token-1");
+            then.status(200).json_body(json!([{"id": 1}]));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "invoices").with_oauth2_client_credentials(
+        &server.url("/oauth/token"),
+        "my-client",
+        "my-secret",
+        &["invoices.read"],
+    );
+    extractor.extract_value().await.unwrap();
+    extractor.extract_value().await.unwrap();
+
+    token.assert_hits_async(1).await;
+    invoices.assert_hits_async(2).await;
+}
+
+#[tokio::test]
+async fn a_rejected_token_request_is_an_auth_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/oauth/token");
+            then.status(401).json_body(json!({"error": "invalid_client"}));
+        })
+        .await;
+    let invoices = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/invoices");
+            then.status(200).json_body(json!([]));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "invoices").with_oauth2_client_credentials(
+        &server.url("/oauth/token"),
+        "my-client",
+        "wrong-secret",
+        &[],
+    );
+    let err = extractor.extract_value().await.unwrap_err();
+
+    assert!(matches!(err, ExtractorError::AuthError(_)), "{err:?}");
+    assert!(err.to_string().contains("invalid_client"));
+    invoices.assert_hits_async(0).await;
+}
@@ -0,0 +1,100 @@
+//! OAuth2 refresh-token flow of `RestExtractor` against a mock server.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anduflow_core::clock::TestClock;
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::LogStore;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+async fn extract(extractor: &RestExtractor) -> Result<Value, ExtractorError> {
+    let mut log = LogStore::new("events".to_string(), "extract".to_string());
+    extractor.extract_json(&mut log).await
+}
+
+#[tokio::test]
+async fn a_rotated_refresh_token_is_used_for_the_next_refresh() {
+    let server = MockServer::start_async().await;
+    let first = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/oauth/token")
+                .body_contains("grant_type=refresh_token")
+                .body_contains("refresh_token=initial")
+                .body_contains("client_id=my-client");
+            then.status(200).json_body(json!({
+                "access_token": "token-1",
+                "expires_in": 60,
+                "refresh_token": "rotated"
+            }));
+        })
+        .await;
+    let second = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/oauth/token")
+                .body_contains("refresh_token=rotated");
+            then.status(200)
+                .json_body(json!({"access_token": "token-2", "expires_in": 60}));
+        })
+        .await;
+    let mut events = Vec::new();
+    for token in ["token-1", "token-2"] {
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/me/events")
+                    .header("authorization", format!("Bearer {token}"));
+                then.status(200).json_body(json!([{"token": token}]));
+            })
+            .await;
+        events.push(mock);
+    }
+
+    let clock = Arc::new(TestClock::new());
+    let extractor = RestExtractor::new(&server.base_url(), "me/events")
+        .with_clock(clock.clone())
+        .with_oauth2_refresh(&server.url("/oauth/token"), "my-client", "my-secret", "initial");
+
+    assert_eq!(extract(&extractor).await.unwrap(), json!([{"token": "token-1"}]));
+    assert_eq!(extract(&extractor).await.unwrap(), json!([{"token": "token-1"}]));
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(extract(&extractor).await.unwrap(), json!([{"token": "token-2"}]));
+
+    first.assert_hits_async(1).await;
+    second.assert_hits_async(1).await;
+    events[0].assert_hits_async(2).await;
+    events[1].assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn a_rejected_refresh_token_is_an_auth_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/oauth/token");
+            then.status(400).json_body(json!({"error": "invalid_grant"}));
+        })
+        .await;
+    let events = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/me/events");
+            then.status(200).json_body(json!([]));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "me/events").with_oauth2_refresh(
+        &server.url("/oauth/token"),
+        "my-client",
+        "my-secret",
+        "revoked",
+    );
+    let err = extract(&extractor).await.unwrap_err();
+
+    assert!(matches!(err, ExtractorError::AuthError(_)));
+    events.assert_hits_async(0).await;
+}
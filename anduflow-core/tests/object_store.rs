@@ -0,0 +1,73 @@
+//! Reading objects through `ObjectStoreExtractor` from an in-memory store.
+
+use std::sync::Arc;
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::object_store_extractor::ObjectStoreExtractor;
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::LogStore;
+use object_store::memory::InMemory;
+use object_store::path::Path;
+use object_store::{ObjectStore, PutPayload};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct User {
+    id: u64,
+    name: String,
+}
+
+async fn store_with(path: &str, contents: &'static [u8]) -> Arc<InMemory> {
+    let store = Arc::new(InMemory::new());
+    store
+        .put(&Path::from(path), PutPayload::from_static(contents))
+        .await
+        .unwrap();
+    store
+}
+
+#[tokio::test]
+async fn json_objects_are_deserialized() {
+    let store = store_with("exports/users.json", br#"[{"id":1,"name":"ada"}]"#).await;
+    let extractor = ObjectStoreExtractor::new(store, "exports/users.json");
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    let users: Vec<User> = extractor.extract_json(&mut log).await.unwrap();
+
+    assert_eq!(users, [User { id: 1, name: "ada".to_string() }]);
+}
+
+#[tokio::test]
+async fn text_bytes_and_metadata_are_read() {
+    let store = store_with("notes/today.txt", b"hello\n").await;
+    let extractor = ObjectStoreExtractor::new(store, "notes/today.txt");
+
+    extractor.ping().await.unwrap();
+    assert_eq!(extractor.extract_text().await.unwrap(), "hello\n");
+    assert_eq!(extractor.extract_bytes().await.unwrap(), b"hello\n");
+    assert_eq!(&extractor.extract_raw().await.unwrap()[..], b"hello\n");
+
+    let metadata: Value = serde_json::from_str(&extractor.metadata().await.unwrap()).unwrap();
+    assert_eq!(metadata["path"], "notes/today.txt");
+    assert_eq!(metadata["size"], 6);
+    assert!(metadata["last_modified"].is_string());
+}
+
+#[tokio::test]
+async fn a_missing_object_is_an_object_store_error() {
+    let extractor = ObjectStoreExtractor::new(Arc::new(InMemory::new()), "missing.json");
+
+    assert!(matches!(extractor.ping().await, Err(ExtractorError::ObjectStoreError(_))));
+    assert!(matches!(extractor.extract_raw().await, Err(ExtractorError::ObjectStoreError(_))));
+}
+
+#[tokio::test]
+async fn binary_objects_are_not_text() {
+    let store = store_with("images/logo.png", b"\x89PNG\r\n\x1a\n\xff").await;
+    let extractor = ObjectStoreExtractor::new(store, "images/logo.png");
+
+    let err = extractor.extract_text().await.unwrap_err();
+
+    assert!(err.to_string().contains("Object 'images/logo.png' is not valid UTF-8"));
+}
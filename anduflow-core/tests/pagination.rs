@@ -0,0 +1,454 @@
+//! Paginated extraction with `RestExtractor` against a mock server.
+
+use anduflow_core::extract::rest_extractor::{Pagination, RestExtractor};
+use anduflow_utils::error::ExtractorError;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+fn page_number(page_size: usize) -> Pagination {
+    Pagination::PageNumber {
+        page_param: "page".to_string(),
+        size_param: "per_page".to_string(),
+        page_size,
+    }
+}
+
+#[tokio::test]
+async fn partial_results_keep_the_pages_before_a_failure() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items").query_param("page", "1");
+            then.status(200).json_body(json!([{"id": 1}, {"id": 2}]));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items").query_param("page", "2");
+            then.status(500).body("upstream exploded");
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "items")
+        .with_pagination(page_number(2))
+        .with_partial_results(true);
+    let paginated = extractor.extract_all_json::<Value>().await.unwrap();
+
+    assert_eq!(paginated.items, vec![json!({"id": 1}), json!({"id": 2})]);
+    assert_eq!(paginated.pages, 1);
+    assert!(matches!(
+        paginated.error,
+        Some(ExtractorError::HttpStatusError { status: 500, .. })
+    ));
+}
+
+#[tokio::test]
+async fn without_partial_results_a_failed_page_fails_the_extraction() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items").query_param("page", "1");
+            then.status(200).json_body(json!([{"id": 1}, {"id": 2}]));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items").query_param("page", "2");
+            then.status(500).body("upstream exploded");
+        })
+        .await;
+
+    let extractor =
+        RestExtractor::new(&server.base_url(), "items").with_pagination(page_number(2));
+    let err = extractor.extract_all_json::<Value>().await.unwrap_err();
+
+    assert!(matches!(err, ExtractorError::HttpStatusError { status: 500, .. }));
+}
+
+#[tokio::test]
+async fn partial_results_apply_to_post_pagination() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/search")
+                .json_body(json!({"query": "rust", "cursor": "c2"}));
+            then.status(503).body("try later");
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search").json_body(json!({"query": "rust"}));
+            then.status(200)
+                .json_body(json!({"results": [{"id": 1}], "next": "c2"}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "search")
+        .with_method("POST")
+        .with_json_body(&json!({"query": "rust"}))
+        .with_partial_results(true);
+    let paginated = extractor
+        .extract_paginated_post::<Value>("/next", "/cursor")
+        .await
+        .unwrap();
+
+    assert_eq!(paginated.items, vec![json!({"id": 1})]);
+    assert_eq!(paginated.pages, 1);
+    let err = paginated.error.unwrap();
+    assert_eq!(err.status_code(), Some(503));
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn a_cursor_leading_back_to_a_visited_page_is_a_loop() {
+    let server = MockServer::start_async().await;
+    let page_a = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events").query_param("cursor", "a");
+            then.status(200)
+                .json_body(json!({"data": [{"id": 3}], "next": "b"}));
+        })
+        .await;
+    let page_b = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events").query_param("cursor", "b");
+            then.status(200)
+                .json_body(json!({"data": [{"id": 2}], "next": "a"}));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events");
+            then.status(200)
+                .json_body(json!({"data": [{"id": 1}], "next": "b"}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "events").with_pagination(
+        Pagination::Cursor {
+            next_field: "/next".to_string(),
+            cursor_param: "cursor".to_string(),
+        },
+    );
+    let err = extractor.extract_all_json::<Value>().await.unwrap_err();
+
+    assert!(matches!(&err, ExtractorError::PaginationLoop(url) if url.contains("cursor=b")));
+    assert_eq!(page_b.hits_async().await, 1);
+    assert_eq!(page_a.hits_async().await, 1);
+}
+
+#[tokio::test]
+async fn a_repeated_post_cursor_is_a_loop() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search").json_body(json!({"cursor": "x"}));
+            then.status(200)
+                .json_body(json!({"results": [{"id": 2}], "next": "x"}));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search");
+            then.status(200)
+                .json_body(json!({"results": [{"id": 1}], "next": "x"}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "search").with_method("POST");
+    let err = extractor
+        .extract_paginated_post::<Value>("/next", "/cursor")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ExtractorError::PaginationLoop(_)));
+}
+
+#[tokio::test]
+async fn post_pagination_sends_the_cursor_and_stops_at_an_empty_page() {
+    let server = MockServer::start_async().await;
+    let second = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/search")
+                .header("content-type", "application/json")
+                .json_body(json!({"query": "rust", "page": {"cursor": "c2"}}));
+            then.status(200)
+                .json_body(json!({"results": [], "next": "c3"}));
+        })
+        .await;
+    let third = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search").body_contains("c3");
+            then.status(200)
+                .json_body(json!({"results": [{"id": 3}], "next": null}));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search").json_body(json!({"query": "rust"}));
+            then.status(200)
+                .json_body(json!({"results": [{"id": 1}, {"id": 2}], "next": "c2"}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "search")
+        .with_method("POST")
+        .with_json_body(&json!({"query": "rust"}));
+    let paginated = extractor
+        .extract_paginated_post::<Value>("/next", "/page/cursor")
+        .await
+        .unwrap();
+
+    assert_eq!(paginated.items, vec![json!({"id": 1}), json!({"id": 2})]);
+    assert_eq!(paginated.pages, 2);
+    second.assert_async().await;
+    assert_eq!(third.hits_async().await, 0);
+}
+
+#[tokio::test]
+async fn post_pagination_stops_at_the_page_limit() {
+    let server = MockServer::start_async().await;
+    let beyond_limit = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search").body_contains("c3");
+            then.status(200)
+                .json_body(json!({"results": [{"id": 3}], "next": "c4"}));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search").body_contains("c2");
+            then.status(200)
+                .json_body(json!({"results": [{"id": 2}], "next": "c3"}));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search");
+            then.status(200)
+                .json_body(json!({"results": [{"id": 1}], "next": "c2"}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "search")
+        .with_method("POST")
+        .with_max_pages(2);
+    let paginated = extractor
+        .extract_paginated_post::<Value>("/next", "/cursor")
+        .await
+        .unwrap();
+
+    assert_eq!(paginated.items, vec![json!({"id": 1}), json!({"id": 2})]);
+    assert!(paginated.error.is_none());
+    assert_eq!(beyond_limit.hits_async().await, 0);
+}
+
+#[tokio::test]
+async fn post_pagination_keeps_a_configured_content_type() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/search")
+                .header("content-type", "application/vnd.api+json")
+                .body_contains("rust");
+            then.status(200).json_body(json!({"results": [{"id": 1}]}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "search")
+        .with_method("POST")
+        .with_header("Content-Type", "application/vnd.api+json")
+        .with_json_body(&json!({"query": "rust"}));
+    let paginated = extractor
+        .extract_paginated_post::<Value>("/next", "/cursor")
+        .await
+        .unwrap();
+
+    assert_eq!(paginated.items, vec![json!({"id": 1})]);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn offset_pagination_concatenates_three_pages_in_order() {
+    let server = MockServer::start_async().await;
+    for (offset, records) in [
+        ("0", json!([{"id": 1}, {"id": 2}])),
+        ("2", json!([{"id": 3}, {"id": 4}])),
+        ("4", json!([{"id": 5}])),
+    ] {
+        server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/articles")
+                    .query_param("offset", offset)
+                    .query_param("limit", "2");
+                then.status(200).json_body(records);
+            })
+            .await;
+    }
+
+    let extractor = RestExtractor::new(&server.base_url(), "articles").with_pagination(
+        Pagination::OffsetLimit {
+            offset_param: "offset".to_string(),
+            limit_param: "limit".to_string(),
+            page_size: 2,
+        },
+    );
+    let paginated = extractor.extract_all_json::<Value>().await.unwrap();
+
+    let ids: Vec<i64> = paginated.items.iter().map(|r| r["id"].as_i64().unwrap()).collect();
+    assert_eq!(ids, [1, 2, 3, 4, 5]);
+    assert_eq!(paginated.pages, 3);
+}
+
+#[tokio::test]
+async fn page_number_pagination_concatenates_three_pages_in_order() {
+    let server = MockServer::start_async().await;
+    for (page, records) in [
+        ("1", json!({"results": [{"id": 1}, {"id": 2}]})),
+        ("2", json!({"results": [{"id": 3}, {"id": 4}]})),
+        ("3", json!({"results": []})),
+    ] {
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/articles").query_param("page", page);
+                then.status(200).json_body(records);
+            })
+            .await;
+    }
+
+    let extractor =
+        RestExtractor::new(&server.base_url(), "articles").with_pagination(page_number(2));
+    let paginated = extractor.extract_all_json::<Value>().await.unwrap();
+
+    let ids: Vec<i64> = paginated.items.iter().map(|r| r["id"].as_i64().unwrap()).collect();
+    assert_eq!(ids, [1, 2, 3, 4]);
+    assert_eq!(paginated.pages, 3);
+}
+
+#[tokio::test]
+async fn cursor_pagination_concatenates_three_pages_in_order() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events").query_param("cursor", "p3");
+            then.status(200)
+                .json_body(json!({"data": [{"id": 3}], "meta": {"next_cursor": null}}));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events").query_param("cursor", "p2");
+            then.status(200)
+                .json_body(json!({"data": [{"id": 2}], "meta": {"next_cursor": "p3"}}));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events");
+            then.status(200)
+                .json_body(json!({"data": [{"id": 1}], "meta": {"next_cursor": "p2"}}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "events").with_pagination(
+        Pagination::Cursor {
+            next_field: "/meta/next_cursor".to_string(),
+            cursor_param: "cursor".to_string(),
+        },
+    );
+    let paginated = extractor.extract_all_json::<Value>().await.unwrap();
+
+    assert_eq!(
+        paginated.items,
+        vec![json!({"id": 1}), json!({"id": 2}), json!({"id": 3})]
+    );
+}
+
+#[tokio::test]
+async fn a_server_ignoring_the_page_parameter_fails_instead_of_looping() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/articles");
+            then.status(200).json_body(json!([{"id": 1}, {"id": 2}]));
+        })
+        .await;
+
+    let extractor =
+        RestExtractor::new(&server.base_url(), "articles").with_pagination(page_number(2));
+    let err = extractor.extract_all_json::<Value>().await.unwrap_err();
+
+    assert!(err.to_string().contains("'page'"));
+    assert_eq!(mock.hits_async().await, 2);
+}
+
+#[tokio::test]
+async fn max_pages_stops_offset_pagination() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/articles");
+            then.status(200).json_body(json!([{"id": 1}, {"id": 2}]));
+        })
+        .await;
+
+    let offset = RestExtractor::new(&server.base_url(), "articles")
+        .with_pagination(Pagination::OffsetLimit {
+            offset_param: "offset".to_string(),
+            limit_param: "limit".to_string(),
+            page_size: 2,
+        })
+        .with_max_pages(1);
+    let paginated = offset.extract_all_json::<Value>().await.unwrap();
+
+    assert_eq!(paginated.pages, 1);
+    assert_eq!(paginated.items.len(), 2);
+    assert_eq!(mock.hits_async().await, 1);
+}
+
+/// Serves two overlapping pages of events, sharing the record with id 2.
+async fn serve_overlapping_pages(server: &MockServer) {
+    let pages = [
+        json!([{"id": 1}, {"id": 2}]),
+        json!([{"id": 2}, {"id": 3}]),
+        json!([]),
+    ];
+    for (page, body) in pages.into_iter().enumerate() {
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/events").query_param("page", (page + 1).to_string());
+                then.status(200).json_body(body);
+            })
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn records_repeated_across_pages_are_kept_once() {
+    let server = MockServer::start_async().await;
+    serve_overlapping_pages(&server).await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "events")
+        .with_pagination(page_number(2))
+        .with_dedup_key("/id");
+    let paginated = extractor.extract_all_json::<Value>().await.unwrap();
+
+    assert_eq!(paginated.items, vec![json!({"id": 1}), json!({"id": 2}), json!({"id": 3})]);
+    assert_eq!(paginated.pages, 3);
+}
+
+#[tokio::test]
+async fn without_a_dedup_key_overlapping_records_are_repeated() {
+    let server = MockServer::start_async().await;
+    serve_overlapping_pages(&server).await;
+
+    let extractor =
+        RestExtractor::new(&server.base_url(), "events").with_pagination(page_number(2));
+    let paginated = extractor.extract_all_json::<Value>().await.unwrap();
+
+    assert_eq!(paginated.items.len(), 4);
+}
@@ -0,0 +1,66 @@
+//! Appending record batches to a Parquet file with `ParquetLoader`.
+
+use std::fs::File;
+use std::sync::Arc;
+
+use anduflow_core::load::Loader;
+use anduflow_core::load::parquet_loader::ParquetLoader;
+use datafusion::arrow::array::{Array, Int64Array, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+fn batch(ids: Vec<i64>) -> RecordBatch {
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+    RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(ids))]).unwrap()
+}
+
+fn read_back(path: &std::path::Path) -> Vec<RecordBatch> {
+    ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+        .unwrap()
+        .build()
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+}
+
+#[tokio::test]
+async fn two_loads_are_appended_to_one_file() {
+    let path = std::env::temp_dir().join(format!("anduflow-parquet-{}.parquet", std::process::id()));
+    let loader = ParquetLoader::new(&path);
+
+    loader.load(batch(vec![1, 2, 3])).await.unwrap();
+    loader.load(batch(vec![4, 5])).await.unwrap();
+    loader.finish().unwrap();
+
+    let batches = read_back(&path);
+    let rows: usize = batches.iter().map(RecordBatch::num_rows).sum();
+    assert_eq!(rows, 5);
+    let ids: Vec<i64> = batches
+        .iter()
+        .flat_map(|batch| {
+            let ids = batch.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+            ids.values().to_vec()
+        })
+        .collect();
+    assert_eq!(ids, [1, 2, 3, 4, 5]);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn a_batch_with_another_schema_is_rejected() {
+    let path =
+        std::env::temp_dir().join(format!("anduflow-parquet-schema-{}.parquet", std::process::id()));
+    let loader = ParquetLoader::new(&path);
+    loader.load(batch(vec![1])).await.unwrap();
+
+    let names = Arc::new(Schema::new(vec![Field::new("name", DataType::Utf8, false)]));
+    let other = RecordBatch::try_new(names, vec![Arc::new(StringArray::from(vec!["ada"]))]).unwrap();
+    assert!(loader.load(other).await.is_err());
+
+    loader.finish().unwrap();
+    let batches = read_back(&path);
+    assert_eq!(batches.iter().map(RecordBatch::num_rows).sum::<usize>(), 1);
+    assert_eq!(batches[0].column(0).len(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,81 @@
+//! `ping` and `reachable` of `RestExtractor` against a mock server.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use httpmock::Method::HEAD;
+use httpmock::prelude::*;
+
+async fn server_responding(status: u16) -> MockServer {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(HEAD).path("/status");
+            then.status(status);
+        })
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn ping_succeeds_on_a_success_status() {
+    let server = server_responding(200).await;
+    let extractor = RestExtractor::new(&server.base_url(), "status");
+
+    extractor.ping().await.unwrap();
+}
+
+#[tokio::test]
+async fn ping_fails_on_an_error_status() {
+    let server = server_responding(503).await;
+    let extractor = RestExtractor::new(&server.base_url(), "status");
+
+    assert!(matches!(
+        extractor.ping().await,
+        Err(ExtractorError::HttpStatusError { status: 503, .. })
+    ));
+}
+
+#[tokio::test]
+async fn ping_falls_back_to_get_when_head_is_rejected() {
+    let server = MockServer::start_async().await;
+    let head = server
+        .mock_async(|when, then| {
+            when.method(HEAD).path("/status");
+            then.status(405);
+        })
+        .await;
+    let get = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/status");
+            then.status(200).body("ok");
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "status");
+    extractor.ping().await.unwrap();
+
+    head.assert_async().await;
+    get.assert_async().await;
+}
+
+#[tokio::test]
+async fn reachable_returns_the_status_whatever_it_is() {
+    let server = server_responding(503).await;
+    let extractor = RestExtractor::new(&server.base_url(), "status");
+
+    assert_eq!(extractor.reachable().await.unwrap(), 503);
+}
+
+#[tokio::test]
+async fn an_unreachable_source_fails_both_checks() {
+    // Nothing listens on a port once its listener is dropped.
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let extractor = RestExtractor::new(&format!("http://{addr}"), "status");
+
+    assert!(extractor.ping().await.is_err());
+    assert!(extractor.reachable().await.is_err());
+}
@@ -0,0 +1,324 @@
+//! Extract, transform and load runs of `Pipeline`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::mock_extractor::MockExtractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_core::load::Loader;
+use anduflow_core::pipeline::{Pipeline, Stage};
+use anduflow_core::transform::select_columns::SelectColumns;
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::{LogStatus, LogStore};
+use async_trait::async_trait;
+use bytes::Bytes;
+use datafusion::arrow::record_batch::RecordBatch;
+use httpmock::prelude::*;
+use rusqlite::Connection;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+/// Keeps every batch it is given.
+#[derive(Debug, Clone, Default)]
+struct CapturingLoader {
+    batches: Arc<Mutex<Vec<RecordBatch>>>,
+}
+
+#[async_trait]
+impl Loader for CapturingLoader {
+    async fn load(&self, batch: RecordBatch) -> ExtractorResult<()> {
+        self.batches.lock().unwrap().push(batch);
+        Ok(())
+    }
+}
+
+fn log() -> LogStore {
+    LogStore::new("users".to_string(), "pipeline".to_string())
+}
+
+/// The error message recorded on a log.
+fn error_message(log: &LogStore) -> String {
+    serde_json::to_value(log).unwrap()["error_message"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[tokio::test]
+async fn records_are_extracted_transformed_and_loaded() {
+    let loader = CapturingLoader::default();
+    let extractor = MockExtractor::new().with_json(json!([
+        {"id": 1, "email": "ada@example.com", "internal": "x"},
+        {"id": 2, "email": "grace@example.com", "internal": "y"},
+    ]));
+    let mut pipeline = Pipeline::new(extractor, loader.clone())
+        .with_transform(SelectColumns::new(&["id", "email"]));
+    let mut log = log();
+
+    assert_eq!(pipeline.run(&mut log).await.unwrap(), 2);
+
+    assert!(matches!(log.status(), LogStatus::Completed));
+    let batches = loader.batches.lock().unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].num_columns(), 2);
+    assert_eq!(batches[0].num_rows(), 2);
+}
+
+#[tokio::test]
+async fn an_empty_extraction_loads_nothing() {
+    let loader = CapturingLoader::default();
+    let extractor = MockExtractor::new().with_json(Value::Array(Vec::new()));
+    let mut pipeline = Pipeline::new(extractor, loader.clone());
+    let mut log = log();
+
+    assert_eq!(pipeline.run(&mut log).await.unwrap(), 0);
+
+    assert!(matches!(log.status(), LogStatus::Completed));
+    assert!(loader.batches.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn a_slow_extractor_fails_the_extract_stage_timeout() {
+    let loader = CapturingLoader::default();
+    let extractor = MockExtractor::new()
+        .with_json(json!([{"id": 1}]))
+        .with_delay(Duration::from_secs(5));
+    let mut pipeline = Pipeline::new(extractor, loader.clone())
+        .with_stage_timeout(Stage::Extract, Duration::from_millis(50))
+        .with_stage_timeout(Stage::Load, Duration::from_secs(60));
+    let mut log = log();
+
+    let err = pipeline.run(&mut log).await.unwrap_err();
+
+    assert!(err.to_string().contains("extract stage timed out"));
+    assert!(matches!(log.status(), LogStatus::Failed));
+    assert!(error_message(&log).starts_with("extract stage failed"));
+    assert!(loader.batches.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn a_failing_transform_is_reported_as_the_transform_stage() {
+    let extractor = MockExtractor::new().with_json(json!([{"id": 1}]));
+    let mut pipeline = Pipeline::new(extractor, CapturingLoader::default())
+        .with_transform(SelectColumns::new(&["missing"]));
+    let mut log = log();
+
+    assert!(pipeline.run(&mut log).await.is_err());
+
+    assert!(matches!(log.status(), LogStatus::Failed));
+    assert!(error_message(&log).starts_with("transform stage failed"));
+}
+
+/// A mock extractor that raises a flag when it is closed.
+#[derive(Debug)]
+struct ClosingExtractor {
+    inner: MockExtractor,
+    closed: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl Extractor for ClosingExtractor {
+    async fn ping(&self) -> ExtractorResult<()> {
+        self.inner.ping().await
+    }
+
+    async fn close(&mut self) -> ExtractorResult<()> {
+        self.closed.store(true, Ordering::SeqCst);
+        self.inner.close().await
+    }
+
+    async fn extract_json<T: DeserializeOwned>(&self, logger: &mut LogStore) -> ExtractorResult<T> {
+        self.inner.extract_json(logger).await
+    }
+
+    async fn extract_value(&self) -> ExtractorResult<Value> {
+        self.inner.extract_value().await
+    }
+
+    async fn extract_text(&self) -> ExtractorResult<String> {
+        self.inner.extract_text().await
+    }
+
+    async fn extract_bytes(&self) -> ExtractorResult<Vec<u8>> {
+        self.inner.extract_bytes().await
+    }
+
+    async fn extract_raw(&self) -> ExtractorResult<Bytes> {
+        self.inner.extract_raw().await
+    }
+
+    fn source_name(&self) -> ExtractorResult<&str> {
+        self.inner.source_name()
+    }
+
+    async fn metadata(&self) -> ExtractorResult<String> {
+        self.inner.metadata().await
+    }
+}
+
+fn closing(inner: MockExtractor) -> (ClosingExtractor, Arc<AtomicBool>) {
+    let closed = Arc::new(AtomicBool::new(false));
+    let extractor = ClosingExtractor {
+        inner,
+        closed: closed.clone(),
+    };
+    (extractor, closed)
+}
+
+#[tokio::test]
+async fn the_extractor_is_closed_after_a_run() {
+    let (extractor, closed) = closing(MockExtractor::new().with_json(json!([{"id": 1}])));
+    let mut pipeline = Pipeline::new(extractor, CapturingLoader::default());
+    let mut log = log();
+
+    pipeline.run(&mut log).await.unwrap();
+
+    assert!(closed.load(Ordering::SeqCst));
+    assert!(matches!(log.status(), LogStatus::Completed));
+}
+
+#[tokio::test]
+async fn the_extractor_is_closed_after_a_failed_run() {
+    let (extractor, closed) = closing(MockExtractor::new());
+    let mut pipeline = Pipeline::new(extractor, CapturingLoader::default());
+    let mut log = log();
+
+    assert!(pipeline.run(&mut log).await.is_err());
+
+    assert!(closed.load(Ordering::SeqCst));
+    assert!(matches!(log.status(), LogStatus::Failed));
+}
+
+#[tokio::test]
+async fn close_errors_are_recorded_in_the_log() {
+    // The first queued error is returned by the extraction, the second by close.
+    let (extractor, _) = closing(
+        MockExtractor::new()
+            .with_error(ExtractorError::ExtractOpsError("source down".into()))
+            .with_error(ExtractorError::ExtractOpsError("socket stuck".into())),
+    );
+    let mut pipeline = Pipeline::new(extractor, CapturingLoader::default());
+    let mut log = log();
+
+    assert!(pipeline.run(&mut log).await.is_err());
+
+    let json = serde_json::to_value(&log).unwrap();
+    assert!(json["metadata"]["close_error"].as_str().unwrap().contains("socket stuck"));
+    let message = error_message(&log);
+    assert!(message.starts_with("extract stage failed"));
+    assert!(message.contains("source down"));
+    assert!(message.contains("socket stuck"));
+}
+
+fn incremental_pipeline(server: &MockServer, loader: CapturingLoader) -> Pipeline {
+    let extractor = RestExtractor::new(&server.base_url(), "events")
+        .with_incremental("since", "updated_at");
+    Pipeline::new(extractor, loader).with_name("events")
+}
+
+#[tokio::test]
+async fn incremental_runs_resume_from_the_stored_checkpoint() {
+    let server = MockServer::start_async().await;
+    let resumed = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("since", "2024-01-02T00:00:00Z");
+            then.status(200)
+                .json_body(json!([{"id": 3, "updated_at": "2024-01-03T00:00:00Z"}]));
+        })
+        .await;
+    let first = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events");
+            then.status(200).json_body(json!([
+                {"id": 1, "updated_at": "2024-01-01T00:00:00Z"},
+                {"id": 2, "updated_at": "2024-01-02T00:00:00Z"},
+            ]));
+        })
+        .await;
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    let stored = || -> String {
+        conn.query_row(
+            "SELECT checkpoint FROM etl_checkpoints WHERE pipeline = 'events'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap()
+    };
+
+    // Each run uses a new pipeline, as after a process restart.
+    let mut log = log();
+    let rows = incremental_pipeline(&server, CapturingLoader::default())
+        .run_incremental(&conn, &mut log)
+        .await
+        .unwrap();
+    assert_eq!(rows, 2);
+    assert_eq!(stored(), "2024-01-02T00:00:00Z");
+
+    let mut log = log();
+    let rows = incremental_pipeline(&server, CapturingLoader::default())
+        .run_incremental(&conn, &mut log)
+        .await
+        .unwrap();
+    assert_eq!(rows, 1);
+    assert!(matches!(log.status(), LogStatus::Completed));
+    assert_eq!(stored(), "2024-01-03T00:00:00Z");
+
+    first.assert_hits_async(1).await;
+    resumed.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn incremental_runs_need_an_incremental_extractor() {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    let extractor = MockExtractor::new().with_json(json!([{"id": 1}]));
+    let mut pipeline = Pipeline::new(extractor, CapturingLoader::default());
+    let mut log = log();
+
+    assert!(pipeline.run_incremental(&conn, &mut log).await.is_err());
+
+    assert!(matches!(log.status(), LogStatus::Failed));
+    assert!(error_message(&log).starts_with("extract stage failed"));
+}
+
+#[tokio::test]
+async fn enveloped_records_are_loaded_and_checkpointed() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events");
+            then.status(200).json_body(json!({
+                "data": [
+                    {"id": 1, "updated_at": "2024-01-01T00:00:00Z"},
+                    {"id": 2, "updated_at": "2024-01-02T00:00:00Z"},
+                ],
+                "next": null,
+            }));
+        })
+        .await;
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    let loader = CapturingLoader::default();
+    let mut log = log();
+
+    let rows = incremental_pipeline(&server, loader.clone())
+        .run_incremental(&conn, &mut log)
+        .await
+        .unwrap();
+
+    assert_eq!(rows, 2);
+    let batches = loader.batches.lock().unwrap();
+    assert_eq!(batches[0].num_rows(), 2);
+    assert!(batches[0].schema().field_with_name("updated_at").is_ok());
+    assert!(batches[0].schema().field_with_name("data").is_err());
+    let stored: String = conn
+        .query_row(
+            "SELECT checkpoint FROM etl_checkpoints WHERE pipeline = 'events'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(stored, "2024-01-02T00:00:00Z");
+}
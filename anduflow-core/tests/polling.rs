@@ -0,0 +1,55 @@
+//! Polling a REST source on an interval with a test clock.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anduflow_core::clock::{Clock, TestClock};
+use anduflow_core::extract::polling::PollingExtractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use futures::StreamExt;
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[tokio::test]
+async fn each_interval_emits_one_incremental_extraction() {
+    let server = MockServer::start_async().await;
+    let resumed = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("since", "2024-01-02T00:00:00Z");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([{"id": 3, "updated_at": "2024-01-03T00:00:00Z"}]));
+        })
+        .await;
+    let first = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/events");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([
+                    {"id": 1, "updated_at": "2024-01-01T00:00:00Z"},
+                    {"id": 2, "updated_at": "2024-01-02T00:00:00Z"},
+                ]));
+        })
+        .await;
+    let clock = Arc::new(TestClock::new());
+    let start = clock.now();
+    let interval = Duration::from_secs(30);
+
+    let extractor = RestExtractor::new(&server.base_url(), "events")
+        .with_incremental("since", "updated_at");
+    let results: Vec<_> = extractor
+        .into_polling_stream_with_clock(interval, clock.clone())
+        .take(2)
+        .collect()
+        .await;
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].as_ref().unwrap().as_array().unwrap().len(), 2);
+    assert_eq!(results[1].as_ref().unwrap()[0]["id"], 3);
+    assert_eq!(clock.now() - start, 2 * interval);
+    first.assert_hits_async(1).await;
+    resumed.assert_hits_async(1).await;
+}
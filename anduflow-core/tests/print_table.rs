@@ -0,0 +1,48 @@
+//! Rendering of record batches as ASCII tables.
+
+use std::sync::Arc;
+
+use anduflow_core::arrow::print_table;
+use datafusion::arrow::array::{ArrayRef, Int64Array, StringArray};
+use datafusion::arrow::record_batch::RecordBatch;
+
+fn render(batch: &RecordBatch, max_rows: usize) -> String {
+    let mut out = Vec::new();
+    print_table(batch, max_rows, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn rows_past_the_limit_are_counted_not_shown() {
+    let batch = RecordBatch::try_from_iter([
+        ("id", Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef),
+        ("name", Arc::new(StringArray::from(vec![Some("ada"), None, Some("grace")])) as ArrayRef),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        render(&batch, 2),
+        "+----+------+\n\
+         | id | name |\n\
+         +----+------+\n\
+         | 1  | ada  |\n\
+         | 2  | null |\n\
+         +----+------+\n\
+         ... 1 more rows\n"
+    );
+}
+
+#[test]
+fn long_cells_are_truncated() {
+    let long = "x".repeat(50);
+    let batch = RecordBatch::try_from_iter([(
+        "note",
+        Arc::new(StringArray::from(vec![long.as_str()])) as ArrayRef,
+    )])
+    .unwrap();
+
+    let table = render(&batch, 10);
+
+    assert!(table.contains(&format!("| {}... |", "x".repeat(37))));
+    assert!(!table.contains(&long));
+}
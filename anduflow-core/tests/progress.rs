@@ -0,0 +1,61 @@
+//! Progress recorded by `RestExtractor::extract_json` against a mock server.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::logger::store::LogStore;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+async fn processed_items(extractor: RestExtractor) -> Value {
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+    extractor.extract_json::<Value>(&mut log).await.unwrap();
+    serde_json::to_value(&log).unwrap()["processed_items"].clone()
+}
+
+#[tokio::test]
+async fn records_in_a_data_envelope_are_counted() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .json_body(json!({"data": [{"id": 1}, {"id": 2}, {"id": 3}], "total": 3}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+
+    assert_eq!(processed_items(extractor).await, json!(3));
+}
+
+#[tokio::test]
+async fn records_at_the_records_path_are_counted() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .json_body(json!({"response": {"items": [{"id": 1}, {"id": 2}]}}));
+        })
+        .await;
+
+    let extractor =
+        RestExtractor::new(&server.base_url(), "users").with_records_path("/response/items");
+
+    assert_eq!(processed_items(extractor).await, json!(2));
+}
+
+#[tokio::test]
+async fn bodies_without_records_leave_progress_unset() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/status");
+            then.status(200).json_body(json!({"ok": true}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "status");
+
+    assert_eq!(processed_items(extractor).await, Value::Null);
+}
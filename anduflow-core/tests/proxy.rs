@@ -0,0 +1,44 @@
+//! Routing requests through a proxy and trusting extra root certificates.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+
+#[test]
+fn a_bad_proxy_url_fails_when_configured() {
+    let result = RestExtractor::new("https://api.example.com", "users")
+        .with_proxy("http://proxy.internal:not-a-port");
+
+    let err = result.err().expect("the proxy URL should be rejected");
+    assert!(err.to_string().contains("Invalid proxy URL 'http://proxy.internal:not-a-port'"));
+}
+
+#[test]
+fn a_malformed_certificate_fails_when_configured() {
+    let pem = b"-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----\n";
+
+    let result = RestExtractor::new("https://api.example.com", "users")
+        .with_root_certificate(pem);
+
+    let err = result.err().expect("the certificate should be rejected");
+    assert!(err.to_string().contains("Invalid root certificate"));
+}
+
+#[tokio::test]
+async fn requests_are_sent_through_the_proxy() {
+    let proxy = MockServer::start_async().await;
+    let mock = proxy
+        .mock_async(|when, then| {
+            when.method(GET).path("/users").header("host", "api.example.com");
+            then.status(200).body("via proxy");
+        })
+        .await;
+    let extractor = RestExtractor::new("http://api.example.com", "users")
+        .with_proxy(&proxy.base_url())
+        .unwrap();
+
+    let text = extractor.extract_text().await.unwrap();
+
+    assert_eq!(text, "via proxy");
+    mock.assert_async().await;
+}
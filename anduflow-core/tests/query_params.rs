@@ -0,0 +1,40 @@
+//! Validation of query parameter keys in strict mode.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+
+#[test]
+fn an_already_encoded_key_is_rejected() {
+    let err = RestExtractor::new("https://api.example.com", "data")
+        .with_query_param_strict(&[("page%5Bsize%5D", "10")])
+        .unwrap_err();
+
+    assert!(err.to_string().contains("page%5Bsize%5D"));
+}
+
+#[test]
+fn an_empty_key_is_rejected() {
+    let result = RestExtractor::new("https://api.example.com", "data")
+        .with_query_param_strict(&[("limit", "10"), ("", "x")]);
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn a_plain_key_is_sent_encoded_once() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/data").query_param("page[size]", "10");
+            then.status(200).body("ok");
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "data")
+        .with_query_param_strict(&[("page[size]", "10")])
+        .unwrap();
+
+    assert_eq!(extractor.extract_text().await.unwrap(), "ok");
+    mock.assert_async().await;
+}
@@ -0,0 +1,45 @@
+//! Reading response bodies through `extract_reader`.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+
+#[tokio::test]
+async fn the_reader_yields_the_whole_body() {
+    let server = MockServer::start_async().await;
+    let body: String = (0..10_000).map(|i| format!("{i},user {i}\n")).collect();
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/export.csv");
+            then.status(200).body(body.clone());
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "export.csv");
+    let mut reader = Box::pin(extractor.extract_reader().await.unwrap());
+    let mut read = String::new();
+    reader.read_to_string(&mut read).await.unwrap();
+
+    assert_eq!(read, body);
+}
+
+#[tokio::test]
+async fn the_reader_can_feed_a_line_parser() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/export.csv");
+            then.status(200).body("id,name\n1,ada\n2,grace\n");
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "export.csv");
+    let reader = Box::pin(extractor.extract_reader().await.unwrap());
+    let mut lines = BufReader::new(reader).lines();
+    let mut rows = Vec::new();
+    while let Some(line) = lines.next_line().await.unwrap() {
+        rows.push(line);
+    }
+
+    assert_eq!(rows, ["id,name", "1,ada", "2,grace"]);
+}
@@ -0,0 +1,96 @@
+//! Streaming a JSON array response into Arrow record batches.
+
+use std::sync::Arc;
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use datafusion::arrow::array::{Array, Int64Array};
+use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use futures::TryStreamExt;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+fn user_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+    ]))
+}
+
+#[tokio::test]
+async fn a_large_json_array_streams_into_batches_with_the_given_schema() {
+    let records: Vec<Value> = (1..=1000)
+        .map(|id| json!({"id": id, "name": format!("user {id}")}))
+        .collect();
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(Value::Array(records));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    let batches: Vec<_> = extractor
+        .extract_record_batches_with_schema(user_schema(), 256)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let rows: Vec<usize> = batches.iter().map(|batch| batch.num_rows()).collect();
+    assert_eq!(rows, [256, 256, 256, 232]);
+    assert!(batches.iter().all(|batch| batch.schema() == user_schema()));
+    let ids: Vec<i64> = batches
+        .iter()
+        .flat_map(|batch| {
+            let ids = batch.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+            ids.values().to_vec()
+        })
+        .collect();
+    assert_eq!(ids, (1..=1000).collect::<Vec<i64>>());
+}
+
+#[tokio::test]
+async fn a_json_array_streams_into_batches_of_the_requested_size() {
+    let records: Vec<Value> = (1..=5).map(|id| json!({"id": id, "name": format!("user {id}")})).collect();
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(Value::Array(records));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    let batches: Vec<_> = extractor.extract_record_batches(2).try_collect().await.unwrap();
+
+    let rows: Vec<usize> = batches.iter().map(|batch| batch.num_rows()).collect();
+    assert_eq!(rows, [2, 2, 1]);
+    assert!(batches.iter().all(|batch| batch.schema() == batches[0].schema()));
+    let ids: Vec<i64> = batches
+        .iter()
+        .flat_map(|batch| {
+            let ids = batch.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+            ids.values().to_vec()
+        })
+        .collect();
+    assert_eq!(ids, [1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn a_non_array_body_ends_the_batch_stream_with_an_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(json!({"users": []}));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    let result: Result<Vec<_>, _> = extractor
+        .extract_record_batches_with_schema(user_schema(), 2)
+        .try_collect()
+        .await;
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,80 @@
+//! Locating a nested record array with a JSON pointer.
+
+use anduflow_core::extract::rest_extractor::{RestExtractor, records_at};
+use httpmock::prelude::*;
+use serde_json::{Value, json};
+
+fn nested_body() -> Value {
+    json!({
+        "response": {
+            "items": {
+                "records": [{"id": 1}, {"id": 2}],
+                "count": 2
+            }
+        }
+    })
+}
+
+async fn serve_nested(server: &MockServer) {
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/search");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(nested_body());
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn a_deeply_nested_array_is_extracted() {
+    let server = MockServer::start_async().await;
+    serve_nested(&server).await;
+    let extractor = RestExtractor::new(&server.base_url(), "search");
+
+    let records = extractor.extract_records_at("/response/items/records").await.unwrap();
+
+    assert_eq!(records, [json!({"id": 1}), json!({"id": 2})]);
+}
+
+#[tokio::test]
+async fn a_configured_records_path_replaces_the_heuristics() {
+    let server = MockServer::start_async().await;
+    serve_nested(&server).await;
+    let extractor = RestExtractor::new(&server.base_url(), "search")
+        .with_records_path("/response/items/records");
+
+    let schema = extractor.sample_schema().await.unwrap();
+
+    assert_eq!(schema.fields().len(), 1);
+    assert_eq!(schema.field(0).name(), "id");
+}
+
+#[tokio::test]
+async fn a_missing_pointer_is_named_in_the_error() {
+    let server = MockServer::start_async().await;
+    serve_nested(&server).await;
+    let extractor = RestExtractor::new(&server.base_url(), "search");
+
+    let err = extractor.extract_records_at("/response/rows").await.unwrap_err();
+
+    assert!(err.to_string().contains("Records path '/response/rows' not found in response"));
+}
+
+#[test]
+fn a_pointer_to_a_non_array_is_an_error() {
+    let body = nested_body();
+
+    let object = records_at(&body, "/response/items").unwrap_err();
+    let number = records_at(&body, "/response/items/count").unwrap_err();
+
+    assert!(object.to_string().contains("'/response/items' is not an array but an object"));
+    assert!(number.to_string().contains("is not an array but a number"));
+}
+
+#[test]
+fn a_pointer_without_a_leading_slash_is_invalid() {
+    let err = records_at(&nested_body(), "response/items/records").unwrap_err();
+
+    assert!(err.to_string().contains("'response/items/records' not found"));
+}
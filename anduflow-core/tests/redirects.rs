@@ -0,0 +1,94 @@
+//! Redirect policies of `RestExtractor` against a mock server.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::{RedirectPolicy, RestExtractor};
+use anduflow_utils::error::ExtractorError;
+use httpmock::prelude::*;
+
+/// A server redirecting `/a` to `/b` to `/c`, which answers `done`.
+async fn redirecting_server() -> MockServer {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/a");
+            then.status(302).header("location", "/b");
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/b");
+            then.status(302).header("location", "/c");
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/c");
+            then.status(200).body("done");
+        })
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn redirects_are_followed_by_default() {
+    let server = redirecting_server().await;
+    let extractor = RestExtractor::new(&server.base_url(), "a");
+
+    let response = extractor.extract_response().await.unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.url, server.url("/c"));
+}
+
+#[tokio::test]
+async fn without_redirects_the_redirect_is_a_status_error() {
+    let server = redirecting_server().await;
+    let extractor =
+        RestExtractor::new(&server.base_url(), "a").with_redirect_policy(RedirectPolicy::None);
+
+    assert!(matches!(
+        extractor.extract_text().await,
+        Err(ExtractorError::HttpStatusError { status: 302, .. })
+    ));
+}
+
+#[tokio::test]
+async fn redirects_beyond_the_limit_fail() {
+    let server = redirecting_server().await;
+    let limited =
+        RestExtractor::new(&server.base_url(), "a").with_redirect_policy(RedirectPolicy::Limited(1));
+    let enough =
+        RestExtractor::new(&server.base_url(), "a").with_redirect_policy(RedirectPolicy::Limited(2));
+
+    assert!(matches!(
+        limited.extract_text().await,
+        Err(ExtractorError::ExtractOpsError(_))
+    ));
+    assert_eq!(enough.extract_text().await.unwrap(), "done");
+}
+
+#[tokio::test]
+async fn redirects_to_another_host_can_be_refused() {
+    let server = MockServer::start_async().await;
+    // `localhost` names the same server, but is another host than `127.0.0.1`.
+    let elsewhere = server.url("/c").replace("127.0.0.1", "localhost");
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/a");
+            then.status(302).header("location", elsewhere.as_str());
+        })
+        .await;
+    let c = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/c");
+            then.status(200).body("done");
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "a").with_same_host_redirects(true);
+    let err = extractor.extract_text().await.unwrap_err();
+
+    assert!(matches!(err, ExtractorError::ExtractOpsError(_)), "{err:?}");
+    assert!(err.to_string().contains("another host"));
+    c.assert_hits_async(0).await;
+}
@@ -0,0 +1,51 @@
+//! Recording the redacted request plan in the log's metadata.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::logger::store::LogStore;
+use httpmock::prelude::*;
+use serde_json::{Value, json};
+
+#[tokio::test]
+async fn a_logged_extraction_records_the_method_and_redacted_url() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users").query_param("api_key", "s3cret");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([]));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users")
+        .with_query_param(&[("api_key", "s3cret"), ("limit", "10")])
+        .with_header("Authorization", "Bearer t0ken");
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    let _: Value = extractor.extract_json(&mut log).await.unwrap();
+
+    let request = &log.metadata()["request"];
+    assert_eq!(request["method"], "GET");
+    assert_eq!(
+        request["url"],
+        format!("{}/users?api_key=REDACTED&limit=10", server.base_url())
+    );
+    assert_eq!(request["headers"]["authorization"], "REDACTED");
+    assert!(!log.metadata().to_string().contains("s3cret"));
+    assert!(!log.metadata().to_string().contains("t0ken"));
+}
+
+#[test]
+fn custom_sensitive_params_are_redacted() {
+    let extractor = RestExtractor::new("https://api.example.com", "users")
+        .with_query_param(&[("session", "abc123")])
+        .with_sensitive_param("session");
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    extractor.log_request_plan(&mut log).unwrap();
+
+    assert_eq!(
+        log.metadata()["request"]["url"],
+        "https://api.example.com/users?session=REDACTED"
+    );
+}
@@ -0,0 +1,42 @@
+//! Redacted request summaries for logging.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+
+#[test]
+fn the_summary_splits_the_url_and_redacts_sensitive_params() {
+    let summary = RestExtractor::new("https://api.example.com:8443", "v1/users")
+        .with_query_param(&[("limit", "10"), ("session", "abc123")])
+        .with_sensitive_param("session")
+        .request_summary()
+        .unwrap();
+
+    assert_eq!(summary.method, "GET");
+    assert_eq!(summary.host, "api.example.com");
+    assert_eq!(summary.path, "/v1/users");
+    assert_eq!(summary.query["limit"], "10");
+    assert_eq!(summary.query["session"], "REDACTED");
+    assert_eq!(summary.path_and_query(), "/v1/users?limit=10&session=REDACTED");
+}
+
+#[test]
+fn default_sensitive_params_are_redacted_case_insensitively() {
+    let summary = RestExtractor::new("https://api.example.com", "v1/users")
+        .with_method("POST")
+        .with_query_param(&[("API_KEY", "secret")])
+        .request_summary()
+        .unwrap();
+
+    assert_eq!(summary.method, "POST");
+    assert_eq!(summary.query["API_KEY"], "REDACTED");
+    assert!(!summary.path_and_query().contains("secret"));
+}
+
+#[test]
+fn a_request_without_a_query_has_a_bare_path() {
+    let summary = RestExtractor::new("https://api.example.com", "v1/users")
+        .request_summary()
+        .unwrap();
+
+    assert!(summary.query.is_empty());
+    assert_eq!(summary.path_and_query(), "/v1/users");
+}
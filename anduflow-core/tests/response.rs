@@ -0,0 +1,46 @@
+//! Status, headers and body together from `extract_response`.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use bytes::Bytes;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn custom_headers_are_captured_with_the_body() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .header("X-Total-Count", "42")
+                .header("Link", "<https://api.example.com/users?page=2>; rel=\"next\"")
+                .json_body(json!([{"id": 1}]));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+
+    let response = extractor.extract_response().await.unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.headers["x-total-count"], "42");
+    assert_eq!(response.header("X-Total-Count"), Some("42"));
+    assert!(response.header("link").unwrap().contains("rel=\"next\""));
+    assert_eq!(response.header("x-missing"), None);
+    assert_eq!(response.json::<Value>().unwrap(), json!([{"id": 1}]));
+    assert_eq!(response.text(), r#"[{"id":1}]"#);
+    assert_eq!(response.body, Bytes::from_static(br#"[{"id":1}]"#));
+}
+
+#[tokio::test]
+async fn an_error_status_is_still_an_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(404).header("x-request-id", "abc");
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+
+    assert!(extractor.extract_response().await.is_err());
+}
@@ -0,0 +1,87 @@
+//! The in-memory response cache of `RestExtractor` against a mock server.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anduflow_core::clock::TestClock;
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::logger::store::LogStore;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+async fn extract(extractor: &RestExtractor) -> Value {
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+    extractor.extract_json(&mut log).await.unwrap()
+}
+
+#[tokio::test]
+async fn advancing_the_clock_past_the_ttl_expires_the_entry() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(json!([{"id": 1}]));
+        })
+        .await;
+    let clock = Arc::new(TestClock::new());
+    let extractor = RestExtractor::new(&server.base_url(), "users")
+        .with_clock(clock.clone())
+        .with_cache(Some(Duration::from_secs(60)));
+
+    extract(&extractor).await;
+    clock.advance(Duration::from_secs(59));
+    extract(&extractor).await;
+    mock.assert_hits_async(1).await;
+
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(extract(&extractor).await, json!([{"id": 1}]));
+    mock.assert_hits_async(2).await;
+}
+
+/// A POST search extractor whose cache key includes the request body,
+/// recording every key it computes in `keys`.
+fn search(server: &MockServer, query: &str, keys: Arc<Mutex<Vec<String>>>) -> RestExtractor {
+    RestExtractor::new(&server.base_url(), "search")
+        .with_method("POST")
+        .with_json_body(&json!({"query": query}))
+        .with_cache_key(move |request| {
+            let body = request.body().and_then(|body| body.as_bytes()).unwrap_or_default();
+            let key = format!("{} {}", request.url(), String::from_utf8_lossy(body));
+            keys.lock().unwrap().push(key.clone());
+            key
+        })
+}
+
+#[tokio::test]
+async fn posts_with_different_bodies_are_cached_under_distinct_keys() {
+    let server = MockServer::start_async().await;
+    let rust = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search").json_body(json!({"query": "rust"}));
+            then.status(200).json_body(json!([{"hit": "rust"}]));
+        })
+        .await;
+    let go = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/search").json_body(json!({"query": "go"}));
+            then.status(200).json_body(json!([{"hit": "go"}]));
+        })
+        .await;
+    let keys = Arc::new(Mutex::new(Vec::new()));
+    let rust_search = search(&server, "rust", keys.clone());
+    let go_search = search(&server, "go", keys.clone());
+
+    assert_eq!(extract(&rust_search).await, json!([{"hit": "rust"}]));
+    assert_eq!(extract(&go_search).await, json!([{"hit": "go"}]));
+    assert_eq!(extract(&rust_search).await, json!([{"hit": "rust"}]));
+    assert_eq!(extract(&go_search).await, json!([{"hit": "go"}]));
+
+    rust.assert_hits_async(1).await;
+    go.assert_hits_async(1).await;
+    let keys = keys.lock().unwrap();
+    assert_eq!(keys.len(), 4);
+    assert_ne!(keys[0], keys[1]);
+    assert_eq!(keys[0], keys[2]);
+    assert_eq!(keys[1], keys[3]);
+}
@@ -0,0 +1,66 @@
+//! Recording raw responses to disk with `with_response_recorder`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::logger::store::LogStore;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+fn recorder_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("anduflow-recorder-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// The files in `dir` with the given extension, sorted by name.
+fn files(dir: &Path, extension: &str) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+        .collect();
+    files.sort();
+    files
+}
+
+#[tokio::test]
+async fn each_response_is_saved_with_a_redacted_sidecar() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .header("content-type", "application/json")
+                .header("set-cookie", "session=abc123")
+                .body(r#"[{"id":1}]"#);
+        })
+        .await;
+    let dir = recorder_dir();
+    let extractor = RestExtractor::new(&server.base_url(), "users")
+        .with_query_param(&[("api_key", "secret"), ("limit", "10")])
+        .with_response_recorder(dir.clone());
+
+    for _ in 0..2 {
+        let mut log = LogStore::new("users".to_string(), "extract".to_string());
+        let users: Value = extractor.extract_json(&mut log).await.unwrap();
+        assert_eq!(users, json!([{"id": 1}]));
+    }
+
+    let bodies = files(&dir, "body");
+    let sidecars = files(&dir, "json");
+    assert_eq!(bodies.len(), 2);
+    assert_eq!(sidecars.len(), 2);
+    assert_eq!(fs::read_to_string(&bodies[0]).unwrap(), r#"[{"id":1}]"#);
+
+    let sidecar: Value = serde_json::from_slice(&fs::read(&sidecars[1]).unwrap()).unwrap();
+    assert_eq!(sidecar["status"], 200);
+    assert_eq!(sidecar["headers"]["set-cookie"], "REDACTED");
+    assert_eq!(sidecar["headers"]["content-type"], "application/json");
+    let url = sidecar["url"].as_str().unwrap();
+    assert!(url.contains("api_key=REDACTED") && url.contains("limit=10"), "{url}");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,95 @@
+//! Inferring an Arrow schema, and its JSON Schema, from a sampled response.
+
+use anduflow_core::arrow::json_schema_from_schema;
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use datafusion::arrow::datatypes::DataType;
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[tokio::test]
+async fn sampling_a_mocked_array_returns_its_fields() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(json!([
+                {"id": 1, "name": "ada", "score": 9.5, "active": true},
+                {"id": 2, "name": "grace", "score": 8.0, "active": false}
+            ]));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    assert!(extractor.schema().is_none());
+
+    let schema = extractor.sample_schema().await.unwrap();
+
+    let fields: Vec<(&str, &DataType)> = schema
+        .fields()
+        .iter()
+        .map(|field| (field.name().as_str(), field.data_type()))
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            ("id", &DataType::Int64),
+            ("name", &DataType::Utf8),
+            ("score", &DataType::Float64),
+            ("active", &DataType::Boolean),
+        ]
+    );
+    assert_eq!(extractor.schema(), Some(schema));
+}
+
+#[tokio::test]
+async fn records_under_a_data_key_are_sampled() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(json!({"data": [{"id": 1}], "total": 1}));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+
+    let schema = extractor.sample_schema().await.unwrap();
+
+    assert_eq!(schema.fields().len(), 1);
+    assert_eq!(schema.field(0).name(), "id");
+}
+
+#[tokio::test]
+async fn a_body_without_records_cannot_be_sampled() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/status");
+            then.status(200).json_body(json!({"ok": true}));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "status");
+
+    let err = extractor.sample_schema().await.unwrap_err();
+
+    assert!(err.to_string().contains("does not contain a record array"));
+    assert!(extractor.schema().is_none());
+}
+
+#[tokio::test]
+async fn the_sampled_schema_exports_as_json_schema() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(json!([{"id": 1, "name": "ada"}]));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+
+    let schema = extractor.sample_schema().await.unwrap();
+    let document = json_schema_from_schema(&schema);
+
+    assert_eq!(document["type"], "object");
+    assert_eq!(document["properties"]["id"]["type"], json!(["integer", "null"]));
+    assert_eq!(document["properties"]["name"]["type"], json!(["string", "null"]));
+}
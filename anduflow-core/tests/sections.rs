@@ -0,0 +1,49 @@
+//! Splitting one response into named sections with `extract_sections`.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::json;
+
+async fn serve_config(server: &MockServer) -> httpmock::Mock<'_> {
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/config");
+            then.status(200).json_body(json!({
+                "users": [{"id": 1}, {"id": 2}],
+                "settings": {"theme": "dark", "retries": 3},
+                "version": 7
+            }));
+        })
+        .await
+}
+
+#[tokio::test]
+async fn sections_are_extracted_from_a_single_request() {
+    let server = MockServer::start_async().await;
+    let mock = serve_config(&server).await;
+    let extractor = RestExtractor::new(&server.base_url(), "config");
+
+    let sections = extractor
+        .extract_sections(&[("users", "/users"), ("settings", "/settings")])
+        .await
+        .unwrap();
+
+    mock.assert_hits_async(1).await;
+    assert_eq!(sections.len(), 2);
+    assert_eq!(sections["users"], json!([{"id": 1}, {"id": 2}]));
+    assert_eq!(sections["settings"], json!({"theme": "dark", "retries": 3}));
+}
+
+#[tokio::test]
+async fn a_missing_section_is_an_error() {
+    let server = MockServer::start_async().await;
+    serve_config(&server).await;
+    let extractor = RestExtractor::new(&server.base_url(), "config");
+
+    let err = extractor
+        .extract_sections(&[("users", "/users"), ("billing", "/billing")])
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("Section 'billing' not found at '/billing'"));
+}
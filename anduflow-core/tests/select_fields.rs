@@ -0,0 +1,60 @@
+//! Projecting JSON records onto top-level and nested field paths.
+
+use anduflow_core::transform::select_fields::{SelectFields, select_fields};
+use serde_json::json;
+
+#[test]
+fn top_level_and_nested_paths_are_selected() {
+    let records = vec![json!({
+        "id": 1,
+        "title": "Launch",
+        "author": {"name": "Ada", "email": "ada@example.com"},
+        "body": "a long article"
+    })];
+
+    let selected = select_fields(&records, &["id", "author.name"]);
+
+    assert_eq!(selected, vec![json!({"id": 1, "author": {"name": "Ada"}})]);
+}
+
+#[test]
+fn a_path_missing_from_some_records_is_null() {
+    let records = vec![
+        json!({"id": 1, "author": {"name": "Ada"}}),
+        json!({"id": 2}),
+        json!({"id": 3, "author": "anonymous"}),
+    ];
+
+    let selected = SelectFields::new(&["id", "author.name"]).select_all(&records);
+
+    assert_eq!(
+        selected,
+        vec![
+            json!({"id": 1, "author": {"name": "Ada"}}),
+            json!({"id": 2, "author": {"name": null}}),
+            json!({"id": 3, "author": {"name": null}}),
+        ]
+    );
+}
+
+#[test]
+fn jsonpath_and_dotted_paths_are_equivalent() {
+    let record = json!({"meta": {"source": "api", "page": 2}, "id": 7});
+
+    let dotted = SelectFields::new(&["meta.source"]).select(&record);
+    let jsonpath = SelectFields::new(&["$.meta.source"]).select(&record);
+    let bracketed = SelectFields::new(&["$['meta']['source']"]).select(&record);
+
+    assert_eq!(dotted, json!({"meta": {"source": "api"}}));
+    assert_eq!(jsonpath, dotted);
+    assert_eq!(bracketed, dotted);
+}
+
+#[test]
+fn bracketed_keys_may_contain_dots() {
+    let record = json!({"a.b": 1, "a": {"b": 2}});
+
+    let selected = SelectFields::new(&["$['a.b']"]).select(&record);
+
+    assert_eq!(selected, json!({"a.b": 1}));
+}
@@ -0,0 +1,61 @@
+//! Sharing one pooled `reqwest::Client` between extractors.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use reqwest::header::{HeaderMap, HeaderValue};
+
+/// A client whose requests can be told apart by a default header.
+fn tagged_client() -> reqwest::Client {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-client", HeaderValue::from_static("shared"));
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn extractors_from_a_shared_client_send_on_it() {
+    let server = MockServer::start_async().await;
+    let users = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users").header("x-client", "shared");
+            then.status(200).body("users");
+        })
+        .await;
+    let orders = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/orders").header("x-client", "shared");
+            then.status(200).body("orders");
+        })
+        .await;
+    let client = tagged_client();
+
+    let first = RestExtractor::from_shared(client.clone(), &server.base_url(), "users");
+    let second = RestExtractor::from_shared(client, &server.base_url(), "orders");
+
+    assert_eq!(first.extract_text().await.unwrap(), "users");
+    assert_eq!(second.extract_text().await.unwrap(), "orders");
+    users.assert_async().await;
+    orders.assert_async().await;
+}
+
+#[tokio::test]
+async fn with_client_replaces_the_extractors_own_client() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users").header("x-client", "shared");
+            then.status(200).body("users");
+        })
+        .await;
+    let mut extractor =
+        RestExtractor::new(&server.base_url(), "users").with_client(tagged_client());
+
+    extractor.extract_text().await.unwrap();
+    extractor.close().await.unwrap();
+    extractor.extract_text().await.unwrap();
+
+    mock.assert_hits_async(2).await;
+}
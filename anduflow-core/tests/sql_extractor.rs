@@ -0,0 +1,60 @@
+//! Running SQL over registered record batches with `SqlExtractor`.
+
+use std::sync::Arc;
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::sql_extractor::SqlExtractor;
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::LogStore;
+use datafusion::arrow::array::{AsArray, Int64Array, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Int64Type, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use serde_json::{Value, json};
+
+fn users() -> RecordBatch {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("country", DataType::Utf8, false),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+            Arc::new(StringArray::from(vec!["NZ", "US", "NZ"])),
+        ],
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn count_star_over_a_registered_batch() {
+    let extractor = SqlExtractor::new("SELECT count(*) AS n FROM users")
+        .with_batches("users", vec![users()]);
+
+    let result = extractor.extract_batches().await.unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].num_rows(), 1);
+    assert_eq!(result[0].column(0).as_primitive::<Int64Type>().value(0), 3);
+}
+
+#[tokio::test]
+async fn results_are_extracted_as_json_records() {
+    let extractor = SqlExtractor::new("SELECT id FROM users WHERE country = 'NZ' ORDER BY id")
+        .with_batches("users", vec![users()]);
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    let rows: Value = extractor.extract_json(&mut log).await.unwrap();
+
+    assert_eq!(rows, json!([{ "id": 1 }, { "id": 3 }]));
+}
+
+#[tokio::test]
+async fn an_unknown_table_is_a_datafusion_error() {
+    let extractor = SqlExtractor::new("SELECT count(*) FROM orders")
+        .with_batches("users", vec![users()]);
+
+    let err = extractor.extract_batches().await.unwrap_err();
+
+    assert!(matches!(err, ExtractorError::DataFusionError(_)));
+}
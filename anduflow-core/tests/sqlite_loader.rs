@@ -0,0 +1,96 @@
+//! Best-effort loading of record batches into SQLite.
+
+use std::sync::{Arc, Mutex};
+
+use anduflow_core::load::Loader;
+use anduflow_core::load::sqlite_loader::SqliteLoader;
+use datafusion::arrow::array::{Int64Array, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use rusqlite::Connection;
+
+fn users_table() -> Arc<Mutex<Connection>> {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)", [])
+        .unwrap();
+    Arc::new(Mutex::new(conn))
+}
+
+/// Ten users, of which those in `nameless` violate the `NOT NULL` constraint.
+fn users(nameless: &[i64]) -> RecordBatch {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+    ]);
+    let ids: Vec<i64> = (1..=10).collect();
+    let names: Vec<Option<String>> = ids
+        .iter()
+        .map(|id| (!nameless.contains(id)).then(|| format!("user {id}")))
+        .collect();
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(Int64Array::from(ids)),
+            Arc::new(StringArray::from(names)),
+        ],
+    )
+    .unwrap()
+}
+
+fn row_count(conn: &Mutex<Connection>) -> i64 {
+    conn.lock()
+        .unwrap()
+        .query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0))
+        .unwrap()
+}
+
+#[test]
+fn failed_rows_within_the_threshold_are_skipped() {
+    let conn = users_table();
+    let loader = SqliteLoader::new(conn.clone(), "users").with_max_error_fraction(0.2);
+
+    let report = loader.load_with_report(&users(&[3, 7])).unwrap();
+
+    assert_eq!(report.loaded, 8);
+    assert_eq!(report.failed, 2);
+    assert_eq!(report.errors.len(), 2);
+    assert!(report.errors[0].starts_with("row 2:"));
+    assert!(!report.aborted);
+    assert_eq!(row_count(&conn), 8);
+}
+
+#[test]
+fn the_load_aborts_past_the_threshold() {
+    let conn = users_table();
+    let loader = SqliteLoader::new(conn.clone(), "users").with_max_error_fraction(0.1);
+
+    let report = loader.load_with_report(&users(&[2, 4, 9])).unwrap();
+
+    assert_eq!(report.loaded, 2);
+    assert_eq!(report.failed, 2);
+    assert!(report.aborted);
+    assert_eq!(row_count(&conn), 2);
+}
+
+#[tokio::test]
+async fn an_aborted_load_fails_as_a_loader() {
+    let conn = users_table();
+    let loader = SqliteLoader::new(conn.clone(), "users");
+
+    let err = Loader::load(&loader, users(&[5])).await.unwrap_err();
+
+    assert!(err.to_string().contains("Aborted load into 'users'"));
+    assert_eq!(loader.last_report().unwrap().loaded, 4);
+    assert_eq!(row_count(&conn), 4);
+}
+
+#[tokio::test]
+async fn a_clean_load_succeeds_as_a_loader() {
+    let conn = users_table();
+    let loader = SqliteLoader::new(conn.clone(), "users");
+
+    Loader::load(&loader, users(&[])).await.unwrap();
+
+    assert_eq!(loader.last_report().unwrap().loaded, 10);
+    assert_eq!(row_count(&conn), 10);
+}
@@ -0,0 +1,78 @@
+//! Serving the last good result when a later extraction fails.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn a_server_error_returns_the_last_good_result_as_stale() {
+    let server = MockServer::start_async().await;
+    let up = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/rates");
+            then.status(200).json_body(json!({"usd": 1.0}));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "rates").with_stale_fallback(true);
+
+    let live = extractor.extract_json_or_stale::<Value>().await.unwrap();
+    assert!(!live.stale);
+
+    up.delete_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/rates");
+            then.status(500);
+        })
+        .await;
+    let stale = extractor.extract_json_or_stale::<Value>().await.unwrap();
+
+    assert!(stale.stale);
+    assert_eq!(stale.value, json!({"usd": 1.0}));
+}
+
+#[tokio::test]
+async fn without_the_fallback_a_server_error_fails() {
+    let server = MockServer::start_async().await;
+    let up = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/rates");
+            then.status(200).json_body(json!({"usd": 1.0}));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "rates");
+    extractor.extract_json_or_stale::<Value>().await.unwrap();
+
+    up.delete_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/rates");
+            then.status(503);
+        })
+        .await;
+
+    assert!(extractor.extract_json_or_stale::<Value>().await.is_err());
+}
+
+#[tokio::test]
+async fn a_client_error_is_not_covered_by_the_fallback() {
+    let server = MockServer::start_async().await;
+    let up = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/rates");
+            then.status(200).json_body(json!({"usd": 1.0}));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "rates").with_stale_fallback(true);
+    extractor.extract_json_or_stale::<Value>().await.unwrap();
+
+    up.delete_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/rates");
+            then.status(404);
+        })
+        .await;
+
+    assert!(extractor.extract_json_or_stale::<Value>().await.is_err());
+}
@@ -0,0 +1,97 @@
+//! Handling of non-success HTTP statuses by `RestExtractor` against a mock server.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::{ExtractorError, ExtractorResult};
+use anduflow_utils::logger::store::{LogStatus, LogStore};
+use httpmock::prelude::*;
+use serde_json::Value;
+
+async fn failing_server() -> MockServer {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(500).body("database unavailable");
+        })
+        .await;
+    server
+}
+
+fn assert_status_error<T: std::fmt::Debug>(result: ExtractorResult<T>) {
+    match result {
+        Err(ExtractorError::HttpStatusError {
+            status,
+            body_snippet,
+        }) => {
+            assert_eq!(status, 500);
+            assert_eq!(body_snippet, "database unavailable");
+        }
+        other => panic!("expected an HttpStatusError, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn every_extract_method_fails_on_a_server_error() {
+    let server = failing_server().await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+
+    assert_status_error(extractor.extract_value().await);
+    assert_status_error(extractor.extract_text().await);
+    assert_status_error(extractor.extract_bytes().await);
+    assert_status_error(extractor.extract_raw().await);
+}
+
+#[tokio::test]
+async fn a_failed_extract_json_is_logged_as_failed() {
+    let server = failing_server().await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    assert_status_error(extractor.extract_json::<Value>(&mut log).await);
+    assert!(matches!(log.status(), LogStatus::Failed));
+}
+
+#[tokio::test]
+async fn client_errors_keep_their_status() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/missing");
+            then.status(404).body("not found");
+        })
+        .await;
+
+    let missing = RestExtractor::new(&server.base_url(), "missing");
+
+    assert!(matches!(
+        missing.extract_text().await,
+        Err(ExtractorError::HttpStatusError { status: 404, .. })
+    ));
+}
+
+#[tokio::test]
+async fn server_errors_are_retryable_and_client_errors_are_not() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/missing");
+            then.status(404).body("not found");
+        })
+        .await;
+    let failing = failing_server().await;
+
+    let missing = RestExtractor::new(&server.base_url(), "missing");
+    let users = RestExtractor::new(&failing.base_url(), "users");
+
+    assert!(!missing.extract_text().await.unwrap_err().is_retryable());
+    assert!(users.extract_text().await.unwrap_err().is_retryable());
+}
+
+#[tokio::test]
+async fn any_status_is_accepted_when_asked() {
+    let server = failing_server().await;
+    let extractor = RestExtractor::new(&server.base_url(), "users").with_accept_any_status(true);
+
+    assert_eq!(extractor.extract_text().await.unwrap(), "database unavailable");
+}
@@ -0,0 +1,65 @@
+//! Streaming large response bodies with `extract_stream` and `extract_to_writer`.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use futures::TryStreamExt;
+use httpmock::prelude::*;
+
+/// A few megabytes of non-repeating bytes, so reordered chunks would be noticed.
+fn payload() -> Vec<u8> {
+    (0..3 * 1024 * 1024u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8).collect()
+}
+
+async fn serve_export(server: &MockServer, body: Vec<u8>) {
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/export.bin");
+            then.status(200)
+                .header("content-type", "application/octet-stream")
+                .body(body);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn streamed_chunks_reassemble_the_payload() {
+    let server = MockServer::start_async().await;
+    let body = payload();
+    serve_export(&server, body.clone()).await;
+    let extractor = RestExtractor::new(&server.base_url(), "export.bin");
+
+    let chunks: Vec<_> = extractor.extract_stream().await.unwrap().try_collect().await.unwrap();
+
+    assert!(chunks.len() > 1);
+    assert_eq!(chunks.concat(), body);
+}
+
+#[tokio::test]
+async fn a_download_is_written_to_a_file() {
+    let server = MockServer::start_async().await;
+    let body = payload();
+    serve_export(&server, body.clone()).await;
+    let extractor = RestExtractor::new(&server.base_url(), "export.bin");
+    let path = std::env::temp_dir().join(format!("anduflow-export-{}.bin", std::process::id()));
+
+    let file = tokio::fs::File::create(&path).await.unwrap();
+    let written = extractor.extract_to_writer(file).await.unwrap();
+
+    assert_eq!(written, body.len() as u64);
+    assert_eq!(tokio::fs::read(&path).await.unwrap(), body);
+    tokio::fs::remove_file(&path).await.unwrap();
+}
+
+#[tokio::test]
+async fn an_error_status_fails_before_streaming() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/export.bin");
+            then.status(404).body("no such export");
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "export.bin");
+
+    assert!(extractor.extract_stream().await.is_err());
+    assert!(extractor.extract_to_writer(Vec::new()).await.is_err());
+}
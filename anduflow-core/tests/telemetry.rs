@@ -0,0 +1,48 @@
+//! `tracing` spans around extractions, with the `tracing-spans` feature enabled.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::json;
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn a_successful_extraction_is_traced() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).json_body(json!([{"id": 1}]));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    extractor.extract_value().await.unwrap();
+
+    assert!(logs_contain("otel.name=RestExtractor.extract_value"));
+    assert!(logs_contain("method=extract_value"));
+    assert!(logs_contain(&format!("url={}", server.url("/users"))));
+    assert!(logs_contain("http.status_code=200"));
+    assert!(logs_contain("elapsed_ms="));
+    assert!(logs_contain("Extraction completed"));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn a_failed_extraction_is_traced_as_an_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(503).body("maintenance");
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    assert!(extractor.extract_text().await.is_err());
+
+    assert!(logs_contain("http.status_code=503"));
+    assert!(logs_contain("Extraction failed"));
+    assert!(logs_contain("maintenance"));
+}
@@ -0,0 +1,113 @@
+//! Per-tenant extraction against a mock server.
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::{RestExtractor, TenantCreds};
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn each_tenant_is_fetched_with_its_own_token() {
+    let server = MockServer::start_async().await;
+    let acme = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/invoices")
+                .header("authorization", "Bearer token-a");
+            then.status(200).json_body(json!(["acme invoice"]));
+        })
+        .await;
+    let globex = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/invoices")
+                .header("authorization", "Bearer token-g");
+            then.status(200).json_body(json!(["globex invoice"]));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "invoices")
+        .with_header("Authorization", "Bearer own-token");
+    let tenants = [TenantCreds::new("acme", "token-a"), TenantCreds::new("globex", "token-g")];
+    let results = extractor.extract_for_tenants::<Value>(&tenants, 2).await;
+
+    assert_eq!(results[0].0, "acme");
+    assert_eq!(results[0].1.as_ref().unwrap(), &json!(["acme invoice"]));
+    assert_eq!(results[1].0, "globex");
+    assert_eq!(results[1].1.as_ref().unwrap(), &json!(["globex invoice"]));
+    acme.assert_async().await;
+    globex.assert_async().await;
+}
+
+#[tokio::test]
+async fn a_tenant_base_url_prefixes_the_request_path() {
+    let server = MockServer::start_async().await;
+    let eu = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/eu/invoices")
+                .header("authorization", "Bearer token-e");
+            then.status(200).json_body(json!(["eu invoice"]));
+        })
+        .await;
+
+    let extractor = RestExtractor::new("https://api.example.invalid", "invoices");
+    let tenants = [TenantCreds::new("initech", "token-e").with_base_url(&server.url("/eu"))];
+    let results = extractor.extract_for_tenants::<Value>(&tenants, 1).await;
+
+    assert_eq!(results[0].1.as_ref().unwrap(), &json!(["eu invoice"]));
+    eu.assert_async().await;
+}
+
+#[tokio::test]
+async fn tenant_tokens_are_not_replaced_by_oauth2() {
+    let server = MockServer::start_async().await;
+    let token = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/oauth/token");
+            then.status(200)
+                .json_body(json!({"access_token": "oauth-token", "expires_in": 3600}));
+        })
+        .await;
+    let acme = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/invoices")
+                .header("authorization", "Bearer token-a");
+            then.status(200).json_body(json!(["acme invoice"]));
+        })
+        .await;
+    let globex = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/invoices")
+                .header("authorization", "Bearer token-g");
+            then.status(200).json_body(json!(["globex invoice"]));
+        })
+        .await;
+    let own = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/invoices")
+                .header("authorization", "Bearer oauth-token");
+            then.status(200).json_body(json!(["own invoice"]));
+        })
+        .await;
+
+    let extractor = RestExtractor::new(&server.base_url(), "invoices")
+        .with_oauth2_client_credentials(&server.url("/oauth/token"), "client", "secret", &[]);
+    let tenants = [TenantCreds::new("acme", "token-a"), TenantCreds::new("globex", "token-g")];
+    let results = extractor.extract_for_tenants::<Value>(&tenants, 2).await;
+
+    assert_eq!(results[0].0, "acme");
+    assert_eq!(results[0].1.as_ref().unwrap(), &json!(["acme invoice"]));
+    assert_eq!(results[1].0, "globex");
+    assert_eq!(results[1].1.as_ref().unwrap(), &json!(["globex invoice"]));
+    acme.assert_async().await;
+    globex.assert_async().await;
+    token.assert_hits_async(0).await;
+
+    // Requests of the extractor itself still use the OAuth2 token.
+    assert_eq!(extractor.extract_value().await.unwrap(), json!(["own invoice"]));
+    own.assert_async().await;
+    token.assert_async().await;
+}
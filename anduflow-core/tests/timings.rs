@@ -0,0 +1,45 @@
+//! Per-phase timings of `extract_json_timed`.
+
+use std::time::Duration;
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use httpmock::prelude::*;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn parse_time_is_recorded_apart_from_transfer_time() {
+    let server = MockServer::start_async().await;
+    let records: Vec<Value> = (0..20_000).map(|id| json!({"id": id, "name": "user"})).collect();
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .json_body(Value::Array(records.clone()))
+                .delay(Duration::from_millis(100));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+
+    let (users, timings) = extractor.extract_json_timed::<Vec<Value>>().await.unwrap();
+
+    assert_eq!(users.len(), 20_000);
+    assert!(timings.time_to_first_byte >= Duration::from_millis(100));
+    assert!(timings.parse > Duration::ZERO);
+    assert!(timings.total > Duration::ZERO);
+    assert_eq!(timings.transfer(), timings.time_to_first_byte + timings.download);
+    assert!(timings.total >= timings.transfer() + timings.parse);
+}
+
+#[tokio::test]
+async fn a_failed_request_has_no_timings() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(503);
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+
+    assert!(extractor.extract_json_timed::<Value>().await.is_err());
+}
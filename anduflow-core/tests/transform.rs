@@ -0,0 +1,79 @@
+//! The `Transform` trait, chaining and column projection with `SelectColumns`.
+
+use std::sync::Arc;
+
+use anduflow_core::transform::Transform;
+use anduflow_core::transform::select_columns::SelectColumns;
+use anduflow_utils::error::ExtractorResult;
+use async_trait::async_trait;
+use datafusion::arrow::array::{ArrayRef, Int64Array, StringArray};
+use datafusion::arrow::record_batch::RecordBatch;
+
+fn users() -> RecordBatch {
+    RecordBatch::try_from_iter([
+        ("id", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+        ("name", Arc::new(StringArray::from(vec!["ada", "grace"])) as ArrayRef),
+        ("email", Arc::new(StringArray::from(vec!["a@x.com", "g@x.com"])) as ArrayRef),
+        ("internal", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+    ])
+    .unwrap()
+}
+
+fn column_names(batch: &RecordBatch) -> Vec<String> {
+    batch.schema().fields().iter().map(|field| field.name().clone()).collect()
+}
+
+/// Keeps the first `n` rows.
+struct Head(usize);
+
+#[async_trait]
+impl Transform for Head {
+    async fn transform(&self, input: RecordBatch) -> ExtractorResult<RecordBatch> {
+        Ok(input.slice(0, self.0.min(input.num_rows())))
+    }
+}
+
+#[tokio::test]
+async fn only_the_requested_columns_are_kept_in_order() {
+    let output = SelectColumns::new(&["email", "id"]).transform(users()).await.unwrap();
+
+    assert_eq!(column_names(&output), ["email", "id"]);
+    assert_eq!(output.num_rows(), 2);
+    assert_eq!(output.column(1), users().column(0));
+}
+
+#[tokio::test]
+async fn a_missing_column_is_an_error() {
+    let err = SelectColumns::new(&["id", "phone"]).transform(users()).await.unwrap_err();
+
+    assert!(err.to_string().contains("Column 'phone' not found"));
+}
+
+#[tokio::test]
+async fn chained_transforms_run_in_order() {
+    let pipeline = SelectColumns::new(&["name", "id", "email"])
+        .then(Head(1))
+        .then(SelectColumns::new(&["id", "name"]));
+
+    let output = pipeline.transform(users()).await.unwrap();
+
+    assert_eq!(column_names(&output), ["id", "name"]);
+    assert_eq!(output.num_rows(), 1);
+
+    let failing = SelectColumns::new(&["id"]).then(SelectColumns::new(&["name"]));
+    assert!(failing.transform(users()).await.is_err());
+}
+
+#[tokio::test]
+async fn boxed_transforms_can_be_mixed() {
+    let transforms: Vec<Box<dyn Transform>> =
+        vec![Box::new(SelectColumns::new(&["id"])), Box::new(Head(1))];
+
+    let mut batch = users();
+    for transform in &transforms {
+        batch = transform.transform(batch).await.unwrap();
+    }
+
+    assert_eq!(column_names(&batch), ["id"]);
+    assert_eq!(batch.num_rows(), 1);
+}
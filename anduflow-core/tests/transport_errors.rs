@@ -0,0 +1,91 @@
+//! Classification of transport and parse failures into distinct error variants.
+
+use std::net::TcpListener;
+use std::time::Duration;
+
+use anduflow_core::extract::Extractor;
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::LogStore;
+use httpmock::prelude::*;
+use serde_json::Value;
+
+#[tokio::test]
+async fn an_unresolvable_host_is_a_connect_error() {
+    let extractor = RestExtractor::new("http://anduflow-test.invalid", "users");
+
+    let err = extractor.extract_text().await.unwrap_err();
+
+    assert!(matches!(err, ExtractorError::ConnectError(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn a_refused_connection_is_a_connect_error() {
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let extractor = RestExtractor::new(&format!("http://127.0.0.1:{port}"), "users");
+
+    let err = extractor.extract_text().await.unwrap_err();
+
+    assert!(matches!(err, ExtractorError::ConnectError(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn a_delayed_response_past_the_timeout_is_a_timeout_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).delay(Duration::from_secs(5));
+        })
+        .await;
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    let err = client.get(server.url("/users")).send().await.unwrap_err();
+
+    assert!(matches!(ExtractorError::from(err), ExtractorError::TimeoutError(_)));
+}
+
+#[tokio::test]
+async fn a_non_json_body_is_a_json_parse_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(203).body("<html>maintenance</html>");
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    let err = extractor.extract_json::<Value>(&mut log).await.unwrap_err();
+
+    let ExtractorError::JsonParseError { source, snippet, status } = err else {
+        panic!("expected a JSON parse error, got {err:?}");
+    };
+    assert_eq!(status, 203);
+    assert_eq!(snippet, "<html>maintenance</html>");
+    assert!(source.is_syntax());
+}
+
+#[tokio::test]
+async fn the_parse_error_snippet_is_truncated() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200).body("x".repeat(5000));
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "users");
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+
+    let err = extractor.extract_json::<Value>(&mut log).await.unwrap_err();
+
+    let ExtractorError::JsonParseError { snippet, .. } = err else {
+        panic!("expected a JSON parse error, got {err:?}");
+    };
+    assert_eq!(snippet.len(), 1024);
+}
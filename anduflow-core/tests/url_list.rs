@@ -0,0 +1,64 @@
+//! Extraction from a file of URLs against a mock server.
+
+use std::time::Duration;
+
+use anduflow_core::extract::rest_extractor::extract_from_url_list;
+use anduflow_utils::error::ExtractorError;
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[tokio::test]
+async fn url_list_results_follow_file_order_and_keep_failures() {
+    let server = MockServer::start_async().await;
+    let slow = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/slow").header("x-team", "data");
+            then.status(200)
+                .delay(Duration::from_millis(200))
+                .json_body(json!({"url": "slow"}));
+        })
+        .await;
+    let fast = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/fast").header("x-team", "data");
+            then.status(200).json_body(json!({"url": "fast"}));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/missing");
+            then.status(404);
+        })
+        .await;
+
+    let path = std::env::temp_dir().join(format!("anduflow-url-list-{}.txt", std::process::id()));
+    let list = format!(
+        "{}\n\n{}\n{}\n",
+        server.url("/slow"),
+        server.url("/missing"),
+        server.url("/fast"),
+    );
+    tokio::fs::write(&path, list).await.unwrap();
+
+    let results = extract_from_url_list(&path, 2, |extractor| extractor.with_header("x-team", "data"))
+        .await
+        .unwrap();
+    tokio::fs::remove_file(&path).await.unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap(), &json!({"url": "slow"}));
+    assert!(matches!(
+        results[1],
+        Err(ExtractorError::HttpStatusError { status: 404, .. })
+    ));
+    assert_eq!(results[2].as_ref().unwrap(), &json!({"url": "fast"}));
+    slow.assert_async().await;
+    fast.assert_async().await;
+}
+
+#[tokio::test]
+async fn a_missing_url_list_is_an_error() {
+    let path = std::env::temp_dir().join("anduflow-url-list-does-not-exist.txt");
+
+    assert!(extract_from_url_list(&path, 2, |extractor| extractor).await.is_err());
+}
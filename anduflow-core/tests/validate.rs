@@ -0,0 +1,77 @@
+//! Row validation with `ValidateTransformer`.
+
+use std::sync::Arc;
+
+use anduflow_core::transform::validate_transformer::{
+    ColumnRule, ValidateTransformer, ValidationMode,
+};
+use datafusion::arrow::array::{ArrayRef, AsArray, Float64Array, Int64Array, StringArray};
+use datafusion::arrow::datatypes::Int64Type;
+use datafusion::arrow::record_batch::RecordBatch;
+
+fn users() -> RecordBatch {
+    RecordBatch::try_from_iter([
+        ("id", Arc::new(Int64Array::from(vec![1, 2, 3, 4])) as ArrayRef),
+        (
+            "email",
+            Arc::new(StringArray::from(vec![
+                Some("ada@example.com"),
+                None,
+                Some("not an email"),
+                Some("grace@example.com"),
+            ])) as ArrayRef,
+        ),
+        ("age", Arc::new(Float64Array::from(vec![36.0, 41.0, 29.0, -1.0])) as ArrayRef),
+    ])
+    .unwrap()
+}
+
+fn ids(batch: &RecordBatch) -> Vec<i64> {
+    batch.column_by_name("id").unwrap().as_primitive::<Int64Type>().values().to_vec()
+}
+
+#[test]
+fn failing_rows_are_moved_to_the_rejects_batch() {
+    let validate = ValidateTransformer::new(vec![
+        ColumnRule::not_null("email"),
+        ColumnRule::matches("email", r"^[^@\s]+@[^@\s]+$").unwrap(),
+    ]);
+
+    let validated = validate.validate(&users()).unwrap();
+
+    assert_eq!(ids(&validated.valid), [1, 4]);
+    assert_eq!(ids(&validated.rejects), [2, 3]);
+    assert_eq!(validated.rejects.schema(), users().schema());
+}
+
+#[test]
+fn range_and_membership_rules_are_combined() {
+    let validate = ValidateTransformer::new(vec![
+        ColumnRule::min("age", 0.0),
+        ColumnRule::max("age", 40.0),
+        ColumnRule::one_of("id", &["1", "2", "3"]),
+    ]);
+
+    let validated = validate.validate(&users()).unwrap();
+
+    assert_eq!(ids(&validated.valid), [1, 3]);
+    assert_eq!(ids(&validated.rejects), [2, 4]);
+}
+
+#[test]
+fn error_mode_fails_on_the_first_bad_row() {
+    let validate = ValidateTransformer::new(vec![ColumnRule::not_null("email")])
+        .with_mode(ValidationMode::Error);
+
+    let err = validate.validate(&users()).unwrap_err();
+
+    assert!(err.to_string().contains("Row 1 failed NotNull on column 'email'"));
+}
+
+#[test]
+fn unsuitable_columns_are_rejected() {
+    let validate = ValidateTransformer::new(vec![ColumnRule::min("email", 0.0)]);
+
+    assert!(validate.validate(&users()).is_err());
+    assert!(ColumnRule::matches("email", "(").is_err());
+}
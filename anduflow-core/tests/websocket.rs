@@ -0,0 +1,33 @@
+//! Extraction from a local WebSocket echo server.
+
+use bytes::Bytes;
+use futures::{SinkExt, StreamExt, TryStreamExt};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+use anduflow_core::extract::websocket_extractor::WebSocketExtractor;
+
+/// Start a server that pings, echoes the first message, then closes.
+async fn echo_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+        if let Some(Ok(msg)) = ws.next().await {
+            ws.send(Message::Ping(Bytes::new())).await.unwrap();
+            ws.send(msg).await.unwrap();
+            ws.close(None).await.unwrap();
+        }
+    });
+    format!("ws://{addr}")
+}
+
+#[tokio::test]
+async fn frames_are_streamed_until_the_server_closes() {
+    let url = echo_server().await;
+
+    let extractor = WebSocketExtractor::new(&url).with_subscribe_message(r#"{"subscribe":"trades"}"#);
+    let frames: Vec<Bytes> = extractor.extract_stream().await.unwrap().try_collect().await.unwrap();
+
+    assert_eq!(frames, vec![Bytes::from(r#"{"subscribe":"trades"}"#)]);
+}
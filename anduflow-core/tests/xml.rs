@@ -0,0 +1,68 @@
+//! Extracting XML responses into structs.
+
+use anduflow_core::extract::rest_extractor::RestExtractor;
+use anduflow_utils::error::ExtractorError;
+use httpmock::prelude::*;
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Orders {
+    #[serde(rename = "order", default)]
+    orders: Vec<Order>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Order {
+    #[serde(rename = "@id")]
+    id: u32,
+    customer: String,
+    total: f64,
+}
+
+#[tokio::test]
+async fn an_xml_document_is_deserialized_into_a_struct() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/orders").header("accept", "application/xml");
+            then.status(200).header("content-type", "application/xml").body(
+                r#"<?xml version="1.0"?>
+                <orders>
+                    <order id="1"><customer>ada</customer><total>12.5</total></order>
+                    <order id="2"><customer>grace</customer><total>3</total></order>
+                </orders>"#,
+            );
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "orders").with_xml();
+
+    let orders: Orders = extractor.extract_xml().await.unwrap();
+
+    assert_eq!(
+        orders.orders,
+        [
+            Order { id: 1, customer: "ada".to_string(), total: 12.5 },
+            Order { id: 2, customer: "grace".to_string(), total: 3.0 },
+        ]
+    );
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn malformed_xml_is_an_xml_parse_error() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/orders");
+            then.status(200).body("<orders><order id=\"1\">");
+        })
+        .await;
+    let extractor = RestExtractor::new(&server.base_url(), "orders");
+
+    let err = extractor.extract_xml::<Orders>().await.unwrap_err();
+
+    let ExtractorError::XmlParseError { snippet, .. } = err else {
+        panic!("expected an XML parse error, got {err:?}");
+    };
+    assert_eq!(snippet, "<orders><order id=\"1\">");
+}
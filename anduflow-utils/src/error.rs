@@ -6,10 +6,12 @@
 
 use datafusion::arrow::error::ArrowError;
 use datafusion::error::DataFusionError;
+use datafusion::parquet::errors::ParquetError;
 use object_store::Error as ObjStoreError;
 use reqwest::Error as ReqwestError;
 use serde_json::Error as SerdeError;
-use std::io::Error as IoError;
+use std::error::Error as StdError;
+use std::io::{Error as IoError, ErrorKind};
 use rusqlite::Error as RusqliteError;
 use thiserror::Error;
 
@@ -26,10 +28,53 @@ pub enum ExtractorError {
     /// An HTTP request failed.
     ///
     /// This variant wraps a `reqwest::Error` and is used when an HTTP request
-    /// fails for any reason, such as network issues, timeouts, or HTTP error
-    /// status codes.
+    /// fails for a reason not covered by [`ConnectError`](Self::ConnectError),
+    /// [`TimeoutError`](Self::TimeoutError) or [`BodyError`](Self::BodyError),
+    /// such as an invalid URL or a redirect loop.
     #[error("HTTP request failed: {0}")]
-    HttpRequestError(#[from] ReqwestError),
+    HttpRequestError(#[source] ReqwestError),
+
+    /// Could not connect to the remote host.
+    ///
+    /// This variant is used when DNS resolution, the TCP connection or the
+    /// TLS handshake fails, i.e. the host is unreachable.
+    #[error("Connection failed: {0}")]
+    ConnectError(#[source] ReqwestError),
+
+    /// An HTTP request timed out.
+    ///
+    /// This variant is used when a connect or request timeout elapses before
+    /// the server responds.
+    #[error("Request timed out: {0}")]
+    TimeoutError(#[source] ReqwestError),
+
+    /// The response body could not be read or decoded.
+    ///
+    /// This variant is used when the connection drops while streaming the
+    /// body or the body cannot be decoded.
+    #[error("Failed to read response body: {0}")]
+    BodyError(#[source] ReqwestError),
+
+    /// The server responded with a non-success HTTP status.
+    ///
+    /// This error occurs when a response arrives but its status is not 2xx,
+    /// e.g. a 404 or 500 error page. It lets callers tell a reachable but
+    /// erroring server apart from a transport failure.
+    #[error("HTTP status {status}: {body_snippet}")]
+    HttpStatusError {
+        /// The response status code.
+        status: u16,
+        /// The start of the response body, to help diagnose the failure.
+        body_snippet: String,
+    },
+
+    /// Authentication failed.
+    ///
+    /// This error occurs when credentials cannot be obtained, e.g. when an
+    /// OAuth2 token endpoint rejects the client or is unreachable. It is kept
+    /// separate from failures of the data request itself.
+    #[error("Authentication failed: {0}")]
+    AuthError(String),
 
     /// Could not clone request for execution.
     ///
@@ -45,6 +90,64 @@ pub enum ExtractorError {
     #[error("Serialization error: {0}")]
     SerializationError(#[from] SerdeError),
 
+    /// A response body could not be parsed as JSON.
+    ///
+    /// This error occurs when an extractor receives a body that is not valid
+    /// JSON, or does not match the requested type. Unlike
+    /// [`SerializationError`](Self::SerializationError), it carries the
+    /// response status and the start of the body, so pipelines can tell a bad
+    /// payload (to quarantine) apart from a failed request (to retry).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_utils::error::ExtractorError;
+    ///
+    /// let source = serde_json::from_str::<serde_json::Value>("<html>").unwrap_err();
+    /// let err = ExtractorError::JsonParseError {
+    ///     source,
+    ///     snippet: "<html>".into(),
+    ///     status: 200,
+    /// };
+    /// assert!(matches!(err, ExtractorError::JsonParseError { status: 200, .. }));
+    /// assert!(std::error::Error::source(&err).is_some());
+    /// assert!(err.to_string().contains("<html>"));
+    /// ```
+    #[error("Failed to parse JSON (status {status}): {source}. Response snippet: {snippet}")]
+    JsonParseError {
+        /// The underlying parse error.
+        source: SerdeError,
+        /// The start of the response body, at most 1024 characters.
+        snippet: String,
+        /// The response status code.
+        status: u16,
+    },
+
+    /// A response body could not be parsed as XML.
+    ///
+    /// This error occurs when an extractor receives a body that is not
+    /// well-formed XML, or does not match the requested type. It carries the
+    /// start of the body to help diagnose the failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_utils::error::ExtractorError;
+    ///
+    /// let err = ExtractorError::XmlParseError {
+    ///     message: "missing field `id`".into(),
+    ///     snippet: "<user/>".into(),
+    /// };
+    /// assert!(err.to_string().contains("<user/>"));
+    /// ```
+    #[error("Failed to parse XML: {message}. Response snippet: {snippet}")]
+    XmlParseError {
+        /// A description of the parse error.
+        message: String,
+        /// The start of the response body, at most 1024 characters.
+        snippet: String,
+    },
+
     /// Extract operation error.
     ///
     /// This is a generic error for extract operations that don't fit into
@@ -52,6 +155,61 @@ pub enum ExtractorError {
     #[error("Extract Operation Error: {0}")]
     ExtractOpsError(String),
 
+    /// Record count mismatch.
+    ///
+    /// This error occurs when the number of extracted records does not match
+    /// the total reported by the source, which usually indicates a truncated
+    /// or partially-served response.
+    #[error("Record count mismatch: source reported {expected}, extracted {actual}")]
+    CountMismatch {
+        /// The total reported by the source.
+        expected: usize,
+        /// The number of records actually extracted.
+        actual: usize,
+    },
+
+    /// Byte budget exceeded.
+    ///
+    /// This error occurs when a run's shared byte budget is exhausted, or a
+    /// response is larger than what remains of it. Further extractions in the
+    /// run are refused.
+    #[error("Byte budget of {limit} bytes exceeded (requested {requested} bytes)")]
+    BudgetExceeded {
+        /// The total number of bytes the budget allows.
+        limit: u64,
+        /// The size of the refused charge, or 0 if the budget was already exhausted.
+        requested: u64,
+    },
+
+    /// A paginated extraction led back to a page it already fetched.
+    ///
+    /// This error occurs when a `next` cursor or link points to a page that
+    /// was already visited in the same extraction, e.g. `A -> B -> A`, which
+    /// would otherwise loop forever. The string names the repeated page.
+    #[error("Pagination loop: {0} was already visited")]
+    PaginationLoop(String),
+
+    /// No records to infer a schema from.
+    ///
+    /// This error occurs when JSON records are converted to Arrow with an
+    /// inferred schema but there are none, e.g. an incremental pull with no
+    /// new rows. Supply a schema (for instance one inferred by an earlier run)
+    /// to get an empty batch instead.
+    #[error("Cannot infer a schema from zero records; supply a schema to convert an empty result")]
+    EmptyRecords,
+
+    /// Table schema mismatch.
+    ///
+    /// This error occurs when an existing SQLite table's columns differ from
+    /// the schema the code expects, e.g. a table left behind by an older version.
+    #[error("Table '{table}' does not match the expected schema: {}", discrepancies.join("; "))]
+    SchemaMismatch {
+        /// The table that was checked.
+        table: String,
+        /// One human-readable entry per differing column.
+        discrepancies: Vec<String>,
+    },
+
     /// DataFusion error.
     ///
     /// This variant wraps a `datafusion::error::DataFusionError` and is used
@@ -80,9 +238,139 @@ pub enum ExtractorError {
     #[error("Arrow error: {0}")]
     ArrowError(#[from] ArrowError),
 
+    /// Parquet error.
+    ///
+    /// This variant wraps a `parquet::errors::ParquetError` and is used when
+    /// reading or writing Parquet files fails.
+    #[error("Parquet error: {0}")]
+    ParquetError(#[from] ParquetError),
+
     /// SQLite error.
     /// 
     /// This variant wraps a `rusqlite::Error` and is used when SQLite operations fail.
     #[error ("SQLite error: {0}")]
     SqliteError(#[from] RusqliteError),
 }
+
+impl ExtractorError {
+    /// Whether the failure is transient, so the operation may succeed if retried.
+    ///
+    /// Timeouts, connection failures, connections reset or dropped mid-request
+    /// and `429` or `5xx` statuses are retryable. Everything else, such as
+    /// serialization and parse errors, authentication failures, other
+    /// statuses and invalid requests, is not: retrying would fail the same way.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if retrying may succeed
+    /// - `false` if the error is permanent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use anduflow_utils::error::ExtractorError;
+    ///
+    /// let status = |status| ExtractorError::HttpStatusError { status, body_snippet: String::new() };
+    /// assert!(status(429).is_retryable());
+    /// assert!(status(500).is_retryable());
+    /// assert!(status(503).is_retryable());
+    /// assert!(!status(400).is_retryable());
+    /// assert!(!status(404).is_retryable());
+    ///
+    /// let reset = io::Error::from(io::ErrorKind::ConnectionReset);
+    /// assert!(ExtractorError::StandardError(reset).is_retryable());
+    /// let missing = io::Error::from(io::ErrorKind::NotFound);
+    /// assert!(!ExtractorError::StandardError(missing).is_retryable());
+    ///
+    /// let parse = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+    /// assert!(!ExtractorError::SerializationError(parse).is_retryable());
+    /// let source = serde_json::from_str::<serde_json::Value>("<html>").unwrap_err();
+    /// let parse = ExtractorError::JsonParseError { source, snippet: "<html>".into(), status: 200 };
+    /// assert!(!parse.is_retryable());
+    /// assert!(!ExtractorError::AuthError("invalid_client".into()).is_retryable());
+    ///
+    /// let invalid_url = reqwest::Client::new().get("not a url").build().unwrap_err();
+    /// assert!(!ExtractorError::from(invalid_url).is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ExtractorError::ConnectError(_) | ExtractorError::TimeoutError(_) => true,
+            ExtractorError::BodyError(err) => !err.is_decode(),
+            ExtractorError::HttpRequestError(err) => is_transient_io(err),
+            ExtractorError::HttpStatusError { status, .. } => {
+                *status == 429 || (500..600).contains(status)
+            }
+            ExtractorError::StandardError(err) => is_transient_io(err),
+            _ => false,
+        }
+    }
+
+    /// The HTTP status of the response that caused the error, if there was one.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(u16)` for status and JSON parse errors, and HTTP errors raised
+    ///   for a response
+    /// - `None` if no response was received or the error is not HTTP-related
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_utils::error::ExtractorError;
+    ///
+    /// let err = ExtractorError::HttpStatusError { status: 503, body_snippet: String::new() };
+    /// assert_eq!(err.status_code(), Some(503));
+    /// let source = serde_json::from_str::<serde_json::Value>("<html>").unwrap_err();
+    /// let err = ExtractorError::JsonParseError { source, snippet: "<html>".into(), status: 200 };
+    /// assert_eq!(err.status_code(), Some(200));
+    /// assert_eq!(ExtractorError::AuthError("expired".into()).status_code(), None);
+    /// ```
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            ExtractorError::HttpStatusError { status, .. }
+            | ExtractorError::JsonParseError { status, .. } => Some(*status),
+            ExtractorError::HttpRequestError(err)
+            | ExtractorError::ConnectError(err)
+            | ExtractorError::TimeoutError(err)
+            | ExtractorError::BodyError(err) => err.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `err`, or an error it wraps, is an I/O error from a dropped or
+/// timed-out connection.
+fn is_transient_io(err: &(dyn StdError + 'static)) -> bool {
+    let mut current = Some(err);
+    while let Some(err) = current {
+        if let Some(io) = err.downcast_ref::<IoError>() {
+            return matches!(
+                io.kind(),
+                ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::UnexpectedEof
+                    | ErrorKind::TimedOut
+                    | ErrorKind::Interrupted
+            );
+        }
+        current = err.source();
+    }
+    false
+}
+
+impl From<ReqwestError> for ExtractorError {
+    /// Classify a `reqwest::Error` into the most specific variant.
+    fn from(err: ReqwestError) -> Self {
+        if err.is_timeout() {
+            ExtractorError::TimeoutError(err)
+        } else if err.is_connect() {
+            ExtractorError::ConnectError(err)
+        } else if err.is_body() || err.is_decode() {
+            ExtractorError::BodyError(err)
+        } else {
+            ExtractorError::HttpRequestError(err)
+        }
+    }
+}
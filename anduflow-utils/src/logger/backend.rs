@@ -0,0 +1,126 @@
+//! Pluggable destinations for [`LogStore`] lifecycle events.
+//!
+//! A [`LogBackend`] is attached to a [`LogStore`] with
+//! [`LogStore::with_backend`]; the store then forwards every lifecycle
+//! transition to it. This decouples logging from SQLite so operations can be
+//! reported to stdout, a file, or a remote endpoint; [`SqliteBackend`] keeps
+//! the `etl_logs` table up to date as the operation runs.
+
+use std::fmt::Debug;
+use std::io::Write;
+use std::sync::Mutex;
+
+use rusqlite::Connection;
+use serde_json::json;
+
+use crate::error::ExtractorResult;
+use crate::logger::store::{create_table_sql, LogStore};
+
+/// A destination for [`LogStore`] lifecycle events.
+///
+/// Each method receives the full state of the log after the transition.
+pub trait LogBackend: Debug + Send + Sync {
+    /// Called once when the backend is attached to a new operation.
+    fn record(&self, log: &LogStore) -> ExtractorResult<()>;
+
+    /// Called when the operation's status or progress changes.
+    fn update(&self, log: &LogStore) -> ExtractorResult<()>;
+
+    /// Called when the operation completes or fails.
+    fn finish(&self, log: &LogStore) -> ExtractorResult<()>;
+}
+
+/// A [`LogBackend`] that writes each event to stdout as a single JSON line.
+///
+/// Each line has the form `{"event": "record" | "update" | "finish", "log": {...}}`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonStdoutBackend;
+
+impl JsonStdoutBackend {
+    fn emit(&self, event: &str, log: &LogStore) -> ExtractorResult<()> {
+        let line = serde_json::to_string(&json!({ "event": event, "log": log }))?;
+        writeln!(std::io::stdout().lock(), "{line}")?;
+        Ok(())
+    }
+}
+
+impl LogBackend for JsonStdoutBackend {
+    fn record(&self, log: &LogStore) -> ExtractorResult<()> {
+        self.emit("record", log)
+    }
+
+    fn update(&self, log: &LogStore) -> ExtractorResult<()> {
+        self.emit("update", log)
+    }
+
+    fn finish(&self, log: &LogStore) -> ExtractorResult<()> {
+        self.emit("finish", log)
+    }
+}
+
+/// A [`LogBackend`] that writes each operation to the `etl_logs` table.
+///
+/// `record` inserts the operation's row with [`LogStore::insert`], and
+/// `update` and `finish` overwrite it with [`LogStore::update`], so the table
+/// shows progress while the operation runs rather than only once it is saved.
+/// Child logs created with [`LogStore::new_child`] share the backend and get
+/// rows of their own.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use rusqlite::Connection;
+/// use anduflow_utils::logger::backend::SqliteBackend;
+/// use anduflow_utils::logger::store::LogStore;
+///
+/// let backend = Arc::new(SqliteBackend::new(Connection::open_in_memory().unwrap()).unwrap());
+/// let mut log = LogStore::new("users".to_string(), "extract".to_string())
+///     .with_backend(backend.clone());
+/// log.mark_in_progress();
+/// log.mark_completed();
+///
+/// let status: String = backend
+///     .connection()
+///     .query_row("SELECT status FROM etl_logs", [], |row| row.get(0))
+///     .unwrap();
+/// assert_eq!(status, "Completed");
+/// ```
+#[derive(Debug)]
+pub struct SqliteBackend {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteBackend {
+    /// Write to `conn`, creating the `etl_logs` table if it does not exist.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(SqliteBackend)` writing to `conn`
+    /// - `Err(ExtractorError)` if the table could not be created
+    pub fn new(conn: Connection) -> ExtractorResult<Self> {
+        conn.execute_batch(&create_table_sql())?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Lock the connection, e.g. to query the rows written so far.
+    pub fn connection(&self) -> std::sync::MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl LogBackend for SqliteBackend {
+    fn record(&self, log: &LogStore) -> ExtractorResult<()> {
+        log.insert_row(&self.connection())
+    }
+
+    fn update(&self, log: &LogStore) -> ExtractorResult<()> {
+        log.update_row(&self.connection())
+    }
+
+    fn finish(&self, log: &LogStore) -> ExtractorResult<()> {
+        log.update_row(&self.connection())
+    }
+}
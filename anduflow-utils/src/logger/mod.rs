@@ -1 +1,2 @@
-pub mod store;
\ No newline at end of file
+pub mod backend;
+pub mod store;
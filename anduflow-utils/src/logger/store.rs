@@ -1,9 +1,12 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use std::sync::Arc;
-use rusqlite::Connection;
-use crate::error::ExtractorResult;
+use std::time::Duration;
+use rusqlite::types::{FromSql, Type};
+use rusqlite::{params_from_iter, Connection, Row, ToSql};
+use crate::error::{ExtractorError, ExtractorResult};
+use crate::logger::backend::LogBackend;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum LogStatus {
@@ -14,6 +17,52 @@ pub enum LogStatus {
     Cancelled,
 }
 
+impl LogStatus {
+    /// The stable string stored in the `status` column.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogStatus::Started => "Started",
+            LogStatus::InProgress => "InProgress",
+            LogStatus::Completed => "Completed",
+            LogStatus::Failed => "Failed",
+            LogStatus::Cancelled => "Cancelled",
+        }
+    }
+}
+
+impl std::str::FromStr for LogStatus {
+    type Err = ExtractorError;
+
+    /// Parse the string stored in the `status` column; see [`LogStatus::as_str`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Started" => Ok(LogStatus::Started),
+            "InProgress" => Ok(LogStatus::InProgress),
+            "Completed" => Ok(LogStatus::Completed),
+            "Failed" => Ok(LogStatus::Failed),
+            "Cancelled" => Ok(LogStatus::Cancelled),
+            other => Err(ExtractorError::ExtractOpsError(format!(
+                "Unknown log status '{other}'"
+            ))),
+        }
+    }
+}
+
+/// Aggregate throughput over the `etl_logs` rows created in a time window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThroughputSummary {
+    /// Number of operations logged in the window.
+    pub total_operations: usize,
+    /// Number of those operations that completed successfully.
+    pub completed_operations: usize,
+    /// `completed_operations / total_operations`, or `0.0` for an empty window.
+    pub success_rate: f64,
+    /// Mean `elapsed_ms` over operations that recorded one.
+    pub average_elapsed_ms: Option<f64>,
+    /// Sum of `processed_items` over the window.
+    pub total_items_processed: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogStore {
     // Core identifiers
@@ -54,6 +103,13 @@ pub struct LogStore {
     // System info
     hostname: Option<String>,
     process_id: Option<u32>,
+
+    // Lifecycle events are forwarded here when attached
+    #[serde(skip)]
+    backend: Option<Arc<dyn LogBackend>>,
+    // Sample memory on every progress update
+    #[serde(skip)]
+    memory_sampling: bool,
 }
 
 impl LogStore {
@@ -80,18 +136,72 @@ impl LogStore {
             tags: Vec::new(),
             hostname: Some(std::env::var("HOSTNAME").unwrap_or_default()),
             process_id: Some(std::process::id()),
+            backend: None,
+            memory_sampling: false,
         }
     }
 
+    /// Create a log for a sub-step of this operation.
+    ///
+    /// The child's `parent_id` is this log's id, so the steps of a pipeline run
+    /// can be fetched with [`fetch_children`] and rolled up with
+    /// [`aggregate_children`](Self::aggregate_children). The child forwards its
+    /// transitions to the same backend as this log, if one is attached, starting
+    /// with `record` as for [`with_backend`](Self::with_backend), and inherits
+    /// its memory sampling setting.
+    pub fn new_child(&self, operation: String, operation_type: String) -> Self {
+        let mut child = Self::new(operation, operation_type);
+        child.parent_id = Some(self.id);
+        child.backend = self.backend.clone();
+        child.memory_sampling = self.memory_sampling;
+        child.notify(|backend, log| backend.record(log));
+        child
+    }
+
+    /// Attach a backend that receives every lifecycle transition of this log.
+    ///
+    /// The backend's `record` is called immediately with the current state.
+    pub fn with_backend(mut self, backend: Arc<dyn LogBackend>) -> Self {
+        self.backend = Some(backend);
+        self.notify(|backend, log| backend.record(log));
+        self
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn parent_id(&self) -> Option<Uuid> {
+        self.parent_id
+    }
+
+    pub fn status(&self) -> &LogStatus {
+        &self.status
+    }
+
+    pub fn source_uri(&self) -> Option<&str> {
+        self.source_uri.as_deref()
+    }
+
+    pub fn destination_uri(&self) -> Option<&str> {
+        self.destination_uri.as_deref()
+    }
+
+    pub fn elapsed_ms(&self) -> Option<usize> {
+        self.elapsed_ms
+    }
+
     pub fn mark_in_progress(&mut self) {
         self.status = LogStatus::InProgress;
         self.started_at = Some(Utc::now());
+        self.notify(|backend, log| backend.update(log));
     }
 
     pub fn mark_completed(&mut self) {
         self.status = LogStatus::Completed;
         self.completed_at = Some(Utc::now());
         self.calculate_elapsed_time();
+        self.notify(|backend, log| backend.finish(log));
     }
 
     pub fn mark_failed(&mut self, error: String) {
@@ -99,6 +209,19 @@ impl LogStore {
         self.error_message = Some(error);
         self.completed_at = Some(Utc::now());
         self.calculate_elapsed_time();
+        self.notify(|backend, log| backend.finish(log));
+    }
+
+    /// Forward an event to the attached backend, if any.
+    ///
+    /// Logging must never fail the operation being logged, so backend errors
+    /// are reported through `tracing` and otherwise ignored.
+    fn notify(&self, event: impl FnOnce(&dyn LogBackend, &LogStore) -> ExtractorResult<()>) {
+        if let Some(backend) = &self.backend
+            && let Err(err) = event(backend.as_ref(), self)
+        {
+            tracing::warn!("log backend failed for operation {}: {err}", self.id);
+        }
     }
 
     pub fn update_progress(&mut self, processed: usize, total: usize) {
@@ -112,6 +235,94 @@ impl LogStore {
             let seconds = elapsed.num_seconds().max(1) as f64;
             self.items_per_second = Some(processed as f64 / seconds);
         }
+        self.notify(|backend, log| backend.update(log));
+    }
+
+    /// Recompute this log's status and timing from its children.
+    ///
+    /// The status is `Failed` if any child failed, `InProgress` if any child
+    /// has not finished, `Cancelled` if any was cancelled and `Completed`
+    /// otherwise. `started_at` becomes the earliest child start and, once every
+    /// child has finished, `completed_at` the latest child completion, from
+    /// which `elapsed_ms` is recalculated. Does nothing if `children` is empty.
+    pub fn aggregate_children(&mut self, children: &[LogStore]) {
+        if children.is_empty() {
+            return;
+        }
+        let failed = children
+            .iter()
+            .filter(|child| matches!(child.status, LogStatus::Failed))
+            .count();
+        let unfinished = children
+            .iter()
+            .any(|child| matches!(child.status, LogStatus::Started | LogStatus::InProgress));
+        let cancelled = children
+            .iter()
+            .any(|child| matches!(child.status, LogStatus::Cancelled));
+
+        self.status = if failed > 0 {
+            LogStatus::Failed
+        } else if unfinished {
+            LogStatus::InProgress
+        } else if cancelled {
+            LogStatus::Cancelled
+        } else {
+            LogStatus::Completed
+        };
+        if failed > 0 && self.error_message.is_none() {
+            self.error_message = Some(format!(
+                "{failed} of {} child operations failed",
+                children.len()
+            ));
+        }
+
+        self.started_at = children.iter().filter_map(|child| child.started_at).min();
+        if unfinished {
+            self.completed_at = None;
+            self.elapsed_ms = None;
+        } else {
+            self.completed_at = children.iter().filter_map(|child| child.completed_at).max();
+            self.calculate_elapsed_time();
+        }
+        if self.memory_sampling {
+            self.sample_memory();
+        }
+        self.notify(|backend, log| backend.update(log));
+    }
+
+    /// Record the process's current resident memory in `memory_usage_mb`.
+    ///
+    /// On Linux this reads `VmRSS` from `/proc/self/status`. On other platforms,
+    /// or if the value cannot be read, `memory_usage_mb` is set to `None`; this
+    /// never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anduflow_utils::logger::store::LogStore;
+    ///
+    /// let mut log = LogStore::new("users".to_string(), "extract".to_string());
+    /// log.sample_memory();
+    /// if cfg!(target_os = "linux") {
+    ///     assert!(log.memory_usage_mb().unwrap() > 0.0);
+    /// } else {
+    ///     assert!(log.memory_usage_mb().is_none());
+    /// }
+    /// ```
+    pub fn sample_memory(&mut self) {
+        self.memory_usage_mb = resident_memory_mb();
+    }
+
+    /// Also [`sample_memory`](Self::sample_memory) on every
+    /// [`update_progress`](Self::update_progress), so throughput and memory
+    /// are recorded together. Off by default.
+    pub fn with_memory_sampling(mut self, enabled: bool) -> Self {
+        self.memory_sampling = enabled;
+        self
+    }
+
+    pub fn memory_usage_mb(&self) -> Option<f64> {
+        self.memory_usage_mb
     }
 
     fn calculate_elapsed_time(&mut self) {
@@ -129,10 +340,417 @@ impl LogStore {
         self.metadata = metadata;
     }
 
+    /// Insert a single entry into the metadata object, replacing any
+    /// non-object metadata that was set before.
+    pub fn insert_metadata(&mut self, key: &str, value: serde_json::Value) {
+        if !self.metadata.is_object() {
+            self.metadata = serde_json::Value::Object(serde_json::Map::new());
+        }
+        if let Some(map) = self.metadata.as_object_mut() {
+            map.insert(key.to_string(), value);
+        }
+    }
+
+    pub fn metadata(&self) -> &serde_json::Value {
+        &self.metadata
+    }
+
+    /// Summarise throughput for every operation created at or after `since`.
+    ///
+    /// The aggregation runs in SQLite so rows are never loaded into memory.
+    pub fn throughput_summary(
+        conn: &Arc<Connection>,
+        since: DateTime<Utc>,
+    ) -> ExtractorResult<ThroughputSummary> {
+        let summary = conn.query_row(
+            r#"
+            SELECT
+                COUNT(*),
+                COALESCE(SUM(CASE WHEN status = ?2 THEN 1 ELSE 0 END), 0),
+                CAST(SUM(CASE WHEN status = ?2 THEN 1 ELSE 0 END) AS REAL) / NULLIF(COUNT(*), 0),
+                AVG(elapsed_ms),
+                COALESCE(SUM(processed_items), 0)
+            FROM etl_logs
+            WHERE created_at >= ?1
+            "#,
+            (format_timestamp(&since), LogStatus::Completed.as_str()),
+            |row| {
+                Ok(ThroughputSummary {
+                    total_operations: row.get::<_, i64>(0)? as usize,
+                    completed_operations: row.get::<_, i64>(1)? as usize,
+                    success_rate: row.get::<_, Option<f64>>(2)?.unwrap_or(0.0),
+                    average_elapsed_ms: row.get(3)?,
+                    total_items_processed: row.get::<_, i64>(4)? as usize,
+                })
+            },
+        )?;
+        Ok(summary)
+    }
+
     pub fn set_source_destination(&mut self, source: Option<String>, destination: Option<String>) {
         self.source_uri = source;
         self.destination_uri = destination;
     }
+
+    /// Write this log as a new row of the `etl_logs` table.
+    ///
+    /// Timestamps are stored with [`format_timestamp`], `status` with
+    /// [`LogStatus::as_str`], and `metadata` and `tags` as JSON text. While
+    /// another connection holds the write lock, the write waits for it up to the
+    /// connection's busy timeout; see [`configure_busy_timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rusqlite::Connection;
+    /// use anduflow_utils::logger::store::{ensure_etl_logs_table_exists, LogStore};
+    ///
+    /// let conn = Arc::new(Connection::open_in_memory().unwrap());
+    /// ensure_etl_logs_table_exists(&conn).unwrap();
+    ///
+    /// let mut log = LogStore::new("users".to_string(), "extract".to_string());
+    /// log.add_tag("nightly".to_string());
+    /// log.insert(&conn).unwrap();
+    ///
+    /// log.update_progress(10, 10);
+    /// log.mark_completed();
+    /// log.update(&conn).unwrap();
+    ///
+    /// let (status, processed, tags): (String, i64, String) = conn
+    ///     .query_row(
+    ///         "SELECT status, processed_items, tags FROM etl_logs WHERE id = ?1",
+    ///         [log.id().to_string()],
+    ///         |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(status, "Completed");
+    /// assert_eq!(processed, 10);
+    /// assert_eq!(tags, r#"["nightly"]"#);
+    /// ```
+    pub fn insert(&self, conn: &Arc<Connection>) -> ExtractorResult<()> {
+        self.insert_row(conn)
+    }
+
+    /// Like [`insert`](Self::insert), for a connection that is not shared.
+    pub(crate) fn insert_row(&self, conn: &Connection) -> ExtractorResult<()> {
+        let metadata = serde_json::to_string(&self.metadata)?;
+        let tags = serde_json::to_string(&self.tags)?;
+        let params = self.row_params(&metadata, &tags);
+        conn.execute(
+            r#"
+            INSERT INTO etl_logs (
+                id, parent_id, operation, operation_type, status, error_message,
+                created_at, started_at, completed_at, elapsed_ms,
+                total_items, processed_items, progress_percentage,
+                items_per_second, memory_usage_mb, source_uri, destination_uri,
+                metadata, tags, hostname, process_id
+            ) VALUES (
+                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11,
+                ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21
+            )
+            "#,
+            params_from_iter(params.iter()),
+        )?;
+        Ok(())
+    }
+
+    /// Overwrite the `etl_logs` row previously written by [`insert`](Self::insert)
+    /// with the current state of this log.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the row was updated
+    /// - `Err(ExtractorError::ExtractOpsError)` if there is no row with this log's id
+    /// - `Err(ExtractorError)` if the write failed
+    pub fn update(&self, conn: &Arc<Connection>) -> ExtractorResult<()> {
+        self.update_row(conn)
+    }
+
+    /// Like [`update`](Self::update), for a connection that is not shared.
+    pub(crate) fn update_row(&self, conn: &Connection) -> ExtractorResult<()> {
+        let metadata = serde_json::to_string(&self.metadata)?;
+        let tags = serde_json::to_string(&self.tags)?;
+        let params = self.row_params(&metadata, &tags);
+        let updated = conn.execute(
+            r#"
+            UPDATE etl_logs SET
+                parent_id = ?2, operation = ?3, operation_type = ?4, status = ?5,
+                error_message = ?6, created_at = ?7, started_at = ?8,
+                completed_at = ?9, elapsed_ms = ?10, total_items = ?11,
+                processed_items = ?12, progress_percentage = ?13,
+                items_per_second = ?14, memory_usage_mb = ?15, source_uri = ?16,
+                destination_uri = ?17, metadata = ?18, tags = ?19, hostname = ?20,
+                process_id = ?21
+            WHERE id = ?1
+            "#,
+            params_from_iter(params.iter()),
+        )?;
+        if updated == 0 {
+            return Err(ExtractorError::ExtractOpsError(format!(
+                "No etl_logs row for operation {}",
+                self.id
+            )));
+        }
+        Ok(())
+    }
+
+    /// The values of every `etl_logs` column, in table order, for use as
+    /// positional parameters `?1` to `?21`.
+    fn row_params<'a>(&'a self, metadata: &'a str, tags: &'a str) -> Vec<Box<dyn ToSql + 'a>> {
+        vec![
+            Box::new(self.id.to_string()),
+            Box::new(self.parent_id.map(|id| id.to_string())),
+            Box::new(self.operation.as_str()),
+            Box::new(self.operation_type.as_str()),
+            Box::new(self.status.as_str()),
+            Box::new(self.error_message.as_deref()),
+            Box::new(format_timestamp(&self.created_at)),
+            Box::new(self.started_at.as_ref().map(format_timestamp)),
+            Box::new(self.completed_at.as_ref().map(format_timestamp)),
+            Box::new(self.elapsed_ms.map(|ms| ms as i64)),
+            Box::new(self.total_items.map(|n| n as i64)),
+            Box::new(self.processed_items.map(|n| n as i64)),
+            Box::new(self.progress_percentage),
+            Box::new(self.items_per_second),
+            Box::new(self.memory_usage_mb),
+            Box::new(self.source_uri.as_deref()),
+            Box::new(self.destination_uri.as_deref()),
+            Box::new(metadata),
+            Box::new(tags),
+            Box::new(self.hostname.as_deref()),
+            Box::new(self.process_id),
+        ]
+    }
+}
+
+impl TryFrom<&Row<'_>> for LogStore {
+    type Error = rusqlite::Error;
+
+    /// Rebuild a log from an `etl_logs` row selected with `SELECT *`.
+    ///
+    /// Nullable columns that are missing from the row, e.g. in tables written
+    /// by an older schema version, are read as `None`.
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        let metadata: Option<String> = optional_column(row, "metadata")?;
+        let tags: Option<String> = optional_column(row, "tags")?;
+        let status: String = row.get("status")?;
+        Ok(Self {
+            id: parse_column(row, "id", row.get::<_, String>("id")?.parse::<Uuid>())?,
+            parent_id: optional_column::<String>(row, "parent_id")?
+                .map(|id| parse_column(row, "parent_id", id.parse::<Uuid>()))
+                .transpose()?,
+            operation: row.get("operation")?,
+            operation_type: row.get("operation_type")?,
+            status: parse_column(row, "status", status.parse::<LogStatus>())?,
+            error_message: optional_column(row, "error_message")?,
+            created_at: parse_column(
+                row,
+                "created_at",
+                DateTime::parse_from_rfc3339(&row.get::<_, String>("created_at")?)
+                    .map(|ts| ts.with_timezone(&Utc)),
+            )?,
+            started_at: parse_timestamp_column(row, "started_at")?,
+            completed_at: parse_timestamp_column(row, "completed_at")?,
+            elapsed_ms: optional_column::<i64>(row, "elapsed_ms")?.map(|ms| ms as usize),
+            total_items: optional_column::<i64>(row, "total_items")?.map(|n| n as usize),
+            processed_items: optional_column::<i64>(row, "processed_items")?.map(|n| n as usize),
+            progress_percentage: optional_column(row, "progress_percentage")?,
+            items_per_second: optional_column(row, "items_per_second")?,
+            memory_usage_mb: optional_column(row, "memory_usage_mb")?,
+            source_uri: optional_column(row, "source_uri")?,
+            destination_uri: optional_column(row, "destination_uri")?,
+            metadata: match metadata {
+                Some(text) => parse_column(row, "metadata", serde_json::from_str(&text))?,
+                None => serde_json::Value::Null,
+            },
+            tags: match tags {
+                Some(text) => parse_column(row, "tags", serde_json::from_str(&text))?,
+                None => Vec::new(),
+            },
+            hostname: optional_column(row, "hostname")?,
+            process_id: optional_column(row, "process_id")?,
+            backend: None,
+            memory_sampling: false,
+        })
+    }
+}
+
+/// Read a nullable column, treating a column the row doesn't have as `NULL`.
+fn optional_column<T: FromSql>(row: &Row<'_>, name: &str) -> rusqlite::Result<Option<T>> {
+    match row.as_ref().column_index(name) {
+        Ok(index) => row.get(index),
+        Err(rusqlite::Error::InvalidColumnName(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Read an optional RFC-3339 timestamp column.
+fn parse_timestamp_column(row: &Row<'_>, name: &str) -> rusqlite::Result<Option<DateTime<Utc>>> {
+    optional_column::<String>(row, name)?
+        .map(|text| {
+            let parsed = DateTime::parse_from_rfc3339(&text).map(|ts| ts.with_timezone(&Utc));
+            parse_column(row, name, parsed)
+        })
+        .transpose()
+}
+
+/// Turn a failure to parse the text of column `name` into a rusqlite error.
+fn parse_column<T, E>(row: &Row<'_>, name: &str, parsed: Result<T, E>) -> rusqlite::Result<T>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    parsed.map_err(|err| {
+        let index = row.as_ref().column_index(name).unwrap_or_default();
+        rusqlite::Error::FromSqlConversionFailure(index, Type::Text, Box::new(err))
+    })
+}
+
+/// Every `etl_logs` row whose `operation_type` is `operation_type`, oldest first.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use chrono::{Duration, Utc};
+/// use rusqlite::Connection;
+/// use anduflow_utils::logger::store::{
+///     ensure_etl_logs_table_exists, fetch_failed_logs, fetch_logs_by_operation_type,
+///     fetch_logs_since, LogStatus, LogStore,
+/// };
+///
+/// let conn = Arc::new(Connection::open_in_memory().unwrap());
+/// ensure_etl_logs_table_exists(&conn).unwrap();
+///
+/// let mut users = LogStore::new("users".to_string(), "extract".to_string());
+/// users.mark_completed();
+/// let mut orders = LogStore::new("orders".to_string(), "extract".to_string());
+/// orders.mark_failed("timeout".to_string());
+/// let mut warehouse = LogStore::new("warehouse".to_string(), "load".to_string());
+/// warehouse.mark_in_progress();
+/// for log in [&users, &orders, &warehouse] {
+///     log.insert(&conn).unwrap();
+/// }
+///
+/// let extracts = fetch_logs_by_operation_type(&conn, "extract").unwrap();
+/// assert_eq!(extracts.len(), 2);
+///
+/// let failed = fetch_failed_logs(&conn).unwrap();
+/// assert_eq!(failed.len(), 1);
+/// assert_eq!(failed[0].id(), orders.id());
+/// assert!(matches!(failed[0].status(), LogStatus::Failed));
+///
+/// let recent = fetch_logs_since(&conn, Utc::now() - Duration::hours(1)).unwrap();
+/// assert_eq!(recent.len(), 3);
+/// assert!(fetch_logs_since(&conn, Utc::now() + Duration::hours(1)).unwrap().is_empty());
+/// ```
+pub fn fetch_logs_by_operation_type(
+    conn: &Arc<Connection>,
+    operation_type: &str,
+) -> ExtractorResult<Vec<LogStore>> {
+    fetch_logs(conn, "operation_type = ?1", operation_type)
+}
+
+/// Every `etl_logs` row whose `parent_id` is `parent_id`, oldest first.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use rusqlite::Connection;
+/// use anduflow_utils::logger::store::{
+///     ensure_etl_logs_table_exists, fetch_children, LogStatus, LogStore,
+/// };
+///
+/// let conn = Arc::new(Connection::open_in_memory().unwrap());
+/// ensure_etl_logs_table_exists(&conn).unwrap();
+///
+/// let mut run = LogStore::new("nightly".to_string(), "pipeline".to_string());
+/// let mut extract = run.new_child("users".to_string(), "extract".to_string());
+/// extract.mark_completed();
+/// let mut load = run.new_child("users".to_string(), "load".to_string());
+/// load.mark_failed("disk full".to_string());
+/// for log in [&run, &extract, &load] {
+///     log.insert(&conn).unwrap();
+/// }
+///
+/// let children = fetch_children(&conn, run.id()).unwrap();
+/// assert_eq!(children.len(), 2);
+/// assert!(children.iter().all(|child| child.parent_id() == Some(run.id())));
+/// assert!(children.iter().any(|child| child.id() == extract.id()));
+/// assert!(children.iter().any(|child| child.id() == load.id()));
+///
+/// run.aggregate_children(&children);
+/// assert!(matches!(run.status(), LogStatus::Failed));
+/// assert!(run.elapsed_ms().is_some());
+/// ```
+pub fn fetch_children(conn: &Arc<Connection>, parent_id: Uuid) -> ExtractorResult<Vec<LogStore>> {
+    fetch_logs(conn, "parent_id = ?1", &parent_id.to_string())
+}
+
+/// Every `etl_logs` row with status `Failed`, oldest first.
+pub fn fetch_failed_logs(conn: &Arc<Connection>) -> ExtractorResult<Vec<LogStore>> {
+    fetch_logs(conn, "status = ?1", LogStatus::Failed.as_str())
+}
+
+/// Every `etl_logs` row created at or after `since`, oldest first.
+pub fn fetch_logs_since(
+    conn: &Arc<Connection>,
+    since: DateTime<Utc>,
+) -> ExtractorResult<Vec<LogStore>> {
+    fetch_logs(conn, "created_at >= ?1", &format_timestamp(&since))
+}
+
+/// Select the `etl_logs` rows matching `condition`, which may refer to `param` as `?1`.
+fn fetch_logs(conn: &Connection, condition: &str, param: &str) -> ExtractorResult<Vec<LogStore>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT * FROM etl_logs WHERE {condition} ORDER BY created_at"
+    ))?;
+    let logs = stmt
+        .query_map([param], |row| row.try_into())?
+        .collect::<Result<Vec<LogStore>, _>>()?;
+    Ok(logs)
+}
+
+/// The resident set size of this process in MB, if the platform exposes it.
+#[cfg(target_os = "linux")]
+fn resident_memory_mb() -> Option<f64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes: f64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes / 1024.0)
+}
+
+/// The resident set size of this process in MB, if the platform exposes it.
+#[cfg(not(target_os = "linux"))]
+fn resident_memory_mb() -> Option<f64> {
+    None
+}
+
+/// Format a timestamp the way it is stored in the `etl_logs` TEXT columns.
+///
+/// A fixed-width UTC RFC-3339 form keeps lexicographic and chronological
+/// order identical, so timestamps can be compared directly in SQL.
+pub fn format_timestamp(ts: &DateTime<Utc>) -> String {
+    ts.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Let SQLite itself wait up to `timeout` for a lock before returning `SQLITE_BUSY`.
+///
+/// Equivalent to `PRAGMA busy_timeout`. Connections opened with rusqlite wait
+/// 5 seconds by default; raise the timeout on logger connections shared by
+/// many concurrent pipelines so short-lived write locks don't fail log writes.
+/// The wait blocks the calling thread, so async callers writing logs under
+/// heavy contention should do so from `spawn_blocking`.
+pub fn configure_busy_timeout(conn: &Connection, timeout: Duration) -> ExtractorResult<()> {
+    conn.busy_timeout(timeout)?;
+    Ok(())
 }
 
 // SQLite schema creation
@@ -200,9 +818,77 @@ pub fn ensure_table_exists(
 }
 
 
-/// Convenience function specifically for the etl_logs table
+/// Check if a table exists, creating it if it doesn't, and verify that an
+/// existing table has the columns `create_table_sql` would create.
+///
+/// The expected columns are found by running `create_table_sql` against a
+/// scratch in-memory database and comparing both tables' `PRAGMA table_info`.
+/// Missing columns and columns with a different declared type are reported;
+/// extra columns are allowed.
+///
+/// # Arguments
+///
+/// * `conn` - The SQLite connection
+/// * `table_name` - The name of the table to check/create
+/// * `create_table_sql` - The SQL statement to create the table
+///
+/// # Returns
+///
+/// Returns `Ok(true)` if the table was created, `Ok(false)` if it already existed
+/// with a matching schema, `Err(ExtractorError::SchemaMismatch)` listing the
+/// differences if it has drifted, or another error if the operation failed.
+pub fn ensure_table_matches(
+    conn: &Arc<Connection>,
+    table_name: &str,
+    create_table_sql: &str,
+) -> ExtractorResult<bool> {
+    if ensure_table_exists(conn, table_name, create_table_sql)? {
+        return Ok(true);
+    }
+
+    let scratch = Connection::open_in_memory()?;
+    scratch.execute(create_table_sql, [])?;
+    let expected = table_columns(&scratch, table_name)?;
+    let actual = table_columns(conn, table_name)?;
+
+    let discrepancies: Vec<String> = expected
+        .iter()
+        .filter_map(|(name, expected_type)| {
+            match actual.iter().find(|(actual_name, _)| actual_name.eq_ignore_ascii_case(name)) {
+                None => Some(format!("missing column '{name}' ({expected_type})")),
+                Some((_, actual_type)) if !actual_type.eq_ignore_ascii_case(expected_type) => {
+                    Some(format!("column '{name}' is {actual_type}, expected {expected_type}"))
+                }
+                Some(_) => None,
+            }
+        })
+        .collect();
+
+    if discrepancies.is_empty() {
+        Ok(false)
+    } else {
+        Err(ExtractorError::SchemaMismatch {
+            table: table_name.to_string(),
+            discrepancies,
+        })
+    }
+}
+
+/// Read the `(name, declared type)` of every column of `table_name`.
+fn table_columns(conn: &Connection, table_name: &str) -> ExtractorResult<Vec<(String, String)>> {
+    let mut stmt = conn.prepare("SELECT name, type FROM pragma_table_info(?1)")?;
+    let columns = stmt
+        .query_map([table_name], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(columns)
+}
+
+/// Convenience function specifically for the etl_logs table.
+///
+/// An existing table is validated with [`ensure_table_matches`], so a stale
+/// `etl_logs` table is reported instead of breaking later inserts.
 pub fn ensure_etl_logs_table_exists(conn: &Arc<Connection>) -> ExtractorResult<bool> {
-    ensure_table_exists(
+    ensure_table_matches(
         conn,
         "etl_logs",
         r#"
@@ -0,0 +1,113 @@
+//! Lifecycle events forwarded from `LogStore` to its backend.
+
+use std::sync::{Arc, Mutex};
+
+use anduflow_utils::error::ExtractorResult;
+use anduflow_utils::logger::backend::{LogBackend, SqliteBackend};
+use anduflow_utils::logger::store::LogStore;
+
+/// Captures `(event, operation, status)` for every event it receives.
+#[derive(Debug, Default)]
+struct CapturingBackend {
+    events: Mutex<Vec<(&'static str, String, String)>>,
+}
+
+impl CapturingBackend {
+    fn capture(&self, event: &'static str, log: &LogStore) -> ExtractorResult<()> {
+        let json = serde_json::to_value(log)?;
+        self.events.lock().unwrap().push((
+            event,
+            json["operation"].as_str().unwrap().to_string(),
+            log.status().as_str().to_string(),
+        ));
+        Ok(())
+    }
+
+    fn events(&self) -> Vec<(&'static str, String, String)> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl LogBackend for CapturingBackend {
+    fn record(&self, log: &LogStore) -> ExtractorResult<()> {
+        self.capture("record", log)
+    }
+
+    fn update(&self, log: &LogStore) -> ExtractorResult<()> {
+        self.capture("update", log)
+    }
+
+    fn finish(&self, log: &LogStore) -> ExtractorResult<()> {
+        self.capture("finish", log)
+    }
+}
+
+fn event(event: &'static str, operation: &str, status: &str) -> (&'static str, String, String) {
+    (event, operation.to_string(), status.to_string())
+}
+
+#[test]
+fn every_transition_reaches_the_backend_in_order() {
+    let backend = Arc::new(CapturingBackend::default());
+    let mut log =
+        LogStore::new("users".to_string(), "extract".to_string()).with_backend(backend.clone());
+
+    log.mark_in_progress();
+    log.update_progress(5, 10);
+    log.mark_failed("boom".to_string());
+
+    assert_eq!(
+        backend.events(),
+        [
+            event("record", "users", "Started"),
+            event("update", "users", "InProgress"),
+            event("update", "users", "InProgress"),
+            event("finish", "users", "Failed"),
+        ]
+    );
+}
+
+#[test]
+fn child_logs_are_recorded_on_the_parent_backend() {
+    let backend = Arc::new(CapturingBackend::default());
+    let parent =
+        LogStore::new("pipeline".to_string(), "pipeline".to_string()).with_backend(backend.clone());
+
+    let mut child = parent.new_child("users".to_string(), "extract".to_string());
+    child.mark_completed();
+
+    assert_eq!(
+        backend.events(),
+        [
+            event("record", "pipeline", "Started"),
+            event("record", "users", "Started"),
+            event("finish", "users", "Completed"),
+        ]
+    );
+}
+
+#[test]
+fn the_sqlite_backend_keeps_each_row_current() {
+    let backend = Arc::new(SqliteBackend::new(rusqlite::Connection::open_in_memory().unwrap()).unwrap());
+    let parent =
+        LogStore::new("pipeline".to_string(), "pipeline".to_string()).with_backend(backend.clone());
+    let mut child = parent.new_child("users".to_string(), "extract".to_string());
+
+    child.mark_in_progress();
+    child.update_progress(3, 4);
+    let status = |id: uuid::Uuid| -> (String, Option<i64>) {
+        backend
+            .connection()
+            .query_row(
+                "SELECT status, processed_items FROM etl_logs WHERE id = ?1",
+                [id.to_string()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap()
+    };
+    assert_eq!(status(child.id()), ("InProgress".to_string(), Some(3)));
+
+    child.mark_completed();
+    assert_eq!(status(child.id()), ("Completed".to_string(), Some(3)));
+    assert_eq!(status(parent.id()), ("Started".to_string(), None));
+}
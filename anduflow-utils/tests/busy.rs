@@ -0,0 +1,55 @@
+//! Logger writes while another connection holds the database's write lock.
+
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use anduflow_utils::logger::store::{configure_busy_timeout, ensure_etl_logs_table_exists, LogStore};
+use rusqlite::Connection;
+
+#[test]
+fn a_write_waits_for_a_held_lock_to_be_released() {
+    let path = std::env::temp_dir().join(format!("anduflow-busy-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let conn = Arc::new(Connection::open(&path).unwrap());
+    ensure_etl_logs_table_exists(&conn).unwrap();
+    configure_busy_timeout(&conn, Duration::from_secs(5)).unwrap();
+
+    let holder = Connection::open(&path).unwrap();
+    holder.execute_batch("BEGIN IMMEDIATE").unwrap();
+    let release = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        holder.execute_batch("COMMIT").unwrap();
+    });
+
+    let log = LogStore::new("users".to_string(), "extract".to_string());
+    log.insert(&conn).unwrap();
+    release.join().unwrap();
+
+    let rows: i64 = conn
+        .query_row("SELECT COUNT(*) FROM etl_logs", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(rows, 1);
+    drop(conn);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn a_write_fails_once_the_busy_timeout_expires() {
+    let path = std::env::temp_dir().join(format!("anduflow-busy-timeout-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let conn = Arc::new(Connection::open(&path).unwrap());
+    ensure_etl_logs_table_exists(&conn).unwrap();
+    configure_busy_timeout(&conn, Duration::from_millis(50)).unwrap();
+
+    let holder = Connection::open(&path).unwrap();
+    holder.execute_batch("BEGIN IMMEDIATE").unwrap();
+
+    let log = LogStore::new("users".to_string(), "extract".to_string());
+    assert!(log.insert(&conn).is_err());
+
+    holder.execute_batch("COMMIT").unwrap();
+    log.insert(&conn).unwrap();
+    drop((conn, holder));
+    std::fs::remove_file(&path).unwrap();
+}
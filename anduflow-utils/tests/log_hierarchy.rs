@@ -0,0 +1,79 @@
+//! Parent and child logs of a pipeline run, persisted to and read back from SQLite.
+
+use std::sync::Arc;
+
+use anduflow_utils::logger::store::{
+    ensure_etl_logs_table_exists, fetch_children, LogStatus, LogStore,
+};
+use rusqlite::Connection;
+
+fn logs_table() -> Arc<Connection> {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    ensure_etl_logs_table_exists(&conn).unwrap();
+    conn
+}
+
+#[test]
+fn a_parent_with_two_children_is_reconstructed_from_sqlite() {
+    let conn = logs_table();
+    let mut run = LogStore::new("nightly".to_string(), "pipeline".to_string());
+    run.insert(&conn).unwrap();
+
+    let mut extract = run.new_child("users".to_string(), "extract".to_string());
+    extract.mark_in_progress();
+    extract.mark_completed();
+    extract.insert(&conn).unwrap();
+    let mut load = run.new_child("users".to_string(), "load".to_string());
+    load.mark_in_progress();
+    load.mark_failed("disk full".to_string());
+    load.insert(&conn).unwrap();
+    let unrelated = LogStore::new("orders".to_string(), "extract".to_string());
+    unrelated.insert(&conn).unwrap();
+
+    let children = fetch_children(&conn, run.id()).unwrap();
+
+    let mut ids: Vec<_> = children.iter().map(LogStore::id).collect();
+    ids.sort();
+    let mut expected = vec![extract.id(), load.id()];
+    expected.sort();
+    assert_eq!(ids, expected);
+    assert!(children.iter().all(|child| child.parent_id() == Some(run.id())));
+    assert!(fetch_children(&conn, unrelated.id()).unwrap().is_empty());
+
+    run.aggregate_children(&children);
+    assert!(matches!(run.status(), LogStatus::Failed));
+    assert!(run.elapsed_ms().is_some());
+}
+
+#[test]
+fn a_parent_of_completed_children_is_completed() {
+    let mut run = LogStore::new("nightly".to_string(), "pipeline".to_string());
+    let children: Vec<LogStore> = ["extract", "load"]
+        .into_iter()
+        .map(|step| {
+            let mut child = run.new_child("users".to_string(), step.to_string());
+            child.mark_in_progress();
+            child.mark_completed();
+            child
+        })
+        .collect();
+
+    run.aggregate_children(&children);
+
+    assert!(matches!(run.status(), LogStatus::Completed));
+}
+
+#[test]
+fn an_unfinished_child_keeps_the_parent_in_progress() {
+    let mut run = LogStore::new("nightly".to_string(), "pipeline".to_string());
+    let mut done = run.new_child("users".to_string(), "extract".to_string());
+    done.mark_in_progress();
+    done.mark_completed();
+    let mut running = run.new_child("users".to_string(), "load".to_string());
+    running.mark_in_progress();
+
+    run.aggregate_children(&[done, running]);
+
+    assert!(matches!(run.status(), LogStatus::InProgress));
+    assert!(run.elapsed_ms().is_none());
+}
@@ -0,0 +1,114 @@
+//! Reading `etl_logs` rows back into `LogStore`s.
+
+use std::sync::Arc;
+
+use anduflow_utils::logger::store::{
+    ensure_etl_logs_table_exists, fetch_failed_logs, fetch_logs_by_operation_type,
+    fetch_logs_since, LogStatus, LogStore,
+};
+use chrono::{Duration, Utc};
+use rusqlite::Connection;
+use serde_json::json;
+
+fn logs_table() -> Arc<Connection> {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    ensure_etl_logs_table_exists(&conn).unwrap();
+    conn
+}
+
+/// Inserts a completed extract, a failed extract and an in-progress load.
+fn insert_mixed_logs(conn: &Arc<Connection>) -> [LogStore; 3] {
+    let mut users = LogStore::new("users".to_string(), "extract".to_string());
+    users.add_tag("nightly".to_string());
+    users.insert_metadata("endpoint", json!("users"));
+    users.mark_completed();
+    let mut orders = LogStore::new("orders".to_string(), "extract".to_string());
+    orders.mark_failed("timeout".to_string());
+    let mut warehouse = LogStore::new("warehouse".to_string(), "load".to_string());
+    warehouse.mark_in_progress();
+    for log in [&users, &orders, &warehouse] {
+        log.insert(conn).unwrap();
+    }
+    [users, orders, warehouse]
+}
+
+#[test]
+fn logs_are_filtered_by_operation_type() {
+    let conn = logs_table();
+    let [users, orders, warehouse] = insert_mixed_logs(&conn);
+
+    let extracts = fetch_logs_by_operation_type(&conn, "extract").unwrap();
+    let ids: Vec<_> = extracts.iter().map(LogStore::id).collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&users.id()) && ids.contains(&orders.id()));
+
+    let loads = fetch_logs_by_operation_type(&conn, "load").unwrap();
+    assert_eq!(loads.len(), 1);
+    assert_eq!(loads[0].id(), warehouse.id());
+    assert!(matches!(loads[0].status(), LogStatus::InProgress));
+}
+
+#[test]
+fn only_failed_logs_are_returned_with_their_error() {
+    let conn = logs_table();
+    let [_, orders, _] = insert_mixed_logs(&conn);
+
+    let failed = fetch_failed_logs(&conn).unwrap();
+
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].id(), orders.id());
+    assert!(matches!(failed[0].status(), LogStatus::Failed));
+    assert_eq!(serde_json::to_value(&failed[0]).unwrap()["error_message"], "timeout");
+}
+
+#[test]
+fn tags_and_metadata_round_trip() {
+    let conn = logs_table();
+    insert_mixed_logs(&conn);
+
+    let users = fetch_logs_by_operation_type(&conn, "extract")
+        .unwrap()
+        .into_iter()
+        .find(|log| matches!(log.status(), LogStatus::Completed))
+        .unwrap();
+    let users = serde_json::to_value(&users).unwrap();
+
+    assert_eq!(users["tags"], json!(["nightly"]));
+    assert_eq!(users["metadata"]["endpoint"], "users");
+}
+
+#[test]
+fn logs_are_filtered_by_creation_time() {
+    let conn = logs_table();
+    insert_mixed_logs(&conn);
+
+    assert_eq!(fetch_logs_since(&conn, Utc::now() - Duration::hours(1)).unwrap().len(), 3);
+    assert!(fetch_logs_since(&conn, Utc::now() + Duration::hours(1)).unwrap().is_empty());
+}
+
+#[test]
+fn rows_from_an_older_schema_have_no_optional_fields() {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    conn.execute_batch(
+        "CREATE TABLE etl_logs (
+             id TEXT PRIMARY KEY,
+             operation TEXT NOT NULL,
+             operation_type TEXT NOT NULL,
+             status TEXT NOT NULL,
+             created_at TEXT NOT NULL
+         );
+         INSERT INTO etl_logs VALUES (
+             '67e55044-10b1-426f-9247-bb680e5fe0c8', 'users', 'extract', 'Failed',
+             '2024-05-01T12:00:00Z'
+         );",
+    )
+    .unwrap();
+
+    let failed = fetch_failed_logs(&conn).unwrap();
+
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].id().to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    let log = serde_json::to_value(&failed[0]).unwrap();
+    assert!(log["error_message"].is_null());
+    assert_eq!(log["tags"], json!([]));
+}
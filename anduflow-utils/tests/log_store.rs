@@ -0,0 +1,62 @@
+//! Persistence of `LogStore` records in the `etl_logs` table.
+
+use std::sync::Arc;
+
+use anduflow_utils::logger::store::{ensure_etl_logs_table_exists, LogStore};
+use chrono::DateTime;
+use rusqlite::Connection;
+use serde_json::json;
+
+fn logs_table() -> Arc<Connection> {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    ensure_etl_logs_table_exists(&conn).unwrap();
+    conn
+}
+
+#[test]
+fn an_inserted_log_is_updated_in_place() {
+    let conn = logs_table();
+    let mut log = LogStore::new("users".to_string(), "extract".to_string());
+    log.add_tag("nightly".to_string());
+    log.add_tag("crm".to_string());
+    log.insert(&conn).unwrap();
+
+    let status: String = conn
+        .query_row("SELECT status FROM etl_logs WHERE id = ?1", [log.id().to_string()], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(status, "Started");
+
+    log.mark_in_progress();
+    log.update_progress(25, 25);
+    log.insert_metadata("endpoint", json!("users"));
+    log.mark_completed();
+    log.update(&conn).unwrap();
+
+    let (status, processed, tags, metadata, completed_at): (String, i64, String, String, String) =
+        conn.query_row(
+            "SELECT status, processed_items, tags, metadata, completed_at
+             FROM etl_logs WHERE id = ?1",
+            [log.id().to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
+        )
+        .unwrap();
+    assert_eq!(status, "Completed");
+    assert_eq!(processed, 25);
+    assert_eq!(tags, r#"["nightly","crm"]"#);
+    assert_eq!(serde_json::from_str::<serde_json::Value>(&metadata).unwrap()["endpoint"], "users");
+    assert!(DateTime::parse_from_rfc3339(&completed_at).is_ok());
+    let rows: i64 = conn
+        .query_row("SELECT COUNT(*) FROM etl_logs", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(rows, 1);
+}
+
+#[test]
+fn updating_a_log_that_was_never_inserted_fails() {
+    let conn = logs_table();
+    let log = LogStore::new("users".to_string(), "extract".to_string());
+
+    assert!(log.update(&conn).is_err());
+}
@@ -0,0 +1,47 @@
+//! Sampling the process's resident memory into `LogStore`.
+
+use anduflow_utils::logger::store::LogStore;
+
+fn log() -> LogStore {
+    LogStore::new("users".to_string(), "extract".to_string())
+}
+
+#[test]
+fn memory_is_unset_until_sampled() {
+    let mut log = log();
+    log.update_progress(1, 2);
+
+    assert!(log.memory_usage_mb().is_none());
+}
+
+#[test]
+fn sampling_reads_the_resident_memory() {
+    let mut log = log();
+
+    log.sample_memory();
+
+    if cfg!(target_os = "linux") {
+        assert!(log.memory_usage_mb().unwrap() > 0.0);
+    } else {
+        assert!(log.memory_usage_mb().is_none());
+    }
+}
+
+#[test]
+fn progress_updates_sample_memory_when_enabled() {
+    let mut log = log().with_memory_sampling(true);
+
+    log.update_progress(1, 2);
+
+    assert_eq!(log.memory_usage_mb().is_some(), cfg!(target_os = "linux"));
+}
+
+#[test]
+fn children_inherit_memory_sampling() {
+    let parent = log().with_memory_sampling(true);
+    let mut child = parent.new_child("page 1".to_string(), "extract".to_string());
+
+    child.update_progress(1, 1);
+
+    assert_eq!(child.memory_usage_mb().is_some(), cfg!(target_os = "linux"));
+}
@@ -0,0 +1,61 @@
+//! Classifying `ExtractorError` variants as retryable and reading their status codes.
+
+use std::io::{Error as IoError, ErrorKind};
+
+use anduflow_utils::error::ExtractorError;
+
+fn status(status: u16) -> ExtractorError {
+    ExtractorError::HttpStatusError {
+        status,
+        body_snippet: String::new(),
+    }
+}
+
+fn parse_error(status: u16) -> ExtractorError {
+    ExtractorError::JsonParseError {
+        source: serde_json::from_str::<serde_json::Value>("<html>").unwrap_err(),
+        snippet: "<html>".to_string(),
+        status,
+    }
+}
+
+#[test]
+fn throttling_and_server_errors_are_retryable() {
+    for code in [429, 500, 502, 503, 504, 599] {
+        assert!(status(code).is_retryable(), "{code}");
+    }
+}
+
+#[test]
+fn client_errors_are_not_retryable() {
+    for code in [400, 401, 403, 404, 422] {
+        assert!(!status(code).is_retryable(), "{code}");
+    }
+}
+
+#[test]
+fn transient_io_errors_are_retryable() {
+    for kind in [ErrorKind::ConnectionReset, ErrorKind::TimedOut, ErrorKind::UnexpectedEof] {
+        assert!(ExtractorError::StandardError(IoError::from(kind)).is_retryable(), "{kind:?}");
+    }
+    assert!(!ExtractorError::StandardError(IoError::from(ErrorKind::NotFound)).is_retryable());
+}
+
+#[test]
+fn parse_auth_and_operational_errors_are_not_retryable() {
+    let serialization = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+
+    assert!(!ExtractorError::SerializationError(serialization).is_retryable());
+    assert!(!parse_error(200).is_retryable());
+    assert!(!ExtractorError::AuthError("invalid_client".to_string()).is_retryable());
+    assert!(!ExtractorError::ExtractOpsError("bad config".to_string()).is_retryable());
+    assert!(!ExtractorError::EmptyRecords.is_retryable());
+}
+
+#[test]
+fn status_codes_are_exposed_where_known() {
+    assert_eq!(status(503).status_code(), Some(503));
+    assert_eq!(parse_error(200).status_code(), Some(200));
+    assert_eq!(ExtractorError::AuthError("expired".to_string()).status_code(), None);
+    assert_eq!(ExtractorError::EmptyRecords.status_code(), None);
+}
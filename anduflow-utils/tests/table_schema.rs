@@ -0,0 +1,62 @@
+//! Validation of existing tables against their expected schema.
+
+use std::sync::Arc;
+
+use anduflow_utils::error::ExtractorError;
+use anduflow_utils::logger::store::ensure_table_matches;
+use rusqlite::Connection;
+
+const CREATE_RUNS: &str =
+    "CREATE TABLE runs (id TEXT PRIMARY KEY, status TEXT NOT NULL, elapsed_ms INTEGER)";
+
+#[test]
+fn a_missing_table_is_created() {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+
+    assert!(ensure_table_matches(&conn, "runs", CREATE_RUNS).unwrap());
+    assert!(!ensure_table_matches(&conn, "runs", CREATE_RUNS).unwrap());
+}
+
+#[test]
+fn a_table_missing_a_column_is_reported() {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    conn.execute("CREATE TABLE runs (id TEXT PRIMARY KEY, status TEXT NOT NULL)", [])
+        .unwrap();
+
+    let err = ensure_table_matches(&conn, "runs", CREATE_RUNS).unwrap_err();
+
+    match err {
+        ExtractorError::SchemaMismatch { table, discrepancies } => {
+            assert_eq!(table, "runs");
+            assert_eq!(discrepancies, ["missing column 'elapsed_ms' (INTEGER)"]);
+        }
+        other => panic!("expected a schema mismatch, got {other}"),
+    }
+}
+
+#[test]
+fn a_column_with_a_different_type_is_reported() {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    conn.execute(
+        "CREATE TABLE runs (id TEXT PRIMARY KEY, status TEXT NOT NULL, elapsed_ms TEXT)",
+        [],
+    )
+    .unwrap();
+
+    let err = ensure_table_matches(&conn, "runs", CREATE_RUNS).unwrap_err();
+
+    assert!(err.to_string().contains("column 'elapsed_ms' is TEXT, expected INTEGER"));
+}
+
+#[test]
+fn extra_columns_are_allowed() {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    conn.execute(
+        "CREATE TABLE runs \
+         (id TEXT PRIMARY KEY, status TEXT NOT NULL, elapsed_ms INTEGER, note TEXT)",
+        [],
+    )
+    .unwrap();
+
+    assert!(!ensure_table_matches(&conn, "runs", CREATE_RUNS).unwrap());
+}
@@ -0,0 +1,58 @@
+//! Throughput aggregated over the `etl_logs` table.
+
+use std::sync::Arc;
+
+use anduflow_utils::logger::store::{ensure_etl_logs_table_exists, format_timestamp, LogStore};
+use chrono::{Duration, Utc};
+use rusqlite::Connection;
+
+/// Seed a row created `age` ago with the given outcome.
+fn seed(
+    conn: &Connection,
+    id: &str,
+    age: Duration,
+    status: &str,
+    elapsed_ms: Option<i64>,
+    items: i64,
+) {
+    conn.execute(
+        "INSERT INTO etl_logs
+             (id, operation, operation_type, status, created_at, elapsed_ms, processed_items)
+         VALUES (?1, 'users', 'extract', ?2, ?3, ?4, ?5)",
+        (id, status, format_timestamp(&(Utc::now() - age)), elapsed_ms, items),
+    )
+    .unwrap();
+}
+
+#[test]
+fn the_window_is_summarised_in_sql() {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    ensure_etl_logs_table_exists(&conn).unwrap();
+    seed(&conn, "a", Duration::minutes(5), "Completed", Some(100), 10);
+    seed(&conn, "b", Duration::minutes(4), "Completed", Some(300), 20);
+    seed(&conn, "c", Duration::minutes(3), "Completed", None, 5);
+    seed(&conn, "d", Duration::minutes(2), "Failed", Some(200), 0);
+    seed(&conn, "old", Duration::days(2), "Failed", Some(9000), 1000);
+
+    let summary = LogStore::throughput_summary(&conn, Utc::now() - Duration::hours(1)).unwrap();
+
+    assert_eq!(summary.total_operations, 4);
+    assert_eq!(summary.completed_operations, 3);
+    assert_eq!(summary.success_rate, 0.75);
+    assert_eq!(summary.average_elapsed_ms, Some(200.0));
+    assert_eq!(summary.total_items_processed, 35);
+}
+
+#[test]
+fn an_empty_window_has_no_successes() {
+    let conn = Arc::new(Connection::open_in_memory().unwrap());
+    ensure_etl_logs_table_exists(&conn).unwrap();
+    seed(&conn, "old", Duration::days(2), "Completed", Some(100), 10);
+
+    let summary = LogStore::throughput_summary(&conn, Utc::now() - Duration::hours(1)).unwrap();
+
+    assert_eq!(summary.total_operations, 0);
+    assert_eq!(summary.success_rate, 0.0);
+    assert_eq!(summary.average_elapsed_ms, None);
+    assert_eq!(summary.total_items_processed, 0);
+}